@@ -9,4 +9,12 @@ CREATE USER "fpa-user" WITH
 	NOCREATEROLE
 	ENCRYPTED PASSWORD 'fpa-pass';
 COMMENT ON ROLE "fpa-user"
-	IS 'Database operation user for the FPA Management application.';
\ No newline at end of file
+	IS 'Database operation user for the FPA Management application.';
+
+CREATE USER "fpa-system" WITH
+	NOCREATEDB
+	NOCREATEROLE
+	BYPASSRLS
+	ENCRYPTED PASSWORD 'fpa-pass';
+COMMENT ON ROLE "fpa-system"
+	IS 'Database user for the administration of the Tenants, with access to all of them.';
\ No newline at end of file
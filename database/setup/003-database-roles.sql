@@ -7,3 +7,4 @@ GRANT CONNECT, TEMPORARY ON DATABASE "fpa-management" TO "fpa-admin";
 GRANT ALL ON DATABASE "fpa-management" TO "fpa-admin";
 
 GRANT CONNECT, TEMPORARY ON DATABASE "fpa-management" TO "fpa-user";
+GRANT CONNECT, TEMPORARY ON DATABASE "fpa-management" TO "fpa-system";
\ No newline at end of file
@@ -70,4 +70,56 @@ CREATE TYPE function_type AS ENUM (
 	'CE',
 	'EE',
 	'SE');
-COMMENT ON TYPE function_type IS 'Type os Functions.';	
\ No newline at end of file
+COMMENT ON TYPE function_type IS 'Type os Functions.';
+
+CREATE TYPE counting_type AS ENUM (
+	'DEVELOPMENT',
+	'ENHANCEMENT',
+	'APPLICATION');
+COMMENT ON TYPE counting_type IS 'Type of counting for the Frontier.';
+
+CREATE TYPE counting_method AS ENUM (
+	'DETAILED',
+	'INDICATIVE',
+	'ESTIMATED');
+COMMENT ON TYPE counting_method IS 'Method of counting (IFPUG detailed or NESMA) for the Frontier.';
+
+CREATE TYPE change_type AS ENUM (
+	'ADDED',
+	'CHANGED',
+	'DELETED',
+	'CONVERSION');
+COMMENT ON TYPE change_type IS 'Change made to the Function in an enhancement counting.';
+
+CREATE TYPE impact_type AS ENUM (
+	'INCLUSION',
+	'ALTERATION',
+	'EXCLUSION',
+	'TESTING',
+	'CORRECTIVE');
+COMMENT ON TYPE impact_type IS 'Impact factor (SISP) applied to the Function.';
+
+CREATE TYPE member_type AS ENUM (
+	'OWNER',
+	'EDITOR',
+	'VIEWER');
+COMMENT ON TYPE member_type IS 'Access of the User to the Project.';
+
+CREATE TYPE key_scope AS ENUM (
+	'READ',
+	'WRITE');
+COMMENT ON TYPE key_scope IS 'Access granted by the API key of the User.';
+
+CREATE TYPE audit_entity AS ENUM (
+	'PROJECT',
+	'FRONTIER',
+	'FACTOR',
+	'EMPIRICAL',
+	'FUNCTION');
+COMMENT ON TYPE audit_entity IS 'Record of the Project changed by the operation.';
+
+CREATE TYPE audit_action AS ENUM (
+	'CREATE',
+	'UPDATE',
+	'DELETE');
+COMMENT ON TYPE audit_action IS 'Operation made on the record.';
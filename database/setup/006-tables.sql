@@ -149,12 +149,69 @@ CREATE UNIQUE INDEX uq_projects_tenant_name ON projects(tenant, name);
 
 COMMENT ON INDEX uq_projects_tenant_name IS 'Unique Project Name on a Tenant.';
 
+CREATE TABLE members (
+    project     id,
+    "user"      id,
+    tenant      id,
+    role        member_type NOT NULL,
+    time        datetime    NOT NULL
+);
+
+COMMENT ON TABLE members                IS 'Users with access to the Project.';
+COMMENT ON COLUMN members.project       IS 'Project shared with the User.';
+COMMENT ON COLUMN members.user          IS 'User member of the Project.';
+COMMENT ON COLUMN members.tenant        IS 'Tenant owner of the Member.';
+COMMENT ON COLUMN members.role          IS 'Access of the User to the Project.';
+COMMENT ON COLUMN members.time          IS 'Member registration time.';
+
+ALTER TABLE members ADD
+    CONSTRAINT pk_members
+    PRIMARY KEY (project, "user");
+
+COMMENT ON INDEX pk_members IS 'Primary key for the Member.';
+
+-- Deferred, so the owner is registered before the Project is inserted.
+ALTER TABLE members ADD
+    CONSTRAINT fk_members_project
+    FOREIGN KEY (project)
+    REFERENCES projects (project)
+    ON DELETE CASCADE
+    DEFERRABLE INITIALLY DEFERRED;
+
+CREATE INDEX ix_members_project ON members (project);
+
+COMMENT ON INDEX ix_members_project IS 'Reference index to the Project of the Member.';
+
+ALTER TABLE members ADD
+    CONSTRAINT fk_members_user
+    FOREIGN KEY ("user")
+    REFERENCES users ("user");
+
+CREATE INDEX ix_members_user ON members ("user");
+
+COMMENT ON INDEX ix_members_user IS 'Reference index for Users.';
+
+ALTER TABLE members ADD
+    CONSTRAINT fk_members_tenant
+    FOREIGN KEY (tenant)
+    REFERENCES tenants (tenant);
+
+CREATE INDEX ix_members_tenant ON members (tenant);
+
+COMMENT ON INDEX ix_members_tenant IS 'Index to management access on tenant scope.';
+
+CREATE UNIQUE INDEX uq_members_owner ON members (project) WHERE role = 'OWNER';
+
+COMMENT ON INDEX uq_members_owner IS 'Unique owner of the Project.';
+
 CREATE TABLE frontiers (
     frontier    id,
     project     id,
     tenant      id,
     name        brief,
-    description description
+    description description,
+    counting    counting_type NOT NULL DEFAULT 'DEVELOPMENT',
+    method      counting_method NOT NULL DEFAULT 'DETAILED'
 );
 
 COMMENT ON TABLE frontiers                IS 'Frontier for group Functions on a Project.';
@@ -163,6 +220,8 @@ COMMENT ON COLUMN frontiers.project       IS 'Project Identifier.';
 COMMENT ON COLUMN frontiers.tenant        IS 'Tenant owner of the Frontier.';
 COMMENT ON COLUMN frontiers.name          IS 'Frontier`s Name.';
 COMMENT ON COLUMN frontiers.description   IS 'Frontier`s Description';
+COMMENT ON COLUMN frontiers.counting      IS 'Type of counting for the Frontier.';
+COMMENT ON COLUMN frontiers.method        IS 'Method of counting for the Frontier.';
 
 ALTER TABLE frontiers ADD 
     CONSTRAINT pk_frontiers
@@ -280,14 +339,42 @@ CREATE INDEX ix_empiricals_tenant ON empiricals (tenant);
 
 COMMENT ON INDEX ix_empiricals_tenant IS 'Index to management access on tenant scope.';
 
+CREATE TABLE impacts (
+    tenant      id,
+    impact      impact_type NOT NULL,
+    value       INTEGER NOT NULL
+);
+
+COMMENT ON TABLE impacts            IS 'Impact factors (SISP) of the Tenant.';
+COMMENT ON COLUMN impacts.tenant    IS 'Tenant owner of the Impact.';
+COMMENT ON COLUMN impacts.impact    IS 'Impact`s Factor.';
+COMMENT ON COLUMN impacts.value     IS 'Percent of the function points for the Impact`s Factor.';
+
+ALTER TABLE impacts ADD
+    CONSTRAINT pk_impacts
+    PRIMARY KEY (tenant, impact);
+
+COMMENT ON INDEX pk_impacts IS 'Primary key for the Impact`s Factors on a Tenant.';
+
+ALTER TABLE impacts ADD
+    CONSTRAINT fk_impacts_tenant
+    FOREIGN KEY (tenant)
+    REFERENCES tenants (tenant);
+
+CREATE INDEX ix_impacts_tenant ON impacts (tenant);
+
+COMMENT ON INDEX ix_impacts_tenant IS 'Index to management access on tenant scope.';
+
 CREATE TABLE functions (
     function    id,
     frontier    id,
     tenant      id,
     type        function_type NOT NULL,
     name        brief,
-    description description
-
+    description description,
+    change      change_type,
+    impact      impact_type,
+    enhancement UUID
 );
 
 COMMENT ON TABLE functions              IS 'Set of All Functions for the Frontier.';
@@ -297,6 +384,9 @@ COMMENT ON COLUMN functions.tenant      IS 'Tenant owner of the Function';
 COMMENT ON COLUMN functions.type        IS 'Functions`s type.';
 COMMENT ON COLUMN functions.name        IS 'Name of the Function.';
 COMMENT ON COLUMN functions.description IS 'Description for the Function.';
+COMMENT ON COLUMN functions.change      IS 'Change made to the Function in an enhancement counting.';
+COMMENT ON COLUMN functions.impact      IS 'Impact factor (SISP) applied to the Function.';
+COMMENT ON COLUMN functions.enhancement IS 'Enhancement Frontier that last changed the Function on a baseline.';
 
 ALTER TABLE functions ADD 
     CONSTRAINT pk_functions
@@ -335,6 +425,9 @@ COMMENT ON COLUMN functions_datas.tenant        IS 'Tenant owner of the Function
 COMMENT ON COLUMN functions_datas.type          IS 'Functions`s type. Only for Data on type 1 and 2.';
 COMMENT ON COLUMN functions_datas.name          IS 'Name of the Function.';
 COMMENT ON COLUMN functions_datas.description   IS 'Description for the Function.';
+COMMENT ON COLUMN functions_datas.change        IS 'Change made to the Function in an enhancement counting.';
+COMMENT ON COLUMN functions_datas.impact        IS 'Impact factor (SISP) applied to the Function.';
+COMMENT ON COLUMN functions_datas.enhancement   IS 'Enhancement Frontier that last changed the Function on a baseline.';
 
 ALTER TABLE functions_datas ADD 
     CONSTRAINT pk_functions_datas
@@ -364,6 +457,16 @@ CREATE INDEX ix_functions_datas_type ON functions (type);
 
 COMMENT ON INDEX ix_functions_datas_type IS 'Reference index to the functions`s type.';
 
+ALTER TABLE functions_datas ADD
+    CONSTRAINT fk_functions_datas_enhancement
+    FOREIGN KEY (enhancement)
+    REFERENCES frontiers (frontier)
+    ON DELETE SET NULL;
+
+CREATE INDEX ix_functions_datas_enhancement ON functions_datas (enhancement);
+
+COMMENT ON INDEX ix_functions_datas_enhancement IS 'Reference index to the Enhancement Frontier that last changed the Function.';
+
 ALTER TABLE functions_datas ADD 
     CONSTRAINT check_functions_datas_type
     CHECK (type IN ('ALI', 'AIE'));
@@ -377,6 +480,9 @@ COMMENT ON COLUMN functions_transactions.tenant         IS 'Tenant owner of the
 COMMENT ON COLUMN functions_transactions.type           IS 'Functions`s type. Only for Transactions on type 3, 4 and 5.';
 COMMENT ON COLUMN functions_transactions.name           IS 'Name of the Function.';
 COMMENT ON COLUMN functions_transactions.description    IS 'Description for the Function.';
+COMMENT ON COLUMN functions_transactions.change         IS 'Change made to the Function in an enhancement counting.';
+COMMENT ON COLUMN functions_transactions.impact         IS 'Impact factor (SISP) applied to the Function.';
+COMMENT ON COLUMN functions_transactions.enhancement    IS 'Enhancement Frontier that last changed the Function on a baseline.';
 
 ALTER TABLE functions_transactions ADD 
     CONSTRAINT pk_functions_transactions
@@ -406,6 +512,16 @@ CREATE INDEX ix_functions_transactions_type ON functions (type);
 
 COMMENT ON INDEX ix_functions_transactions_type IS 'Reference index to the functions`s type.';
 
+ALTER TABLE functions_transactions ADD
+    CONSTRAINT fk_functions_transactions_enhancement
+    FOREIGN KEY (enhancement)
+    REFERENCES frontiers (frontier)
+    ON DELETE SET NULL;
+
+CREATE INDEX ix_functions_transactions_enhancement ON functions_transactions (enhancement);
+
+COMMENT ON INDEX ix_functions_transactions_enhancement IS 'Reference index to the Enhancement Frontier that last changed the Function.';
+
 ALTER TABLE functions_transactions ADD 
     CONSTRAINT check_functions_transactions_type
     CHECK (type IN ('CE', 'EE', 'SE'));
@@ -531,4 +647,205 @@ ALTER TABLE ders ADD
     REFERENCES tenants (tenant);
 
 CREATE INDEX ix_ders_tenant ON alrs (tenant);
-COMMENT ON INDEX ix_ders_tenant IS 'Index to management access on tenant scope.';
\ No newline at end of file
+COMMENT ON INDEX ix_ders_tenant IS 'Index to management access on tenant scope.';
+
+CREATE TABLE ders_transactions (
+    function    id,
+    name        brief,
+    description description,
+    alr         UUID,
+    rlr         VARCHAR(255),
+    der         VARCHAR(255),
+    tenant      id
+);
+
+COMMENT ON TABLE ders_transactions              IS 'Elementary Data crossing the boundary of a Function of type Transaction.';
+COMMENT ON COLUMN ders_transactions.function    IS 'Unique identifier for a Function of type Transaction.';
+COMMENT ON COLUMN ders_transactions.name        IS 'Name of the Elementary Data.';
+COMMENT ON COLUMN ders_transactions.description IS 'Description for the Elementary Data.';
+COMMENT ON COLUMN ders_transactions.alr         IS 'Function of type Data with the referenced Elementary Data.';
+COMMENT ON COLUMN ders_transactions.rlr         IS 'Referenced Logical Record with the referenced Elementary Data.';
+COMMENT ON COLUMN ders_transactions.der         IS 'Name of the referenced Elementary Data.';
+COMMENT ON COLUMN ders_transactions.tenant      IS 'Tenant owner of the Elementary Data.';
+
+ALTER TABLE ders_transactions ADD
+    CONSTRAINT pk_ders_transactions
+    PRIMARY KEY (function, name);
+
+COMMENT ON INDEX pk_ders_transactions IS 'Primary key for Elementary Data of the Transactions.';
+
+ALTER TABLE ders_transactions ADD
+    CONSTRAINT fk_ders_transactions_function
+    FOREIGN KEY (function)
+    REFERENCES functions_transactions (function)
+    ON DELETE CASCADE;
+
+CREATE INDEX ix_ders_transactions_function ON ders_transactions (function);
+
+COMMENT ON INDEX ix_ders_transactions_function IS 'Reference index to the functions of type Transaction.';
+
+ALTER TABLE ders_transactions ADD
+    CONSTRAINT fk_ders_transactions_ders
+    FOREIGN KEY (alr, rlr, der)
+    REFERENCES ders (function, rlr, name)
+    ON DELETE SET NULL;
+
+CREATE INDEX ix_ders_transactions_ders ON ders_transactions (alr, rlr, der);
+
+COMMENT ON INDEX ix_ders_transactions_ders IS 'Reference index to the Referenced Elementary Data.';
+
+ALTER TABLE ders_transactions ADD
+    CONSTRAINT fk_ders_transactions_tenant
+    FOREIGN KEY (tenant)
+    REFERENCES tenants (tenant);
+
+CREATE INDEX ix_ders_transactions_tenant ON ders_transactions (tenant);
+
+COMMENT ON INDEX ix_ders_transactions_tenant IS 'Index to management access on tenant scope.';
+
+CREATE TABLE snapshots (
+    snapshot    id,
+    project     id,
+    tenant      id,
+    label       brief,
+    time        datetime    NOT NULL,
+    content     JSONB       NOT NULL
+);
+
+COMMENT ON TABLE snapshots              IS 'Immutable copy of the counting of a Project.';
+COMMENT ON COLUMN snapshots.snapshot    IS 'Unique identifier for the Snapshot.';
+COMMENT ON COLUMN snapshots.project     IS 'Project of the Snapshot.';
+COMMENT ON COLUMN snapshots.tenant      IS 'Tenant owner of the Snapshot.';
+COMMENT ON COLUMN snapshots.label       IS 'Label of the Snapshot.';
+COMMENT ON COLUMN snapshots.time        IS 'Snapshot registration time.';
+COMMENT ON COLUMN snapshots.content     IS 'Frontiers, factors, empiricals and functions of the Project.';
+
+ALTER TABLE snapshots ADD
+    CONSTRAINT pk_snapshots
+    PRIMARY KEY (snapshot);
+
+COMMENT ON INDEX pk_snapshots IS 'Primary key for the Snapshot.';
+
+ALTER TABLE snapshots ADD
+    CONSTRAINT fk_snapshots_project
+    FOREIGN KEY (project)
+    REFERENCES projects (project)
+    ON DELETE CASCADE;
+
+CREATE INDEX ix_snapshots_project ON snapshots (project);
+
+COMMENT ON INDEX ix_snapshots_project IS 'Reference index to the Project of the Snapshot.';
+
+ALTER TABLE snapshots ADD
+    CONSTRAINT fk_snapshots_tenant
+    FOREIGN KEY (tenant)
+    REFERENCES tenants (tenant);
+
+CREATE INDEX ix_snapshots_tenant ON snapshots (tenant);
+
+COMMENT ON INDEX ix_snapshots_tenant IS 'Index to management access on tenant scope.';
+
+CREATE TABLE keys (
+    key         id,
+    "user"      id,
+    tenant      id,
+    name        brief,
+    hash        CHAR(64)    NOT NULL,
+    scope       key_scope   NOT NULL DEFAULT 'READ',
+    roles       TEXT[]      NOT NULL,
+    expires     datetime    NOT NULL,
+    time        datetime    NOT NULL
+);
+
+COMMENT ON TABLE keys                   IS 'API keys of the Users, for pipelines and scripts.';
+COMMENT ON COLUMN keys.key              IS 'Unique identifier for the API key.';
+COMMENT ON COLUMN keys.user             IS 'User owner of the API key.';
+COMMENT ON COLUMN keys.tenant           IS 'Tenant owner of the API key.';
+COMMENT ON COLUMN keys.name             IS 'Name of the API key.';
+COMMENT ON COLUMN keys.hash             IS 'SHA-256 of the secret of the API key (hexadecimal).';
+COMMENT ON COLUMN keys.scope            IS 'Access granted by the API key.';
+COMMENT ON COLUMN keys.roles            IS 'Roles of the User on the creation of the API key, limited by the scope.';
+COMMENT ON COLUMN keys.expires          IS 'Expiration time of the API key.';
+COMMENT ON COLUMN keys.time             IS 'API key registration time.';
+
+ALTER TABLE keys ADD
+    CONSTRAINT pk_keys
+    PRIMARY KEY (key);
+
+COMMENT ON INDEX pk_keys IS 'Primary key for the API key.';
+
+CREATE UNIQUE INDEX uq_keys_hash ON keys (hash);
+
+COMMENT ON INDEX uq_keys_hash IS 'Unique secret of the API keys, used by the authentication.';
+
+ALTER TABLE keys ADD
+    CONSTRAINT fk_keys_user
+    FOREIGN KEY ("user")
+    REFERENCES users ("user")
+    ON DELETE CASCADE;
+
+CREATE INDEX ix_keys_user ON keys ("user");
+
+COMMENT ON INDEX ix_keys_user IS 'Reference index for Users.';
+
+ALTER TABLE keys ADD
+    CONSTRAINT fk_keys_tenant
+    FOREIGN KEY (tenant)
+    REFERENCES tenants (tenant);
+
+CREATE INDEX ix_keys_tenant ON keys (tenant);
+
+COMMENT ON INDEX ix_keys_tenant IS 'Index to management access on tenant scope.';
+
+CREATE TABLE audits (
+    audit       id,
+    project     id,
+    tenant      id,
+    "user"      id,
+    time        datetime        NOT NULL,
+    entity      audit_entity    NOT NULL,
+    key         id,
+    action      audit_action    NOT NULL,
+    before      JSONB,
+    after       JSONB
+);
+
+COMMENT ON TABLE audits                 IS 'Trail of the changes made on the Projects.';
+COMMENT ON COLUMN audits.audit          IS 'Unique identifier for the Audit record.';
+COMMENT ON COLUMN audits.project        IS 'Project changed, kept after the removal of the Project.';
+COMMENT ON COLUMN audits.tenant         IS 'Tenant owner of the Audit record.';
+COMMENT ON COLUMN audits.user           IS 'User who performed the operation.';
+COMMENT ON COLUMN audits.time           IS 'Operation time.';
+COMMENT ON COLUMN audits.entity         IS 'Type of the record changed.';
+COMMENT ON COLUMN audits.key            IS 'Unique identifier of the record changed (the Frontier for factors and empiricals).';
+COMMENT ON COLUMN audits.action         IS 'Operation made on the record.';
+COMMENT ON COLUMN audits.before         IS 'Record before the operation, null on creation.';
+COMMENT ON COLUMN audits.after          IS 'Record after the operation, null on removal.';
+
+ALTER TABLE audits ADD
+    CONSTRAINT pk_audits
+    PRIMARY KEY (audit);
+
+COMMENT ON INDEX pk_audits IS 'Primary key for the Audit record.';
+
+CREATE INDEX ix_audits_project ON audits (project, time);
+
+COMMENT ON INDEX ix_audits_project IS 'Index to the trail of the Project, by time.';
+
+ALTER TABLE audits ADD
+    CONSTRAINT fk_audits_user
+    FOREIGN KEY ("user")
+    REFERENCES users ("user");
+
+CREATE INDEX ix_audits_user ON audits ("user");
+
+COMMENT ON INDEX ix_audits_user IS 'Reference index for Users.';
+
+ALTER TABLE audits ADD
+    CONSTRAINT fk_audits_tenant
+    FOREIGN KEY (tenant)
+    REFERENCES tenants (tenant);
+
+CREATE INDEX ix_audits_tenant ON audits (tenant);
+
+COMMENT ON INDEX ix_audits_tenant IS 'Index to management access on tenant scope.';
@@ -1,3 +1,28 @@
 --==============================================================================
 -- Visões
 --==============================================================================
+
+CREATE VIEW tenants_usage AS
+SELECT t.tenant,
+       t.tier,
+       (SELECT COUNT(*) FROM projects p WHERE p.tenant = t.tenant) AS projects,
+       (SELECT COUNT(*) FROM frontiers f WHERE f.tenant = t.tenant) AS frontiers,
+       (SELECT COUNT(*) FROM functions f WHERE f.tenant = t.tenant) AS functions
+FROM tenants t
+WHERE t.tenant = current_setting('app.current_tenant')::UUID;
+
+COMMENT ON VIEW tenants_usage IS 'Records of the current Tenant, regardless of the Project members, to apply the quotas of the tier.';
+
+CREATE VIEW functions_sizes AS
+SELECT f.function,
+       f.frontier,
+       f.tenant,
+       f.type,
+       f.change,
+       (SELECT COUNT(*) FROM rlrs r WHERE r.function = f.function) AS rlrs,
+       (SELECT COUNT(*) FROM ders d WHERE d.function = f.function) AS ders,
+       (SELECT COUNT(*) FROM alrs a WHERE a.function = f.function) AS alrs,
+       (SELECT COUNT(*) FROM ders_transactions d WHERE d.function = f.function) AS dets
+FROM functions f;
+
+COMMENT ON VIEW functions_sizes IS 'Records of each Function that rate its complexity, to count the function points of all Frontiers at once.';
@@ -4,3 +4,32 @@
 
 CREATE EXTENSION "uuid-ossp";
 
+--==============================================================================
+-- Project access by the members.
+--==============================================================================
+
+CREATE FUNCTION project_member(project UUID) RETURNS BOOLEAN AS $$
+	SELECT current_setting('app.current_admin', true) = 'true'
+		OR EXISTS (
+			SELECT 1 FROM members m
+			WHERE m.project = $1
+			AND m."user" = current_setting('app.current_user')::UUID);
+$$ LANGUAGE SQL STABLE;
+
+COMMENT ON FUNCTION project_member(UUID) IS 'Current User is a member of the Project, or a Tenant administrator.';
+
+CREATE FUNCTION frontier_member(frontier UUID) RETURNS BOOLEAN AS $$
+	SELECT COALESCE((
+		SELECT project_member(f.project) FROM frontiers f
+		WHERE f.frontier = $1), false);
+$$ LANGUAGE SQL STABLE;
+
+COMMENT ON FUNCTION frontier_member(UUID) IS 'Current User is a member of the Project of the Frontier, or a Tenant administrator.';
+
+CREATE FUNCTION function_member(function UUID) RETURNS BOOLEAN AS $$
+	SELECT COALESCE((
+		SELECT frontier_member(f.frontier) FROM functions f
+		WHERE f.function = $1), false);
+$$ LANGUAGE SQL STABLE;
+
+COMMENT ON FUNCTION function_member(UUID) IS 'Current User is a member of the Project of the Function, or a Tenant administrator.';
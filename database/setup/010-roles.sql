@@ -14,15 +14,48 @@ GRANT SELECT                            ON tenants                  TO "fpa-acce
 GRANT SELECT, INSERT, UPDATE            ON users                    TO "fpa-access";
 GRANT SELECT, INSERT                    ON versions                 TO "fpa-access";
 GRANT SELECT, INSERT, UPDATE, DELETE    ON projects                 TO "fpa-access";
+GRANT SELECT, INSERT, UPDATE, DELETE    ON members                  TO "fpa-access";
 GRANT SELECT, INSERT, UPDATE, DELETE    ON frontiers                TO "fpa-access";
 GRANT SELECT, INSERT, UPDATE, DELETE    ON empiricals               TO "fpa-access";
 GRANT SELECT, INSERT, UPDATE, DELETE    ON factors                  TO "fpa-access";
+GRANT SELECT, INSERT, UPDATE, DELETE    ON impacts                  TO "fpa-access";
 GRANT SELECT, INSERT, UPDATE, DELETE    ON functions                TO "fpa-access";
 GRANT SELECT, INSERT, UPDATE, DELETE    ON functions_datas          TO "fpa-access";
 GRANT SELECT, INSERT, UPDATE, DELETE    ON functions_transactions   TO "fpa-access";
 GRANT SELECT, INSERT, UPDATE, DELETE    ON alrs                     TO "fpa-access";
 GRANT SELECT, INSERT, UPDATE, DELETE    ON rlrs                     TO "fpa-access";
 GRANT SELECT, INSERT, UPDATE, DELETE    ON ders                     TO "fpa-access";
+GRANT SELECT, INSERT, UPDATE, DELETE    ON ders_transactions        TO "fpa-access";
+GRANT SELECT, INSERT                    ON snapshots                TO "fpa-access";
+GRANT SELECT, INSERT, DELETE            ON keys                     TO "fpa-access";
+GRANT SELECT, INSERT                    ON audits                   TO "fpa-access";
+
+--==============================================================================
+-- Views
+--==============================================================================
+
+GRANT SELECT                            ON tenants_usage            TO "fpa-access";
+
+--==============================================================================
+-- System Administration (without RLS)
+--==============================================================================
+
+GRANT SELECT, INSERT, UPDATE            ON tenants                  TO "fpa-system";
+GRANT SELECT                            ON users                    TO "fpa-system";
+GRANT SELECT                            ON projects                 TO "fpa-system";
+GRANT SELECT                            ON frontiers                TO "fpa-system";
+GRANT SELECT                            ON factors                  TO "fpa-system";
+GRANT SELECT                            ON impacts                  TO "fpa-system";
+GRANT SELECT                            ON functions                TO "fpa-system";
+GRANT SELECT                            ON functions_datas          TO "fpa-system";
+GRANT SELECT                            ON functions_transactions   TO "fpa-system";
+GRANT SELECT                            ON alrs                     TO "fpa-system";
+GRANT SELECT                            ON rlrs                     TO "fpa-system";
+GRANT SELECT                            ON ders                     TO "fpa-system";
+GRANT SELECT                            ON ders_transactions        TO "fpa-system";
+GRANT SELECT                            ON keys                     TO "fpa-system";
+GRANT SELECT                            ON audits                   TO "fpa-system";
+GRANT SELECT                            ON functions_sizes          TO "fpa-system";
 
 --==============================================================================
 -- Policies (Multi-Tenant)
@@ -52,6 +85,10 @@ ALTER TABLE frontiers ENABLE ROW LEVEL SECURITY;
 CREATE POLICY frontiers_policy ON frontiers
 USING (tenant = current_setting('app.current_tenant')::UUID);
 
+ALTER TABLE impacts ENABLE ROW LEVEL SECURITY;
+CREATE POLICY impacts_policy ON impacts
+USING (tenant = current_setting('app.current_tenant')::UUID);
+
 ALTER TABLE functions ENABLE ROW LEVEL SECURITY;
 CREATE POLICY functions_policy ON functions
 USING (tenant = current_setting('app.current_tenant')::UUID);
@@ -74,4 +111,71 @@ USING (tenant = current_setting('app.current_tenant')::UUID);
 
 ALTER TABLE ders ENABLE ROW LEVEL SECURITY;
 CREATE POLICY ders_policy ON ders
-USING (tenant = current_setting('app.current_tenant')::UUID);
\ No newline at end of file
+USING (tenant = current_setting('app.current_tenant')::UUID);
+
+ALTER TABLE ders_transactions ENABLE ROW LEVEL SECURITY;
+CREATE POLICY ders_transactions_policy ON ders_transactions
+USING (tenant = current_setting('app.current_tenant')::UUID);
+
+ALTER TABLE snapshots ENABLE ROW LEVEL SECURITY;
+CREATE POLICY snapshots_policy ON snapshots
+USING (tenant = current_setting('app.current_tenant')::UUID);
+
+ALTER TABLE members ENABLE ROW LEVEL SECURITY;
+CREATE POLICY members_policy ON members
+USING (tenant = current_setting('app.current_tenant')::UUID);
+
+ALTER TABLE keys ENABLE ROW LEVEL SECURITY;
+CREATE POLICY keys_policy ON keys
+USING (tenant = current_setting('app.current_tenant')::UUID);
+
+ALTER TABLE audits ENABLE ROW LEVEL SECURITY;
+CREATE POLICY audits_policy ON audits
+USING (tenant = current_setting('app.current_tenant')::UUID);
+
+-- API keys are managed only by their Users.
+CREATE POLICY keys_user_policy ON keys AS RESTRICTIVE
+USING ("user" = current_setting('app.current_user')::UUID);
+
+--==============================================================================
+-- Policies (Project Members)
+--==============================================================================
+
+CREATE POLICY projects_members_policy ON projects AS RESTRICTIVE
+USING (project_member(project));
+
+CREATE POLICY frontiers_members_policy ON frontiers AS RESTRICTIVE
+USING (project_member(project));
+
+CREATE POLICY snapshots_members_policy ON snapshots AS RESTRICTIVE
+USING (project_member(project));
+
+CREATE POLICY audits_members_policy ON audits AS RESTRICTIVE
+USING (project_member(project));
+
+CREATE POLICY factors_members_policy ON factors AS RESTRICTIVE
+USING (frontier_member(frontier));
+
+CREATE POLICY empiricals_members_policy ON empiricals AS RESTRICTIVE
+USING (frontier_member(frontier));
+
+CREATE POLICY functions_members_policy ON functions AS RESTRICTIVE
+USING (frontier_member(frontier));
+
+CREATE POLICY functions_datas_members_policy ON functions_datas AS RESTRICTIVE
+USING (frontier_member(frontier));
+
+CREATE POLICY functions_transactions_members_policy ON functions_transactions AS RESTRICTIVE
+USING (frontier_member(frontier));
+
+CREATE POLICY alrs_members_policy ON alrs AS RESTRICTIVE
+USING (function_member(function));
+
+CREATE POLICY rlrs_members_policy ON rlrs AS RESTRICTIVE
+USING (function_member(function));
+
+CREATE POLICY ders_members_policy ON ders AS RESTRICTIVE
+USING (function_member(function));
+
+CREATE POLICY ders_transactions_members_policy ON ders_transactions AS RESTRICTIVE
+USING (function_member(function));
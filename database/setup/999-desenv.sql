@@ -7,6 +7,21 @@ VALUES	('00000000-0000-0000-0000-000000000001', 'Tenant 01', CURRENT_TIMESTAMP,
 		('00000000-0000-0000-0000-000000000002', 'Tenant 02', CURRENT_TIMESTAMP, 'ACTIVE', 'SILVER'),
 		('00000000-0000-0000-0000-000000000003', 'Tenant 03', CURRENT_TIMESTAMP, 'ACTIVE', 'BRONZE');
 
+-- Users of the Keycloak realms (default, tenant-01 and tenant-02).
+INSERT INTO users ("user", tenant, name, email, time)
+VALUES	('7dd55185-97c0-448d-ad4e-610e7447d3fe', uuid_nil(), 'Administrator System', 'admin@nao.com.br', CURRENT_TIMESTAMP),
+		('788bde27-f334-422b-adec-9de5e8c539a2', uuid_nil(), 'User System', 'user@nao.com.br', CURRENT_TIMESTAMP),
+		('ce8ccb5b-4f6b-4aea-a366-bebe6be9d4cd', uuid_nil(), 'Viewer System', 'viewer@nao.com.br', CURRENT_TIMESTAMP),
+		('ee310f2c-ec38-46f6-b0dc-d15ab936c15a', uuid_nil(), 'Reviewer System', 'reviewer@nao.com.br', CURRENT_TIMESTAMP),
+		('acc5de66-9a8a-48c0-93b6-70afb57e9157', '00000000-0000-0000-0000-000000000001', 'Administrator System', 'admin@nao.com.br', CURRENT_TIMESTAMP),
+		('7c3974d3-ea3f-4e4a-88ee-18e30b9b34bc', '00000000-0000-0000-0000-000000000001', 'User System', 'user@nao.com.br', CURRENT_TIMESTAMP),
+		('43f60c02-9886-48db-8ce0-884a623b01f1', '00000000-0000-0000-0000-000000000001', 'Viewer System', 'viewer@nao.com.br', CURRENT_TIMESTAMP),
+		('c84cb08e-947b-40c3-b32c-04928292c474', '00000000-0000-0000-0000-000000000001', 'Reviewer System', 'reviewer@nao.com.br', CURRENT_TIMESTAMP),
+		('0f055794-f3c6-4c9a-9b36-2665c2da79bb', '00000000-0000-0000-0000-000000000002', 'Administrator System', 'admin@nao.com.br', CURRENT_TIMESTAMP),
+		('404b162b-0e6f-401f-b2bc-efa6110d4045', '00000000-0000-0000-0000-000000000002', 'User System', 'user@nao.com.br', CURRENT_TIMESTAMP),
+		('2e9bf0db-5858-4e7b-9dea-f07c847ce0e8', '00000000-0000-0000-0000-000000000002', 'Viewer System', 'viewer@nao.com.br', CURRENT_TIMESTAMP),
+		('db8a4fcb-920e-4485-bc60-d327eab101d6', '00000000-0000-0000-0000-000000000002', 'Reviewer System', 'reviewer@nao.com.br', CURRENT_TIMESTAMP);
+
 CREATE OR REPLACE FUNCTION update_db() RETURNS VOID AS $$
 DECLARE
 	t RECORD;
@@ -27,6 +42,12 @@ BEGIN
 			INSERT INTO projects (project, tenant, "user", name, description, time)
 			VALUES (project, t.tenant, uuid_nil(), 'Project ' || TO_CHAR(i, 'fm000'), 'Long description for the Project ' || TO_CHAR(i, 'fm000'), CURRENT_TIMESTAMP);
 
+			INSERT INTO members (project, "user", tenant, role, time)
+			VALUES (project, uuid_nil(), t.tenant, 'OWNER', CURRENT_TIMESTAMP);
+			INSERT INTO members (project, "user", tenant, role, time)
+			SELECT project, u."user", t.tenant, CASE u.email WHEN 'viewer@nao.com.br' THEN 'VIEWER' ELSE 'EDITOR' END::member_type, CURRENT_TIMESTAMP
+			FROM users u WHERE u.tenant = t.tenant AND u."user" <> uuid_nil();
+
 			frontier := uuid_generate_v4();
 			INSERT INTO frontiers (frontier, project, tenant, name, description) 
 			VALUES (frontier, project, t.tenant, 'Test', 'Frontier for test');
@@ -0,0 +1,31 @@
+use crate::model::sea_orm_active_enums::InfluenceType;
+
+/// Degree of influence (0 to 5) of a General System Characteristic.
+pub fn influence(value: &InfluenceType) -> i32 {
+    match value {
+        InfluenceType::Absent => 0,
+        InfluenceType::Minimum => 1,
+        InfluenceType::Moderate => 2,
+        InfluenceType::Average => 3,
+        InfluenceType::Significant => 4,
+        InfluenceType::Strong => 5,
+    }
+}
+
+/// Value Adjustment Factor for a Total Degree of Influence.
+pub fn vaf(tdi: i32) -> f64 {
+    f64::from(65 + tdi) / 100.0
+}
+
+/// Limits for a Value Adjustment Factor (TDI from 0 to 70).
+pub const VAF_RANGE: std::ops::RangeInclusive<f64> = 0.65..=1.35;
+
+/// Enhancement Function Points: EFP = (ADD + CHG + CNV) × VAFA + DEL × VAFB.
+pub fn efp(added: i32, changed: i32, conversion: i32, deleted: i32, vafa: f64, vafb: f64) -> f64 {
+    f64::from(added + changed + conversion) * vafa + f64::from(deleted) * vafb
+}
+
+/// Function points weighted by an impact factor (SISP) percent.
+pub fn impact(points: i32, percent: i32) -> f64 {
+    f64::from(points * percent) / 100.0
+}
@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::model::sea_orm_active_enums::FunctionType;
+
+/// Complexity of the Function, according to the IFPUG matrices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum Complexity {
+    /// Low complexity.
+    Low,
+    /// Average complexity.
+    Average,
+    /// High complexity.
+    High,
+}
+
+/// Rates the table, indexed by the row range and the DET range.
+fn rate(row: usize, det: usize) -> Complexity {
+    const MATRIX: [[Complexity; 3]; 3] = [
+        [Complexity::Low, Complexity::Low, Complexity::Average],
+        [Complexity::Low, Complexity::Average, Complexity::High],
+        [Complexity::Average, Complexity::High, Complexity::High],
+    ];
+    MATRIX[row][det]
+}
+
+/// Complexity of a Data Function (ALI or AIE), using the RET/DET matrix.
+///
+/// A Data Function without RLRs is counted as having a single RET.
+pub fn data(rets: usize, dets: usize) -> Complexity {
+    let row = match rets {
+        0..=1 => 0,
+        2..=5 => 1,
+        _ => 2,
+    };
+    let det = match dets {
+        0..=19 => 0,
+        20..=50 => 1,
+        _ => 2,
+    };
+    rate(row, det)
+}
+
+/// Complexity of a Transaction Function (EE, CE or SE), using the FTR/DET matrix.
+pub fn transaction(r#type: &FunctionType, ftrs: usize, dets: usize) -> Complexity {
+    let (row, det) = match r#type {
+        FunctionType::EE => (
+            match ftrs {
+                0..=1 => 0,
+                2 => 1,
+                _ => 2,
+            },
+            match dets {
+                0..=4 => 0,
+                5..=15 => 1,
+                _ => 2,
+            },
+        ),
+        _ => (
+            match ftrs {
+                0..=1 => 0,
+                2..=3 => 1,
+                _ => 2,
+            },
+            match dets {
+                0..=5 => 0,
+                6..=19 => 1,
+                _ => 2,
+            },
+        ),
+    };
+    rate(row, det)
+}
+
+/// Unadjusted function points for a Function Type with the given Complexity.
+pub fn points(r#type: &FunctionType, complexity: &Complexity) -> i32 {
+    match (r#type, complexity) {
+        (FunctionType::ALI, Complexity::Low) => 7,
+        (FunctionType::ALI, Complexity::Average) => 10,
+        (FunctionType::ALI, Complexity::High) => 15,
+        (FunctionType::AIE, Complexity::Low) => 5,
+        (FunctionType::AIE, Complexity::Average) => 7,
+        (FunctionType::AIE, Complexity::High) => 10,
+        (FunctionType::EE, Complexity::Low) => 3,
+        (FunctionType::EE, Complexity::Average) => 4,
+        (FunctionType::EE, Complexity::High) => 6,
+        (FunctionType::CE, Complexity::Low) => 3,
+        (FunctionType::CE, Complexity::Average) => 4,
+        (FunctionType::CE, Complexity::High) => 6,
+        (FunctionType::SE, Complexity::Low) => 4,
+        (FunctionType::SE, Complexity::Average) => 5,
+        (FunctionType::SE, Complexity::High) => 7,
+    }
+}
+
+/// Complexity of a Function in the NESMA estimated counting.
+///
+/// Data Functions are rated low and Transaction Functions average.
+pub fn estimated(r#type: &FunctionType) -> Complexity {
+    match r#type {
+        FunctionType::ALI | FunctionType::AIE => Complexity::Low,
+        _ => Complexity::Average,
+    }
+}
+
+/// Function points of a Function in the NESMA indicative counting.
+///
+/// Only Data Functions are counted: 35 for each ALI and 15 for each AIE.
+pub fn indicative(r#type: &FunctionType) -> i32 {
+    match r#type {
+        FunctionType::ALI => 35,
+        FunctionType::AIE => 15,
+        _ => 0,
+    }
+}
@@ -0,0 +1,28 @@
+use crate::model::sea_orm_active_enums::EmpiricalType;
+
+use super::round;
+
+/// Hours of construction for the function points, using the Productivity (hours/FP).
+pub fn construction(points: f64, productivity: i32) -> f64 {
+    round(points * f64::from(productivity))
+}
+
+/// Hours of an empirical phase, as a percent of the construction hours.
+pub fn phase(construction: f64, percent: i32) -> f64 {
+    round(construction * f64::from(percent) / 100.0)
+}
+
+/// Working days to spend the hours with a team working the daily hours.
+pub fn duration(hours: f64, team: i32, daily: i32) -> f64 {
+    round(hours / (f64::from(team) * f64::from(daily)))
+}
+
+/// Cost of the hours at the hourly rate.
+pub fn cost(hours: f64, rate: f64) -> f64 {
+    round(hours * rate)
+}
+
+/// Verify if the empirical type is a phase over the construction hours.
+pub fn is_phase(empirical: &EmpiricalType) -> bool {
+    !matches!(empirical, EmpiricalType::Productivity)
+}
@@ -0,0 +1,8 @@
+pub mod adjustment;
+pub mod complexity;
+pub mod estimation;
+
+/// Rounds a value to two decimal places.
+pub fn round(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
@@ -1,29 +1,40 @@
 use std::sync::Arc;
 
 use crate::{
-    ctx::Context,
+    ctx::{Context, Role},
     error::Error,
-    jwks,
+    handlers::keys,
     model::{prelude::Users, users},
     state::AppState,
 };
 
 use axum::{
     body::Body,
-    extract::State,
-    http::{header, Request},
+    extract::{MatchedPath, State},
+    http::{header, Method, Request},
     middleware::Next,
     response::Response,
 };
 use chrono::Utc;
 use jsonwebtoken::{decode, decode_header, DecodingKey, Validation};
-use log::{debug, info};
+use log::{debug, info, trace, warn};
 use sea_orm::{ActiveModelTrait, EntityTrait, Set};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 const BEARER: &str = "Bearer ";
 const AUDIENCE: &str = "account";
+const CLIENT: &str = "fpa-management";
+
+/**
+ * Roles of the user in the realm or in a client.
+ */
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct Access {
+    #[serde(default)]
+    roles: Vec<String>,
+}
 
 /**
  * Claims is used to extract information from the Token.
@@ -31,20 +42,49 @@ const AUDIENCE: &str = "account";
 #[derive(Clone, Debug, Serialize, Deserialize)]
 struct Claims {
     sub: Uuid,
-    tenant: Uuid,
+    /// Tenant informed by the realm, it must be the Tenant of the issuer.
+    tenant: Option<Uuid>,
     name: String,
     email: String,
+    #[serde(default)]
+    realm_access: Access,
+    #[serde(default)]
+    resource_access: HashMap<String, Access>,
+}
+
+/**
+ * Issuer of the token, read before the signature is verified to find the key.
+ */
+#[derive(Debug, Deserialize)]
+struct Unverified {
+    iss: String,
 }
 
 impl Claims {
-    fn to_context(&self) -> Context {
+    fn to_context(&self, tenant: Uuid) -> Context {
         Context::new(
             self.sub,
-            self.tenant,
+            tenant,
             self.name.to_owned(),
             self.email.to_owned(),
+            self.roles(),
         )
     }
+
+    /// Application roles from the realm roles and the roles of the client.
+    fn roles(&self) -> Vec<Role> {
+        let client = self
+            .resource_access
+            .get(CLIENT)
+            .cloned()
+            .unwrap_or_default();
+        self.realm_access
+            .roles
+            .iter()
+            .chain(client.roles.iter())
+            .filter_map(|name| Role::from_name(name))
+            .collect()
+    }
 }
 
 pub async fn require(
@@ -54,11 +94,6 @@ pub async fn require(
 ) -> Result<Response, Error> {
     debug!("Validating the user token.");
 
-    if !jwks::is_prepared() {
-        let config = state.configuration();
-        jwks::prepare(config).await?;
-    }
-
     let token = request
         .headers()
         .get(header::AUTHORIZATION)
@@ -75,52 +110,132 @@ pub async fn require(
         None => return Err(Error::Unauthorized),
     };
 
+    // API keys are an alternative to the tokens of the issuers.
+    if token.starts_with(keys::PREFIX) {
+        let context = keys::authenticate(&token, &state).await?;
+        request.extensions_mut().insert(context);
+        return Ok(next.run(request).await);
+    }
+
     let header = decode_header(&token)?;
 
-    let key = jwks::key(header.kid.unwrap().clone())?;
+    let kid = match header.kid {
+        Some(v) => v,
+        None => return Err(Error::TokenInvalid),
+    };
+    // The key is selected by the issuer and kid, the issuer is validated with the signature.
+    let mut unverified = Validation::new(header.alg);
+    unverified.insecure_disable_signature_validation();
+    unverified.validate_aud = false;
+    unverified.validate_exp = false;
+    unverified.required_spec_claims.clear();
+    let iss = decode::<Unverified>(&token, &DecodingKey::from_secret(&[]), &unverified)?
+        .claims
+        .iss;
+    let (key, issuer) = state.jwks().key(&iss, &kid).await?;
     let key = DecodingKey::from_jwk(&key.to_jwk()).unwrap();
 
+    // The token must be issued by the realm that published the key.
     let mut validation = Validation::new(header.alg);
     validation.set_audience(&[AUDIENCE]);
+    validation.set_issuer(&[&issuer.issuer]);
 
     let claims = decode::<Claims>(&token, &key, &validation)?.claims;
-    request.extensions_mut().insert(claims.to_context());
+    if claims.tenant.is_some_and(|t| t != issuer.tenant) {
+        warn!(
+            "Token of {} with the tenant {:?}, expected {}.",
+            issuer.issuer, claims.tenant, issuer.tenant
+        );
+        return Err(Error::TokenInvalid);
+    }
+    request
+        .extensions_mut()
+        .insert(claims.to_context(issuer.tenant));
 
     Ok(next.run(request).await)
 }
 
+/// Role required for a route, by method and path.
+fn permission(method: &Method, path: &str) -> Role {
+    match (method, path) {
+        (_, path) if path.starts_with("/api/admin/") => Role::System,
+        (&Method::GET, _) => Role::Viewer,
+        (_, "/api/keys" | "/api/keys/{key}") => Role::Viewer,
+        (&Method::DELETE, "/api/projects/{project}") => Role::Admin,
+        (&Method::PUT, "/api/impacts") => Role::Admin,
+        (&Method::POST, "/api/projects/{project}/frontiers/{frontier}/baseline") => Role::Reviewer,
+        _ => Role::Analyst,
+    }
+}
+
+pub async fn authorize(
+    context: Option<Context>,
+    path: MatchedPath,
+    request: Request<Body>,
+    next: Next,
+) -> Result<Response, Error> {
+    let role = permission(request.method(), path.as_str());
+    trace!(
+        "Authorizing {} {} for the role {:?}.",
+        request.method(),
+        path.as_str(),
+        role
+    );
+
+    match context {
+        Some(ctx) if ctx.has(role) => Ok(next.run(request).await),
+        _ => Err(Error::Forbidden),
+    }
+}
+
 pub async fn user_register(
     context: Option<Context>,
     State(state): State<Arc<AppState>>,
     request: Request<Body>,
     next: Next,
 ) -> Result<Response, Error> {
-    debug!("Registering the new user.");
-
     let ctx = context.unwrap();
+    if state.user_known(&ctx).await {
+        return Ok(next.run(request).await);
+    }
 
-    let db = state.connection(ctx.tenant()).await?;
+    debug!("Registering the user.");
+    let db = state.connection(&ctx).await?;
     let user = match Users::find_by_id(*ctx.id()).one(&db).await {
         Ok(u) => u,
         Err(_) => return Err(Error::DatabaseConnection),
     };
-    if user.is_none() {
-        let u = users::ActiveModel {
-            user: Set(ctx.id().clone()),
-            name: Set(ctx.name().to_string()),
-            tenant: Set(ctx.tenant().clone()),
-            time: Set(Utc::now().into()),
-            email: Set(ctx.email().to_string()),
-        };
-        let _ = match u.insert(&db).await {
-            Ok(v) => info!("New User: {:?}", v),
-            Err(_) => return Err(Error::RegisterUser),
-        };
-        match db.commit().await {
-            Ok(it) => it,
-            Err(_) => return Err(Error::DatabaseTransaction),
-        };
+    match user {
+        None => {
+            let u = users::ActiveModel {
+                user: Set(*ctx.id()),
+                name: Set(ctx.name().to_string()),
+                tenant: Set(*ctx.tenant()),
+                time: Set(Utc::now().into()),
+                email: Set(ctx.email().to_string()),
+            };
+            match u.insert(&db).await {
+                Ok(v) => info!("New User: {:?}", v),
+                Err(_) => return Err(Error::RegisterUser),
+            };
+        }
+        // Name and e-mail changed on the issuer.
+        Some(u) if u.name != ctx.name() || u.email != ctx.email() => {
+            let mut u: users::ActiveModel = u.into();
+            u.name = Set(ctx.name().to_string());
+            u.email = Set(ctx.email().to_string());
+            match u.update(&db).await {
+                Ok(v) => info!("User updated: {:?}", v),
+                Err(_) => return Err(Error::RegisterUser),
+            };
+        }
+        Some(_) => (),
     }
+    match db.commit().await {
+        Ok(it) => it,
+        Err(_) => return Err(Error::DatabaseTransaction),
+    };
+    state.user_registered(&ctx).await;
 
     Ok(next.run(request).await)
 }
@@ -3,6 +3,10 @@ use std::{path::Path, str::FromStr};
 use axum::http::uri::Scheme;
 use config::{Config, File};
 use log::info;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::model::sea_orm_active_enums::TenantTier;
 
 #[derive(Debug, Clone)]
 pub struct ConfigurationDatabase {
@@ -11,6 +15,8 @@ pub struct ConfigurationDatabase {
     pub port: u16,
     pub username: String,
     pub password: String,
+    pub system_username: String,
+    pub system_password: String,
     pub name: String,
     pub connections_max: u32,
     pub connections_min: u32,
@@ -29,14 +35,85 @@ pub struct Empiricals {
     pub testing: i32,
 }
 
+#[derive(Debug, Clone)]
+pub struct Impacts {
+    pub inclusion: i32,
+    pub alteration: i32,
+    pub exclusion: i32,
+    pub testing: i32,
+    pub corrective: i32,
+}
+
+/// Issuer (realm) of the tokens and the Tenant of its users.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Issuer {
+    pub issuer: String,
+    pub tenant: Uuid,
+}
+
+#[derive(Debug, Clone)]
+pub struct Quota {
+    pub projects: i64,
+    pub frontiers: i64,
+    pub functions: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Quotas {
+    pub gold: Quota,
+    pub silver: Quota,
+    pub bronze: Quota,
+}
+
+impl Quotas {
+    pub fn tier(&self, tier: &TenantTier) -> &Quota {
+        match tier {
+            TenantTier::Gold => &self.gold,
+            TenantTier::Silver => &self.silver,
+            TenantTier::Bronze => &self.bronze,
+        }
+    }
+}
+
+/// Logo of a Tenant on the reports.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Logo {
+    pub tenant: Uuid,
+    pub logo: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Reports {
+    /// Path of the logo for the Tenants without their own.
+    pub logo: String,
+    pub logos: Vec<Logo>,
+}
+
+impl Reports {
+    /// Path of the logo on the reports of the Tenant.
+    pub fn logo_of(&self, tenant: &Uuid) -> &str {
+        self.logos
+            .iter()
+            .find(|l| l.tenant == *tenant)
+            .map_or(&self.logo, |l| &l.logo)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Configuration {
     pub scheme: Scheme,
     pub authority: String,
     pub port: u16,
-    pub jwks: Vec<String>,
+    pub issuers: Vec<Issuer>,
+    pub jwks_refresh: u64,
+    pub jwks_throttle: u64,
+    /// Built-in token issuer for the development (`dev-auth` feature).
+    pub dev_auth: bool,
     pub database: ConfigurationDatabase,
     pub empiricals: Empiricals,
+    pub impacts: Impacts,
+    pub quotas: Quotas,
+    pub reports: Reports,
 }
 
 pub fn prepare() -> Configuration {
@@ -51,13 +128,18 @@ pub fn prepare() -> Configuration {
         scheme: Scheme::from_str(scheme.as_str()).unwrap(),
         authority: settings.get("authority").unwrap(),
         port: settings.get("port").unwrap(),
-        jwks: settings.get("jwks").unwrap(),
+        issuers: settings.get("issuers").unwrap(),
+        jwks_refresh: settings.get("jwks_refresh").unwrap(),
+        jwks_throttle: settings.get("jwks_throttle").unwrap(),
+        dev_auth: settings.get("dev_auth").unwrap(),
         database: ConfigurationDatabase {
             engine: settings.get("database.engine").unwrap(),
             server: settings.get("database.server").unwrap(),
             port: settings.get("database.port").unwrap(),
             username: settings.get("database.username").unwrap(),
             password: settings.get("database.password").unwrap(),
+            system_username: settings.get("database.system_username").unwrap(),
+            system_password: settings.get("database.system_password").unwrap(),
             name: settings.get("database.name").unwrap(),
             connections_max: settings.get("database.connections_max").unwrap(),
             connections_min: settings.get("database.connections_min").unwrap(),
@@ -73,5 +155,29 @@ pub fn prepare() -> Configuration {
             planning: settings.get("empiricals.planning").unwrap(),
             testing: settings.get("empiricals.testing").unwrap(),
         },
+        impacts: Impacts {
+            inclusion: settings.get("impacts.inclusion").unwrap(),
+            alteration: settings.get("impacts.alteration").unwrap(),
+            exclusion: settings.get("impacts.exclusion").unwrap(),
+            testing: settings.get("impacts.testing").unwrap(),
+            corrective: settings.get("impacts.corrective").unwrap(),
+        },
+        quotas: Quotas {
+            gold: quota(&settings, "gold"),
+            silver: quota(&settings, "silver"),
+            bronze: quota(&settings, "bronze"),
+        },
+        reports: Reports {
+            logo: settings.get("reports.logo").unwrap(),
+            logos: settings.get("reports.logos").unwrap_or_default(),
+        },
+    }
+}
+
+fn quota(settings: &Config, tier: &str) -> Quota {
+    Quota {
+        projects: settings.get(&format!("quotas.{tier}.projects")).unwrap(),
+        frontiers: settings.get(&format!("quotas.{tier}.frontiers")).unwrap(),
+        functions: settings.get(&format!("quotas.{tier}.functions")).unwrap(),
     }
 }
@@ -7,21 +7,74 @@ use uuid::Uuid;
 
 use crate::error::Error;
 
+/// Roles of the users, from the lowest to the highest permission.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Role {
+    /// Read only access.
+    Viewer,
+    /// Counts the function points (create, update and remove).
+    Analyst,
+    /// Approves the counting (baselines).
+    Reviewer,
+    /// Manages the Projects and the Tenant settings.
+    Admin,
+    /// Manages all the Tenants (only for the default Tenant).
+    System,
+}
+
+impl Role {
+    /// Role for a realm or client role name of the token.
+    pub fn from_name(name: &str) -> Option<Role> {
+        match name {
+            "viewer" => Some(Role::Viewer),
+            "analyst" => Some(Role::Analyst),
+            "reviewer" => Some(Role::Reviewer),
+            "admin" | "administrator" => Some(Role::Admin),
+            "system" => Some(Role::System),
+            _ => None,
+        }
+    }
+
+    /// Name of the role, as informed by the tokens.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Role::Viewer => "viewer",
+            Role::Analyst => "analyst",
+            Role::Reviewer => "reviewer",
+            Role::Admin => "admin",
+            Role::System => "system",
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Context {
     id: Uuid,
     tenant: Uuid,
     name: String,
     email: String,
+    roles: Vec<Role>,
+    /// API key used by the User, instead of a token.
+    key: Option<Uuid>,
 }
 
 impl Context {
-    pub fn new(id: Uuid, tenant: Uuid, name: String, email: String) -> Self {
+    pub fn new(id: Uuid, tenant: Uuid, name: String, email: String, roles: Vec<Role>) -> Self {
         Self {
             id,
             tenant,
             name,
             email,
+            roles,
+            key: None,
+        }
+    }
+
+    /// Context authenticated by an API key of the User.
+    pub fn with_key(self, key: Uuid) -> Self {
+        Self {
+            key: Some(key),
+            ..self
         }
     }
 
@@ -40,6 +93,24 @@ impl Context {
     pub fn email(&self) -> &str {
         &self.email.as_str()
     }
+
+    pub fn roles(&self) -> &[Role] {
+        &self.roles
+    }
+
+    pub fn key(&self) -> Option<&Uuid> {
+        self.key.as_ref()
+    }
+
+    /// Checks if the user has the role, or a higher one.
+    ///
+    /// The system role is granted only to the users of the default Tenant.
+    pub fn has(&self, role: Role) -> bool {
+        self.roles
+            .iter()
+            .filter(|r| **r != Role::System || self.tenant.is_nil())
+            .any(|r| *r >= role)
+    }
 }
 
 impl<S: Send + Sync> FromRequestParts<S> for Context {
@@ -0,0 +1,196 @@
+//! Built-in token issuer for the development, without the Keycloak (`dev-auth` feature).
+//!
+//! Each Tenant of the configured issuers has a realm, served by the fpa-server itself,
+//! with the discovery, the keys and an endpoint to request the tokens of any user.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    routing::{get, post},
+    Json, Router,
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::Utc;
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use log::{info, warn};
+use rsa::{pkcs1::EncodeRsaPrivateKey, traits::PublicKeyParts, RsaPrivateKey};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use crate::{
+    configuration::{Configuration, Issuer},
+    error::Error,
+};
+
+const BITS: usize = 2048;
+const AUDIENCE: &str = "account";
+const EXPIRATION: i64 = 3600;
+
+/// Signing key of the development realms.
+pub struct DevAuth {
+    base: String,
+    tenants: Vec<Uuid>,
+    encoding: EncodingKey,
+    n: String,
+    e: String,
+}
+
+impl DevAuth {
+    fn new(config: &Configuration) -> Self {
+        info!("Generating the key of the development issuer...");
+        let key = RsaPrivateKey::new(&mut rand::thread_rng(), BITS).unwrap();
+        let der = key.to_pkcs1_der().unwrap();
+
+        let mut tenants: Vec<Uuid> = config.issuers.iter().map(|i| i.tenant).collect();
+        tenants.sort();
+        tenants.dedup();
+
+        Self {
+            base: format!("http://localhost:{}/dev/realms", config.port),
+            tenants,
+            encoding: EncodingKey::from_rsa_der(der.as_bytes()),
+            n: URL_SAFE_NO_PAD.encode(key.n().to_bytes_be()),
+            e: URL_SAFE_NO_PAD.encode(key.e().to_bytes_be()),
+        }
+    }
+
+    /// Issuers of the development realms, one for each Tenant.
+    fn issuers(&self) -> Vec<Issuer> {
+        self.tenants
+            .iter()
+            .map(|tenant| Issuer {
+                issuer: format!("{}/{}", self.base, tenant),
+                tenant: *tenant,
+            })
+            .collect()
+    }
+
+    fn issuer(&self, tenant: &Uuid) -> Result<String, Error> {
+        match self.tenants.contains(tenant) {
+            true => Ok(format!("{}/{}", self.base, tenant)),
+            false => Err(Error::NotFound),
+        }
+    }
+}
+
+/// Registers the development issuers, when enabled by the configuration.
+pub fn prepare(config: &mut Configuration) -> Option<Arc<DevAuth>> {
+    if !config.dev_auth {
+        return None;
+    }
+
+    warn!("Development issuer enabled, any user is authenticated!");
+    let dev = DevAuth::new(config);
+    config.issuers.extend(dev.issuers());
+    Some(Arc::new(dev))
+}
+
+pub fn router(dev: Arc<DevAuth>) -> Router {
+    Router::new()
+        .route(
+            "/dev/realms/{tenant}/.well-known/openid-configuration",
+            get(discovery),
+        )
+        .route("/dev/realms/{tenant}/certs", get(certs))
+        .route("/dev/realms/{tenant}/token", post(token))
+        .with_state(dev)
+}
+
+async fn discovery(
+    Path(tenant): Path<Uuid>,
+    State(dev): State<Arc<DevAuth>>,
+) -> Result<Json<Value>, Error> {
+    let issuer = dev.issuer(&tenant)?;
+    Ok(Json(json!({
+        "issuer": issuer,
+        "jwks_uri": format!("{}/certs", issuer),
+        "token_endpoint": format!("{}/token", issuer),
+    })))
+}
+
+async fn certs(
+    Path(tenant): Path<Uuid>,
+    State(dev): State<Arc<DevAuth>>,
+) -> Result<Json<Value>, Error> {
+    dev.issuer(&tenant)?;
+    Ok(Json(json!({
+        "keys": [{
+            "kid": kid(&tenant),
+            "kty": "RSA",
+            "alg": "RS256",
+            "use": "sig",
+            "n": dev.n,
+            "e": dev.e,
+            "x5c": [],
+            "x5t": "",
+            "x5t#S256": "",
+        }]
+    })))
+}
+
+/// User of the requested token.
+#[derive(Debug, Deserialize)]
+struct TokenParam {
+    user: Uuid,
+    name: String,
+    email: String,
+    /// Realm roles (viewer, analyst, reviewer, administrator or system).
+    #[serde(default)]
+    roles: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Access {
+    roles: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Claims {
+    iss: String,
+    aud: &'static str,
+    sub: Uuid,
+    iat: i64,
+    exp: i64,
+    tenant: Uuid,
+    name: String,
+    email: String,
+    realm_access: Access,
+}
+
+async fn token(
+    Path(tenant): Path<Uuid>,
+    State(dev): State<Arc<DevAuth>>,
+    Json(params): Json<TokenParam>,
+) -> Result<Json<Value>, Error> {
+    let now = Utc::now().timestamp();
+    let claims = Claims {
+        iss: dev.issuer(&tenant)?,
+        aud: AUDIENCE,
+        sub: params.user,
+        iat: now,
+        exp: now + EXPIRATION,
+        tenant,
+        name: params.name,
+        email: params.email,
+        realm_access: Access {
+            roles: params.roles,
+        },
+    };
+
+    let mut header = Header::new(Algorithm::RS256);
+    header.kid = Some(kid(&tenant));
+    let token = encode(&header, &claims, &dev.encoding)?;
+
+    Ok(Json(json!({
+        "access_token": token,
+        "token_type": "Bearer",
+        "expires_in": EXPIRATION,
+    })))
+}
+
+/// Key identifier of the realm, the issuer of a key is selected by its kid.
+fn kid(tenant: &Uuid) -> String {
+    format!("dev-{}", tenant)
+}
@@ -13,10 +13,22 @@ use utoipa::{
     tags(
         [name = "Status", description = "Check service health."],
         [name = "Projects", description = "FPA Projects management."],
+        [name = "Members", description = "Users with access to the Project."],
         [name = "Frontiers", description = "Analysis frontier."],
         [name = "Empiricals", description = "Empiricals Adjustments Factors."],
         [name = "Factors", description = "Adjustments Factors."],
         [name = "Functions", description = "Function management for analysis."],
+        [name = "Baselines", description = "Application baselines maintained by enhancements."],
+        [name = "Snapshots", description = "Immutable copies of the counting of a Project."],
+        [name = "Impacts", description = "Impact factors (SISP) of the Tenant."],
+        [name = "Summaries", description = "Function Points counting summary."],
+        [name = "Estimates", description = "Effort, schedule and cost estimates."],
+        [name = "Archives", description = "Export and import of Projects between Tenants."],
+        [name = "Reports", description = "Counting reports of the Project for the clients."],
+        [name = "Audit", description = "Changes made on the Project."],
+        [name = "Users", description = "Users of the Tenant."],
+        [name = "Keys", description = "API keys of the User, for pipelines and scripts."],
+        [name = "Administration", description = "Tenants management (system users only)."],
     ),
     paths(
         crate::handlers::health,
@@ -25,6 +37,10 @@ use utoipa::{
         crate::handlers::projects::create,
         crate::handlers::projects::update,
         crate::handlers::projects::remove,
+        crate::handlers::members::list,
+        crate::handlers::members::invite,
+        crate::handlers::members::remove,
+        crate::handlers::members::transfer,
         crate::handlers::empiricals::list,
         crate::handlers::empiricals::update,
         crate::handlers::factors::list,
@@ -39,6 +55,34 @@ use utoipa::{
         crate::handlers::functions::create,
         crate::handlers::functions::update,
         crate::handlers::functions::remove,
+        crate::handlers::drafts::ddl,
+        crate::handlers::drafts::openapi,
+        crate::handlers::drafts::reviewed,
+        crate::handlers::imports::csv,
+        crate::handlers::baselines::apply,
+        crate::handlers::snapshots::list,
+        crate::handlers::snapshots::by_id,
+        crate::handlers::snapshots::create,
+        crate::handlers::snapshots::diff,
+        crate::handlers::impacts::list,
+        crate::handlers::impacts::update,
+        crate::handlers::summaries::by_frontier,
+        crate::handlers::estimates::by_frontier,
+        crate::handlers::archives::export,
+        crate::handlers::archives::import,
+        crate::handlers::reports::xlsx,
+        crate::handlers::reports::pdf,
+        crate::handlers::audits::list,
+        crate::handlers::users::list,
+        crate::handlers::users::me,
+        crate::handlers::keys::list,
+        crate::handlers::keys::create,
+        crate::handlers::keys::remove,
+        crate::handlers::admin::list,
+        crate::handlers::admin::by_id,
+        crate::handlers::admin::create,
+        crate::handlers::admin::update,
+        crate::handlers::admin::usage,
     ),
     components(
         schemas(
@@ -46,17 +90,37 @@ use utoipa::{
             crate::model::sea_orm_active_enums::FactorType,
             crate::model::sea_orm_active_enums::InfluenceType,
             crate::model::sea_orm_active_enums::FunctionType,
+            crate::model::sea_orm_active_enums::CountingType,
+            crate::model::sea_orm_active_enums::CountingMethod,
+            crate::model::sea_orm_active_enums::ChangeType,
+            crate::model::sea_orm_active_enums::ImpactType,
+            crate::model::sea_orm_active_enums::MemberType,
+            crate::model::sea_orm_active_enums::TenantStatus,
+            crate::model::sea_orm_active_enums::TenantTier,
+            crate::model::sea_orm_active_enums::KeyScope,
+            crate::model::sea_orm_active_enums::AuditEntity,
+            crate::model::sea_orm_active_enums::AuditAction,
+            crate::analysis::complexity::Complexity,
+            crate::model::tenants::Model,
             crate::model::users::Model,
             crate::model::projects::Model,
+            crate::model::members::Model,
             crate::model::empiricals::Model,
             crate::model::factors::Model,
             crate::model::frontiers::Model,
             crate::model::versions::Model,
+            crate::model::impacts::Model,
+            crate::model::snapshots::Model,
+            crate::model::keys::Model,
+            crate::model::audits::Model,
             crate::error::ErrorResponse,
             crate::handlers::projects::ProjectParam,
+            crate::handlers::members::MemberParam,
+            crate::handlers::members::OwnerParam,
             crate::handlers::empiricals::EmpiricalParam,
             crate::handlers::factors::FactorParam,
             crate::handlers::frontiers::FrontierParam,
+            crate::handlers::impacts::ImpactParam,
             crate::handlers::functions::FunctionALI,
             crate::handlers::functions::FunctionALIParam,
             crate::handlers::functions::FunctionAIE,
@@ -70,6 +134,42 @@ use utoipa::{
             crate::handlers::functions::FunctionData,
             crate::handlers::functions::Function,
             crate::handlers::functions::FunctionParam,
+            crate::handlers::drafts::DDLParam,
+            crate::handlers::drafts::OpenAPIParam,
+            crate::drafts::DraftFunction,
+            crate::drafts::DraftRLR,
+            crate::drafts::DraftDER,
+            crate::drafts::DraftALR,
+            crate::drafts::DraftDET,
+            crate::handlers::imports::CSVError,
+            crate::handlers::imports::CSVErrorType,
+            crate::handlers::baselines::BaselineParam,
+            crate::handlers::baselines::Baseline,
+            crate::handlers::archives::Archive,
+            crate::handlers::archives::ArchiveFrontier,
+            crate::handlers::archives::ArchiveFactor,
+            crate::handlers::archives::ArchiveEmpirical,
+            crate::handlers::archives::ArchiveFunction,
+            crate::handlers::archives::ArchiveDET,
+            crate::handlers::archives::ArchiveReference,
+            crate::handlers::archives::ArchiveConflict,
+            crate::handlers::snapshots::SnapshotParam,
+            crate::handlers::snapshots::SnapshotFactor,
+            crate::handlers::snapshots::SnapshotEmpirical,
+            crate::handlers::snapshots::SnapshotFrontier,
+            crate::handlers::snapshots::SnapshotContent,
+            crate::handlers::snapshots::DiffType,
+            crate::handlers::snapshots::SnapshotDiffFunction,
+            crate::handlers::snapshots::SnapshotDiff,
+            crate::handlers::summaries::SummaryFunction,
+            crate::handlers::summaries::SummaryEnhancement,
+            crate::handlers::summaries::Summary,
+            crate::handlers::estimates::EstimatePhase,
+            crate::handlers::estimates::Estimate,
+            crate::handlers::keys::KeyParam,
+            crate::handlers::keys::KeyCreated,
+            crate::handlers::admin::TenantParam,
+            crate::handlers::admin::TenantUsage,
         ),
     ),
     modifiers(&SecuritySchemas, &InfoModifier),
@@ -0,0 +1,283 @@
+//! Data Functions drafted from the `CREATE TABLE` statements of a database.
+//!
+//! Each table is an RLR with a DER for each column. A table identified by a foreign key to
+//! another table (its primary key includes the foreign key) is a dependent record of that
+//! table, grouped in the same ALI. The remaining tables are the ALIs.
+
+use std::collections::HashMap;
+
+use log::{trace, warn};
+use sqlparser::{
+    ast::{
+        AlterTableOperation, ColumnOption, CommentObject, ObjectName, Statement, TableConstraint,
+    },
+    dialect::{Dialect, GenericDialect, MySqlDialect, PostgreSqlDialect},
+    keywords::Keyword,
+    parser::{Parser, ParserError},
+    tokenizer::{Token, Tokenizer},
+};
+
+use super::{DraftDER, DraftFunction, DraftRLR};
+use crate::{error::Error, model::sea_orm_active_enums::FunctionType};
+
+/// Table of the DDL, with its keys.
+#[derive(Debug, Default)]
+struct Table {
+    name: String,
+    description: Option<String>,
+    columns: Vec<(String, Option<String>)>,
+    primary: Vec<String>,
+    foreigns: Vec<(Vec<String>, String)>,
+}
+
+impl Table {
+    /// Table identified by a foreign key, the first one included in its primary key.
+    fn parent(&self) -> Option<&String> {
+        self.foreigns
+            .iter()
+            .find(|(columns, table)| {
+                *table != self.name && columns.iter().all(|c| self.primary.contains(c))
+            })
+            .map(|(_, table)| table)
+    }
+
+    /// Table referenced by a column that is only a foreign key (or the link to the parent).
+    fn foreign(&self, column: &str) -> Option<String> {
+        let parent = self.parent();
+        self.foreigns
+            .iter()
+            .find(|(columns, table)| {
+                columns.iter().any(|c| c == column)
+                    && (!self.primary.iter().any(|c| c == column) || Some(table) == parent)
+            })
+            .map(|(_, table)| table.clone())
+    }
+
+    /// Registers the primary and foreign keys, ignoring the other constraints.
+    fn constraint(&mut self, constraint: TableConstraint) {
+        match constraint {
+            TableConstraint::PrimaryKey { columns, .. } => {
+                self.primary.extend(columns.into_iter().map(|c| c.value))
+            }
+            TableConstraint::ForeignKey {
+                columns,
+                foreign_table,
+                ..
+            } => self.foreigns.push((
+                columns.into_iter().map(|c| c.value).collect(),
+                name(&foreign_table),
+            )),
+            _ => (),
+        }
+    }
+}
+
+/// Last part of a qualified name (without the schema).
+fn name(object: &ObjectName) -> String {
+    object.0.last().map(|i| i.value.clone()).unwrap_or_default()
+}
+
+/// Statements of the DDL, one by one, so the statements not supported by the parser are skipped.
+fn statements(ddl: &str) -> Result<Vec<Statement>, Error> {
+    let dialect = GenericDialect {};
+    let tokens = match Tokenizer::new(&dialect, ddl).tokenize() {
+        Ok(v) => v,
+        Err(e) => {
+            warn!("Invalid DDL: {}", e);
+            return Err(Error::DDLInvalid);
+        }
+    };
+
+    let dialects: [&dyn Dialect; 3] = [&PostgreSqlDialect {}, &MySqlDialect {}, &dialect];
+    let mut result = Vec::<Statement>::new();
+    for tokens in tokens.split(|t| *t == Token::SemiColon) {
+        let words: Vec<Keyword> = tokens
+            .iter()
+            .filter_map(|t| match t {
+                Token::Word(w) => Some(w.keyword),
+                _ => None,
+            })
+            .take(4)
+            .collect();
+        if words.is_empty() {
+            continue;
+        }
+
+        // The dialect is not informed, the first one to accept the statement is used.
+        let mut statement = Err(ParserError::ParserError(String::new()));
+        for dialect in dialects {
+            statement = Parser::new(dialect)
+                .with_tokens(tokens.to_vec())
+                .parse_statement();
+            if statement.is_ok() {
+                break;
+            }
+        }
+        match statement {
+            Ok(v) => result.push(v),
+            Err(e) if words[0] == Keyword::CREATE && words.contains(&Keyword::TABLE) => {
+                warn!("Invalid DDL: {}", e);
+                return Err(Error::DDLInvalid);
+            }
+            Err(e) => trace!("Statement ignored: {}", e),
+        }
+    }
+
+    Ok(result)
+}
+
+/// Drafts the ALIs for the tables of the DDL. Other statements are ignored, except the comments
+/// and the keys added to the tables.
+pub fn parse(ddl: &str) -> Result<Vec<DraftFunction>, Error> {
+    let mut tables = Vec::<Table>::new();
+    let mut comments = Vec::<(CommentObject, ObjectName, String)>::new();
+    for statement in statements(ddl)? {
+        match statement {
+            Statement::CreateTable(create) => {
+                let mut table = Table {
+                    name: name(&create.name),
+                    ..Default::default()
+                };
+                for column in create.columns {
+                    let mut description = None;
+                    for option in column.options {
+                        match option.option {
+                            ColumnOption::Unique {
+                                is_primary: true, ..
+                            } => table.primary.push(column.name.value.clone()),
+                            ColumnOption::ForeignKey { foreign_table, .. } => table
+                                .foreigns
+                                .push((vec![column.name.value.clone()], name(&foreign_table))),
+                            ColumnOption::Comment(comment) => description = Some(comment),
+                            _ => (),
+                        }
+                    }
+                    table.columns.push((column.name.value, description));
+                }
+                for constraint in create.constraints {
+                    table.constraint(constraint);
+                }
+                tables.push(table);
+            }
+            Statement::Comment {
+                object_type,
+                object_name,
+                comment: Some(comment),
+                ..
+            } => comments.push((object_type, object_name, comment)),
+            Statement::AlterTable {
+                name: table,
+                operations,
+                ..
+            } => {
+                // Keys added after the creation of the table (the usual dump layout).
+                let Some(table) = tables.iter_mut().find(|t| t.name == name(&table)) else {
+                    continue;
+                };
+                for operation in operations {
+                    if let AlterTableOperation::AddConstraint(constraint) = operation {
+                        table.constraint(constraint);
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    if tables.is_empty() {
+        return Err(Error::DDLInvalid);
+    }
+
+    for (object, target, comment) in comments {
+        match object {
+            CommentObject::Table => {
+                if let Some(table) = tables.iter_mut().find(|t| t.name == name(&target)) {
+                    table.description = Some(comment);
+                }
+            }
+            CommentObject::Column if target.0.len() >= 2 => {
+                let column = &target.0[target.0.len() - 1].value;
+                let table = &target.0[target.0.len() - 2].value;
+                if let Some(table) = tables.iter_mut().find(|t| t.name == *table) {
+                    if let Some(c) = table.columns.iter_mut().find(|c| c.0 == *column) {
+                        c.1 = Some(comment);
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    Ok(group(tables))
+}
+
+/// Groups the dependent tables with the table they depend on, one ALI for each group.
+fn group(tables: Vec<Table>) -> Vec<DraftFunction> {
+    let names: Vec<String> = tables.iter().map(|t| t.name.clone()).collect();
+    let parents: HashMap<String, String> = tables
+        .iter()
+        .filter_map(|t| {
+            t.parent()
+                .filter(|p| names.contains(p))
+                .map(|p| (t.name.clone(), p.clone()))
+        })
+        .collect();
+
+    let root = |table: &String| {
+        let mut current = table.clone();
+        let mut visited = vec![current.clone()];
+        while let Some(parent) = parents.get(&current) {
+            if visited.contains(parent) {
+                break;
+            }
+            visited.push(parent.clone());
+            current = parent.clone();
+        }
+        current
+    };
+
+    let mut result = Vec::<DraftFunction>::new();
+    for table in tables.iter() {
+        let root = root(&table.name);
+        trace!("Table {} grouped on {}.", table.name, root);
+
+        let rlr = DraftRLR {
+            name: table.name.clone(),
+            description: table.description.clone(),
+            ders: table
+                .columns
+                .iter()
+                .map(|(column, description)| DraftDER {
+                    name: column.clone(),
+                    description: description.clone(),
+                    foreign: table.foreign(column),
+                })
+                .collect(),
+        };
+        let index = match result.iter().position(|f| f.name == root) {
+            Some(v) => v,
+            None => {
+                result.push(DraftFunction {
+                    id: None,
+                    r#type: FunctionType::ALI,
+                    name: root.clone(),
+                    description: None,
+                    rlrs: Vec::new(),
+                    alrs: Vec::new(),
+                    dets: Vec::new(),
+                });
+                result.len() - 1
+            }
+        };
+        if table.name == root {
+            result[index].description = table.description.clone();
+        }
+        result[index].rlrs.push(rlr);
+    }
+
+    // The RLR of the table grouping the others goes first.
+    for function in result.iter_mut() {
+        function.rlrs.sort_by_key(|r| r.name != function.name);
+    }
+
+    result
+}
@@ -0,0 +1,165 @@
+//! Functions drafted from the artifacts of the application, reviewed before their creation.
+
+pub mod ddl;
+pub mod openapi;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    handlers::functions::{
+        DERReference, FunctionAIEParam, FunctionALIParam, FunctionCEParam, FunctionEEParam,
+        FunctionParam, FunctionSEParam, ALR, DER, DET, RLR,
+    },
+    model::sea_orm_active_enums::FunctionType,
+};
+
+/// Data Function of the Frontier, for association with the drafted Transaction Functions.
+#[derive(Debug, Clone)]
+pub struct DataFunction {
+    /// Unique Identifier of the Data Function.
+    pub id: Uuid,
+    /// Name of the Data Function.
+    pub name: String,
+    /// Names of the RLR and the DER of each DER in the Data Function.
+    pub ders: Vec<(String, String)>,
+}
+
+/// DER proposed for a Function.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DraftDER {
+    /// Name of the DER.
+    pub name: String,
+    /// Description of the DER.
+    pub description: Option<String>,
+    /// Record referenced by an element that is only a foreign key, to review before the confirmation.
+    pub foreign: Option<String>,
+}
+
+/// RLR proposed for a Data Function.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DraftRLR {
+    /// Name of the RLR.
+    pub name: String,
+    /// Description of the RLR.
+    pub description: Option<String>,
+    /// Set of DERs of the RLR.
+    #[serde(default)]
+    pub ders: Vec<DraftDER>,
+}
+
+/// Data Function of the Frontier associated with a drafted Transaction Function.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DraftALR {
+    /// Unique Identifier of the Data Function.
+    pub id: Uuid,
+    /// Name of the Data Function.
+    pub name: String,
+}
+
+/// DET proposed for a Transaction Function.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DraftDET {
+    /// Name of the DET.
+    pub name: String,
+    /// Description of the DET.
+    pub description: Option<String>,
+    /// DER with the same name on an associated Data Function.
+    pub reference: Option<DERReference>,
+}
+
+/// Function proposed from an artifact, or reviewed from the preview to be created.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DraftFunction {
+    /// Unique Identifier of the Function, after the confirmation.
+    pub id: Option<Uuid>,
+    /// Type of the Function.
+    pub r#type: FunctionType,
+    /// Name of the Function.
+    pub name: String,
+    /// Description of the Function.
+    pub description: Option<String>,
+    /// Set of RLRs of the Data Function.
+    #[serde(default)]
+    pub rlrs: Vec<DraftRLR>,
+    /// Set of Data Functions of the Transaction Function.
+    #[serde(default)]
+    pub alrs: Vec<DraftALR>,
+    /// Set of DETs of the Transaction Function.
+    #[serde(default)]
+    pub dets: Vec<DraftDET>,
+}
+
+impl DraftFunction {
+    /// Params to create the drafted Function.
+    pub fn param(&self) -> FunctionParam {
+        let rlrs = self
+            .rlrs
+            .iter()
+            .map(|rlr| RLR {
+                name: rlr.name.clone(),
+                description: rlr.description.clone(),
+                ders: rlr
+                    .ders
+                    .iter()
+                    .map(|der| DER {
+                        name: der.name.clone(),
+                        description: der.description.clone(),
+                    })
+                    .collect(),
+            })
+            .collect();
+        let alrs = self.alrs.iter().map(|alr| ALR { id: alr.id }).collect();
+        let dets = self
+            .dets
+            .iter()
+            .map(|det| DET {
+                name: det.name.clone(),
+                description: det.description.clone(),
+                reference: det.reference.clone(),
+            })
+            .collect();
+
+        match self.r#type {
+            FunctionType::AIE => FunctionParam::AIE(FunctionAIEParam {
+                name: self.name.clone(),
+                description: self.description.clone(),
+                change: None,
+                impact: None,
+                rlrs,
+            }),
+            FunctionType::EE => FunctionParam::EE(FunctionEEParam {
+                name: self.name.clone(),
+                description: self.description.clone(),
+                change: None,
+                impact: None,
+                alrs,
+                dets,
+            }),
+            FunctionType::CE => FunctionParam::CE(FunctionCEParam {
+                name: self.name.clone(),
+                description: self.description.clone(),
+                change: None,
+                impact: None,
+                alrs,
+                dets,
+            }),
+            FunctionType::SE => FunctionParam::SE(FunctionSEParam {
+                name: self.name.clone(),
+                description: self.description.clone(),
+                change: None,
+                impact: None,
+                alrs,
+                dets,
+            }),
+            FunctionType::ALI => FunctionParam::ALI(FunctionALIParam {
+                name: self.name.clone(),
+                description: self.description.clone(),
+                change: None,
+                impact: None,
+                rlrs,
+            }),
+        }
+    }
+}
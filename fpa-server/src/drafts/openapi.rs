@@ -0,0 +1,427 @@
+//! Transaction Functions drafted from the operations of an OpenAPI 3 document (JSON or YAML).
+//!
+//! Operations that change data (POST, PUT, PATCH and DELETE) are EEs. Queries (GET) are CEs, or
+//! SEs when they return derived data, as totals and summaries. The parameters and the properties
+//! of the request and response schemas are the DETs, and the Data Functions of the Frontier named
+//! as the path, the tags or the schemas of the operation are the ALRs.
+
+use log::{trace, warn};
+use openapiv3::{
+    MediaType, OpenAPI, Operation, Parameter, PathItem, ReferenceOr, Schema, SchemaKind,
+    StatusCode, Type,
+};
+
+use super::{DataFunction, DraftALR, DraftDET, DraftFunction};
+use crate::{
+    error::Error, handlers::functions::DERReference, model::sea_orm_active_enums::FunctionType,
+};
+
+/// Words of the names that indicate data derived by the operation.
+const DERIVED: [&str; 11] = [
+    "total",
+    "sum",
+    "count",
+    "average",
+    "avg",
+    "summary",
+    "report",
+    "statistic",
+    "stat",
+    "balance",
+    "percent",
+];
+
+/// Limit of nested schemas (and references) expanded.
+const DEPTH: usize = 8;
+
+/// Words of a name (camelCase, snake_case, kebab-case or text), in lower case and singular.
+fn words(text: &str) -> Vec<String> {
+    let mut result = Vec::<String>::new();
+    let mut current = String::new();
+    let mut previous: Option<char> = None;
+    for c in text.chars() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                result.push(std::mem::take(&mut current));
+            }
+        } else {
+            if c.is_uppercase()
+                && previous.is_some_and(|p| p.is_lowercase() || p.is_numeric())
+                && !current.is_empty()
+            {
+                result.push(std::mem::take(&mut current));
+            }
+            current.extend(c.to_lowercase());
+        }
+        previous = Some(c);
+    }
+    if !current.is_empty() {
+        result.push(current);
+    }
+
+    result
+        .into_iter()
+        .map(|word| {
+            if let Some(stem) = word.strip_suffix("ies") {
+                format!("{}y", stem)
+            } else if word.len() > 3 && word.ends_with('s') && !word.ends_with("ss") {
+                word[..word.len() - 1].to_owned()
+            } else {
+                word
+            }
+        })
+        .collect()
+}
+
+/// Key to compare the names, independent of the case, separators and plural.
+fn key(text: &str) -> String {
+    words(text).concat()
+}
+
+/// Name of the component of a reference (`#/components/schemas/Name`).
+fn component(reference: &str) -> &str {
+    reference.rsplit('/').next().unwrap_or(reference)
+}
+
+/// Item of a reference to the components of the document.
+fn resolve<'a, T>(
+    item: &'a ReferenceOr<T>,
+    components: impl Fn(&str) -> Option<&'a ReferenceOr<T>>,
+) -> Option<&'a T> {
+    let mut item = item;
+    for _ in 0..DEPTH {
+        match item {
+            ReferenceOr::Item(v) => return Some(v),
+            ReferenceOr::Reference { reference } => item = components(component(reference))?,
+        }
+    }
+    None
+}
+
+/// Content of a request or response, JSON preferred.
+fn media<'a>(
+    content: impl IntoIterator<Item = (&'a String, &'a MediaType)>,
+) -> Option<&'a MediaType> {
+    let content: Vec<_> = content.into_iter().collect();
+    content
+        .iter()
+        .find(|(kind, _)| kind.contains("json"))
+        .or(content.first())
+        .map(|(_, media)| *media)
+}
+
+/// Elements of an operation, found on its parameters and schemas.
+struct Elements<'a> {
+    api: &'a OpenAPI,
+    dets: Vec<DraftDET>,
+    schemas: Vec<String>,
+    stack: Vec<String>,
+    derived: bool,
+}
+
+impl<'a> Elements<'a> {
+    fn new(api: &'a OpenAPI) -> Self {
+        Elements {
+            api,
+            dets: Vec::new(),
+            schemas: Vec::new(),
+            stack: Vec::new(),
+            derived: false,
+        }
+    }
+
+    fn schema(&self, name: &str) -> Option<&'a ReferenceOr<Schema>> {
+        self.api.components.as_ref()?.schemas.get(name)
+    }
+
+    /// Registers a DET, once for each name. The data is derived if named as a calculation.
+    fn det(&mut self, name: &str, description: Option<String>, derivable: bool) {
+        if derivable && words(name).iter().any(|w| DERIVED.contains(&w.as_str())) {
+            trace!("Derived data: {}", name);
+            self.derived = true;
+        }
+        if !self.dets.iter().any(|d| d.name == name) {
+            self.dets.push(DraftDET {
+                name: name.to_owned(),
+                description,
+                reference: None,
+            });
+        }
+    }
+
+    /// Follows a reference to a schema, except the recursive ones.
+    fn reference(&mut self, reference: &str, name: Option<&str>, depth: usize, derivable: bool) {
+        let schema = component(reference);
+        if self.stack.iter().any(|s| s == schema) {
+            return;
+        }
+        if !self.schemas.iter().any(|s| s == schema) {
+            self.schemas.push(schema.to_owned());
+        }
+        if let Some(item) = self.schema(schema) {
+            self.stack.push(schema.to_owned());
+            self.item(item, name, depth + 1, derivable);
+            self.stack.pop();
+        }
+    }
+
+    fn item(
+        &mut self,
+        item: &'a ReferenceOr<Schema>,
+        name: Option<&str>,
+        depth: usize,
+        derivable: bool,
+    ) {
+        match item {
+            ReferenceOr::Reference { reference } => {
+                self.reference(reference, name, depth, derivable)
+            }
+            ReferenceOr::Item(schema) => self.walk(schema, name, depth, derivable),
+        }
+    }
+
+    fn boxed(
+        &mut self,
+        item: &'a ReferenceOr<Box<Schema>>,
+        name: Option<&str>,
+        depth: usize,
+        derivable: bool,
+    ) {
+        match item {
+            ReferenceOr::Reference { reference } => {
+                self.reference(reference, name, depth, derivable)
+            }
+            ReferenceOr::Item(schema) => self.walk(schema, name, depth, derivable),
+        }
+    }
+
+    /// Schema of a list, whose siblings are the page data (not derived).
+    fn list(&self, item: &'a ReferenceOr<Box<Schema>>) -> bool {
+        let schema = match item {
+            ReferenceOr::Item(schema) => Some(schema.as_ref()),
+            ReferenceOr::Reference { reference } => self
+                .schema(component(reference))
+                .and_then(|s| resolve(s, |n| self.schema(n))),
+        };
+        match schema.map(|s| &s.schema_kind) {
+            Some(SchemaKind::Type(Type::Array(_))) => true,
+            Some(SchemaKind::Any(any)) => any.items.is_some(),
+            _ => false,
+        }
+    }
+
+    /// The properties of the objects are the DETs, flattening the nested objects and lists.
+    fn walk(&mut self, schema: &'a Schema, name: Option<&str>, depth: usize, derivable: bool) {
+        if depth > DEPTH {
+            return;
+        }
+        let properties = match &schema.schema_kind {
+            SchemaKind::Type(Type::Object(object)) => Some(&object.properties),
+            SchemaKind::Any(any) if !any.properties.is_empty() => Some(&any.properties),
+            _ => None,
+        };
+        if let Some(properties) = properties {
+            let derivable = derivable && !properties.values().any(|p| self.list(p));
+            for (property, item) in properties {
+                self.boxed(item, Some(property), depth + 1, derivable);
+            }
+            return;
+        }
+
+        match &schema.schema_kind {
+            SchemaKind::Type(Type::Array(array)) if array.items.is_some() => {
+                self.boxed(array.items.as_ref().unwrap(), name, depth + 1, derivable)
+            }
+            SchemaKind::Any(any) if any.items.is_some() => {
+                self.boxed(any.items.as_ref().unwrap(), name, depth + 1, derivable)
+            }
+            SchemaKind::OneOf { one_of: items }
+            | SchemaKind::AllOf { all_of: items }
+            | SchemaKind::AnyOf { any_of: items } => {
+                for item in items {
+                    self.item(item, name, depth + 1, derivable);
+                }
+            }
+            SchemaKind::Any(any)
+                if !(any.all_of.is_empty() && any.one_of.is_empty() && any.any_of.is_empty()) =>
+            {
+                for item in any.all_of.iter().chain(&any.one_of).chain(&any.any_of) {
+                    self.item(item, name, depth + 1, derivable);
+                }
+            }
+            SchemaKind::Not { .. } => (),
+            _ => {
+                if let Some(name) = name {
+                    self.det(name, schema.schema_data.description.clone(), derivable);
+                }
+            }
+        }
+    }
+
+    /// Parameters of the path and the query, the headers and cookies are technical.
+    fn parameter(&mut self, item: &'a ReferenceOr<Parameter>) {
+        let Some(parameter) = resolve(item, |n| self.api.components.as_ref()?.parameters.get(n))
+        else {
+            return;
+        };
+        if let Parameter::Path { .. } | Parameter::Query { .. } = parameter {
+            let data = parameter.parameter_data_ref();
+            self.det(&data.name, data.description.clone(), false);
+        }
+    }
+
+    fn request(&mut self, operation: &'a Operation) {
+        let Some(body) = operation.request_body.as_ref().and_then(|item| {
+            resolve(item, |n| {
+                self.api.components.as_ref()?.request_bodies.get(n)
+            })
+        }) else {
+            return;
+        };
+        if let Some(schema) = media(&body.content).and_then(|m| m.schema.as_ref()) {
+            self.item(schema, None, 0, false);
+        }
+    }
+
+    /// Successful responses of the operation.
+    fn response(&mut self, operation: &'a Operation) {
+        for (status, item) in operation.responses.responses.iter() {
+            let success = match status {
+                StatusCode::Code(code) => (200..300).contains(code),
+                StatusCode::Range(range) => *range == 2,
+            };
+            if !success {
+                continue;
+            }
+            let Some(response) = resolve(item, |n| self.api.components.as_ref()?.responses.get(n))
+            else {
+                continue;
+            };
+            if let Some(schema) = media(&response.content).and_then(|m| m.schema.as_ref()) {
+                self.item(schema, None, 0, true);
+            }
+        }
+    }
+}
+
+/// Drafts the Transaction Function of an operation, only for the methods that handle data.
+fn draft(
+    api: &OpenAPI,
+    path: &str,
+    method: &str,
+    item: &PathItem,
+    operation: &Operation,
+    datas: &[DataFunction],
+) -> Option<DraftFunction> {
+    let mut r#type = match method {
+        "post" | "put" | "patch" | "delete" => FunctionType::EE,
+        "get" => FunctionType::CE,
+        _ => return None,
+    };
+
+    let mut elements = Elements::new(api);
+    for parameter in item.parameters.iter().chain(&operation.parameters) {
+        elements.parameter(parameter);
+    }
+    elements.request(operation);
+    elements.response(operation);
+
+    let name = operation
+        .summary
+        .clone()
+        .or(operation.operation_id.clone())
+        .unwrap_or(format!("{} {}", method.to_uppercase(), path));
+    let named = [
+        operation.operation_id.as_deref(),
+        operation.summary.as_deref(),
+    ]
+    .into_iter()
+    .flatten()
+    .chain(path.split('/'))
+    .flat_map(words)
+    .any(|w| DERIVED.contains(&w.as_str()));
+    if r#type == FunctionType::CE && (named || elements.derived) {
+        r#type = FunctionType::SE;
+    }
+
+    // Data Functions named as the resources of the path, the tags or the schemas.
+    let keys: Vec<String> = path
+        .split('/')
+        .filter(|s| !s.starts_with('{'))
+        .chain(operation.tags.iter().map(String::as_str))
+        .chain(elements.schemas.iter().map(String::as_str))
+        .map(key)
+        .filter(|k| !k.is_empty())
+        .collect();
+    let alrs: Vec<&DataFunction> = datas
+        .iter()
+        .filter(|data| keys.contains(&key(&data.name)))
+        .collect();
+
+    let mut dets = elements.dets;
+    for det in dets.iter_mut() {
+        det.reference = alrs.iter().find_map(|alr| {
+            alr.ders
+                .iter()
+                .find(|(_, der)| key(der) == key(&det.name))
+                .map(|(rlr, der)| DERReference {
+                    alr: alr.id,
+                    rlr: rlr.clone(),
+                    der: der.clone(),
+                })
+        });
+    }
+
+    trace!("Operation {} {} drafted as {:?}.", method, path, r#type);
+    Some(DraftFunction {
+        id: None,
+        r#type,
+        name,
+        description: operation.description.clone(),
+        rlrs: Vec::new(),
+        alrs: alrs
+            .into_iter()
+            .map(|alr| DraftALR {
+                id: alr.id,
+                name: alr.name.clone(),
+            })
+            .collect(),
+        dets,
+    })
+}
+
+/// Drafts the Transaction Functions for the operations of the document, associated with the
+/// Data Functions of the Frontier.
+pub fn parse(document: &str, datas: &[DataFunction]) -> Result<Vec<DraftFunction>, Error> {
+    let api: OpenAPI = match serde_json::from_str(document) {
+        Ok(v) => v,
+        Err(_) => match serde_yaml::from_str(document) {
+            Ok(v) => v,
+            Err(e) => {
+                warn!("Invalid OpenAPI document: {}", e);
+                return Err(Error::OpenAPIInvalid);
+            }
+        },
+    };
+    if !api.openapi.starts_with("3.") {
+        warn!("OpenAPI version not supported: {}", api.openapi);
+        return Err(Error::OpenAPIInvalid);
+    }
+
+    let mut result = Vec::<DraftFunction>::new();
+    for (path, item) in api.paths.iter() {
+        let Some(item) = item.as_item() else {
+            continue;
+        };
+        for (method, operation) in item.iter() {
+            if let Some(function) = draft(&api, path, method, item, operation, datas) {
+                result.push(function);
+            }
+        }
+    }
+    if result.is_empty() {
+        return Err(Error::OpenAPIInvalid);
+    }
+
+    Ok(result)
+}
@@ -13,8 +13,8 @@ use uuid::Uuid;
 #[derive(Clone, Debug, Serialize, strum_macros::AsRefStr)]
 pub enum Error {
     Unauthorized,
-    // Forbidden,
-    // ParamInvalid,
+    Forbidden,
+    ParamInvalid,
     NotFound,
     MultipleRowsAffected,
     KeyNotFound,
@@ -32,10 +32,13 @@ pub enum Error {
     ProjectConstraints,
     ProjectUpdate,
     EmpiricalInvalid,
+    ImpactInvalid,
     FrontierCreate,
     FrontierNameDuplicated,
     FrontierUpdate,
     FrontierConstraints,
+    BaselineInvalid,
+    BaselineFunctionNotFound,
     NotFunctionData,
     NotFunctionTransaction,
     FunctionCreate,
@@ -43,6 +46,29 @@ pub enum Error {
     FunctionNameDuplicated,
     FunctionUpdate,
     FunctionConstraints,
+    DERReferenceInvalid,
+    SnapshotCreate,
+    SnapshotContent,
+    MemberCreate,
+    MemberDuplicated,
+    MemberInvalid,
+    MemberUserNotFound,
+    TenantDisabled,
+    TenantSuspended,
+    QuotaProjects,
+    QuotaFrontiers,
+    QuotaFunctions,
+    TenantCreate,
+    TenantUpdate,
+    ApiKeyCreate,
+    AuditCreate,
+    ArchiveVersion,
+    ArchiveInvalid,
+    DDLInvalid,
+    OpenAPIInvalid,
+    CSVInvalid,
+    SpreadsheetCreate,
+    DocumentCreate,
 }
 
 impl core::fmt::Display for Error {
@@ -85,17 +111,24 @@ impl IntoResponse for Error {
                     message: "Authentication error. Request a new Access Token.",
                 },
             ),
-            // Error::Forbidden => {
-            //     (
-            //         StatusCode::FORBIDDEN,
-            //             ErrorResponse {
-            //             id: Uuid::now_v7(),
-            //             time: Utc::now(),
-            //             error: "AUTHORIZATION",
-            //             message: "Não autorizado para esta operação."
-            //         }
-            //     )
-            // }
+            Error::Forbidden => (
+                StatusCode::FORBIDDEN,
+                ErrorResponse {
+                    id: Uuid::now_v7(),
+                    time: Utc::now(),
+                    error: "AUTHORIZATION",
+                    message: "User not authorized for this operation.",
+                },
+            ),
+            Error::ParamInvalid => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse {
+                    id: Uuid::now_v7(),
+                    time: Utc::now(),
+                    error: "PARAM_INVALID",
+                    message: "Invalid value for the request parameters.",
+                },
+            ),
             Error::NotFound => (
                 StatusCode::NOT_FOUND,
                 ErrorResponse {
@@ -152,6 +185,15 @@ impl IntoResponse for Error {
                     message: "Empirical adjustment factors must have a value between 0 and 100.",
                 },
             ),
+            Error::ImpactInvalid => (
+                StatusCode::NOT_ACCEPTABLE,
+                ErrorResponse {
+                    id: Uuid::now_v7(),
+                    time: Utc::now(),
+                    error: "NOT_ACCEPTABLE",
+                    message: "Impact factors must have a value between 0 and 100.",
+                },
+            ),
             Error::ProjectNameDuplicated
             | Error::FrontierNameDuplicated
             | Error::FunctionNameDuplicated => (
@@ -163,6 +205,24 @@ impl IntoResponse for Error {
                     message: "The name must be unique for this scope.",
                 },
             ),
+            Error::BaselineInvalid => (
+                StatusCode::NOT_ACCEPTABLE,
+                ErrorResponse {
+                    id: Uuid::now_v7(),
+                    time: Utc::now(),
+                    error: "NOT_ACCEPTABLE",
+                    message: "An Enhancement Frontier must be applied to an Application Frontier of the same Project.",
+                },
+            ),
+            Error::BaselineFunctionNotFound => (
+                StatusCode::CONFLICT,
+                ErrorResponse {
+                    id: Uuid::now_v7(),
+                    time: Utc::now(),
+                    error: "BASELINE_FUNCTION_NOT_FOUND",
+                    message: "The Function changed by the Enhancement was not found on the baseline.",
+                },
+            ),
             Error::NotFunctionData
             | Error::NotFunctionTransaction
             | Error::FunctionTypeUpdateError => (
@@ -174,6 +234,150 @@ impl IntoResponse for Error {
                     message: "The Function Type is not valid for this scope.",
                 },
             ),
+            Error::DERReferenceInvalid => (
+                StatusCode::NOT_ACCEPTABLE,
+                ErrorResponse {
+                    id: Uuid::now_v7(),
+                    time: Utc::now(),
+                    error: "NOT_ACCEPTABLE",
+                    message: "The DET must reference a DER of a Data Function of the Transaction.",
+                },
+            ),
+            Error::SnapshotCreate => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ErrorResponse {
+                    id: Uuid::now_v7(),
+                    time: Utc::now(),
+                    error: "SNAPSHOT_CREATE",
+                    message: "The Snapshot of the Project could not be registered.",
+                },
+            ),
+            Error::SnapshotContent => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ErrorResponse {
+                    id: Uuid::now_v7(),
+                    time: Utc::now(),
+                    error: "SNAPSHOT_CONTENT",
+                    message: "The content of the Snapshot is not valid for this version.",
+                },
+            ),
+            Error::MemberDuplicated => (
+                StatusCode::CONFLICT,
+                ErrorResponse {
+                    id: Uuid::now_v7(),
+                    time: Utc::now(),
+                    error: "MEMBER_DUPLICATED",
+                    message: "The User is already a member of the Project.",
+                },
+            ),
+            Error::MemberUserNotFound => (
+                StatusCode::CONFLICT,
+                ErrorResponse {
+                    id: Uuid::now_v7(),
+                    time: Utc::now(),
+                    error: "USER_NOT_FOUND",
+                    message: "The User must be registered on the Tenant.",
+                },
+            ),
+            Error::MemberInvalid => (
+                StatusCode::NOT_ACCEPTABLE,
+                ErrorResponse {
+                    id: Uuid::now_v7(),
+                    time: Utc::now(),
+                    error: "NOT_ACCEPTABLE",
+                    message: "The owner of the Project is changed only by the transfer of ownership.",
+                },
+            ),
+            Error::TenantDisabled => (
+                StatusCode::FORBIDDEN,
+                ErrorResponse {
+                    id: Uuid::now_v7(),
+                    time: Utc::now(),
+                    error: "TENANT_DISABLED",
+                    message: "The Tenant is disabled.",
+                },
+            ),
+            Error::TenantSuspended => (
+                StatusCode::FORBIDDEN,
+                ErrorResponse {
+                    id: Uuid::now_v7(),
+                    time: Utc::now(),
+                    error: "TENANT_SUSPENDED",
+                    message: "The Tenant is suspended, only reading is allowed.",
+                },
+            ),
+            Error::QuotaProjects => (
+                StatusCode::FORBIDDEN,
+                ErrorResponse {
+                    id: Uuid::now_v7(),
+                    time: Utc::now(),
+                    error: "QUOTA_PROJECTS",
+                    message: "The Tenant reached the maximum number of Projects for its tier.",
+                },
+            ),
+            Error::QuotaFrontiers => (
+                StatusCode::FORBIDDEN,
+                ErrorResponse {
+                    id: Uuid::now_v7(),
+                    time: Utc::now(),
+                    error: "QUOTA_FRONTIERS",
+                    message: "The Tenant reached the maximum number of Frontiers for its tier.",
+                },
+            ),
+            Error::QuotaFunctions => (
+                StatusCode::FORBIDDEN,
+                ErrorResponse {
+                    id: Uuid::now_v7(),
+                    time: Utc::now(),
+                    error: "QUOTA_FUNCTIONS",
+                    message: "The Tenant reached the maximum number of Functions for its tier.",
+                },
+            ),
+            Error::ArchiveVersion => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse {
+                    id: Uuid::now_v7(),
+                    time: Utc::now(),
+                    error: "ARCHIVE_VERSION",
+                    message: "Version of the archive not supported.",
+                },
+            ),
+            Error::ArchiveInvalid => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse {
+                    id: Uuid::now_v7(),
+                    time: Utc::now(),
+                    error: "ARCHIVE_INVALID",
+                    message: "The archive references a Frontier or Data Function not found on it.",
+                },
+            ),
+            Error::DDLInvalid => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse {
+                    id: Uuid::now_v7(),
+                    time: Utc::now(),
+                    error: "DDL_INVALID",
+                    message: "The DDL must have valid `CREATE TABLE` statements.",
+                },
+            ),
+            Error::OpenAPIInvalid => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse {
+                    id: Uuid::now_v7(),
+                    time: Utc::now(),
+                    error: "OPENAPI_INVALID",
+                    message: "The document must be an OpenAPI 3 specification with operations.",
+                },
+            ),
+            Error::CSVInvalid => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse {
+                    id: Uuid::now_v7(),
+                    time: Utc::now(),
+                    error: "CSV_INVALID",
+                    message: "The CSV must have a header row with the `type` and `name` columns.",
+                },
+            ),
             _ => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 ErrorResponse {
@@ -204,6 +408,20 @@ impl From<jsonwebtoken::errors::Error> for Error {
     }
 }
 
+impl From<rust_xlsxwriter::XlsxError> for Error {
+    fn from(value: rust_xlsxwriter::XlsxError) -> Self {
+        error!("{}", value);
+        Error::SpreadsheetCreate
+    }
+}
+
+impl From<printpdf::Error> for Error {
+    fn from(value: printpdf::Error) -> Self {
+        error!("{}", value);
+        Error::DocumentCreate
+    }
+}
+
 impl From<sea_orm::DbErr> for Error {
     fn from(value: sea_orm::DbErr) -> Self {
         error!("{}", value);
@@ -0,0 +1,331 @@
+use std::{collections::HashMap, sync::Arc};
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode, Uri},
+    response::IntoResponse,
+    Json,
+};
+use chrono::Utc;
+use log::{debug, trace};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseTransaction, EntityTrait, PaginatorTrait,
+    QueryFilter, Set,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    analysis::{self, adjustment},
+    error::{Error, ErrorResponse},
+    handlers::summaries,
+    model::{
+        factors, frontiers, functions_sizes,
+        page::{Page, PageParams},
+        prelude::*,
+        projects,
+        sea_orm_active_enums::{TenantStatus, TenantTier},
+        tenants::{self, ActiveModel, Model},
+        users,
+    },
+    state::AppState,
+};
+
+/// Search for a set of Tenants.
+#[utoipa::path(
+    tag = "Administration",
+    get,
+    path = "/api/admin/tenants",
+    responses(
+        (status = OK, description = "Success.", body = Page<tenants::Model>),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(PageParams),
+    security(("fpa-security" = []))
+)]
+pub async fn list(
+    params: Query<PageParams>,
+    state: State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    debug!("List all tenants (params: {:?})", params);
+
+    let mut conditions = Condition::all();
+    if let Some(name) = params.name() {
+        conditions = conditions.add(tenants::Column::Name.contains(&name));
+    }
+
+    let db = state.system_connection().await?;
+    let paginator = Tenants::find()
+        .filter(conditions)
+        .paginate(&db, params.size());
+
+    let items = paginator.fetch_page(params.page() - 1).await?;
+    let mut page: Page<Model> = Page::new();
+    page.pages = paginator.num_pages().await?;
+    page.index = params.page();
+    page.size = items.len() as u64;
+    page.records = paginator.num_items().await?;
+    page.items = items;
+
+    trace!("::: {:?}", json!(page));
+    Ok(Json(page))
+}
+
+/// Select a specific Tenant.
+#[utoipa::path(
+    tag = "Administration",
+    get,
+    path = "/api/admin/tenants/{tenant}",
+    responses(
+        (status = OK, description = "Success.", body = tenants::Model),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = NOT_FOUND, description = "Tenant not founded.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(
+        ("tenant" = Uuid, Path, description = "Tenant Unique ID.")
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn by_id(
+    Path(tenant): Path<Uuid>,
+    state: State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    debug!("Select a specific tenant (tenant: {:?})", tenant);
+
+    let db = state.system_connection().await?;
+    let data = find(tenant, &db).await?;
+
+    trace!("::: {:?}", json!(data));
+    Ok(Json(data))
+}
+
+/// Tenant's properties.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct TenantParam {
+    /// Tenant's name.
+    pub name: String,
+    /// Tenant's status (active by default).
+    pub status: Option<TenantStatus>,
+    /// Tenant's access level.
+    pub tier: TenantTier,
+}
+
+/// Create a new Tenant.
+#[utoipa::path(
+    tag = "Administration",
+    post,
+    path = "/api/admin/tenants",
+    responses(
+        (status = CREATED, description = "Success.", body = tenants::Model, headers(("Location", description = "New tenant address."))),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn create(
+    state: State<Arc<AppState>>,
+    Json(params): Json<TenantParam>,
+) -> Result<impl IntoResponse, Error> {
+    debug!("Create a new tenant ({:?})", params);
+
+    let db = state.system_connection().await?;
+    let config = state.configuration();
+
+    let tenant = ActiveModel {
+        tenant: Set(Uuid::now_v7()),
+        name: Set(params.name),
+        time: Set(Utc::now().into()),
+        status: Set(params.status.unwrap_or(TenantStatus::Active)),
+        tier: Set(params.tier),
+    };
+    let tenant: Model = match tenant.insert(&db).await {
+        Ok(v) => v,
+        Err(_) => return Err(Error::TenantCreate),
+    };
+
+    match db.commit().await {
+        Ok(it) => it,
+        Err(_) => return Err(Error::DatabaseTransaction),
+    };
+
+    let location = Uri::builder()
+        .scheme(config.scheme.clone())
+        .authority(format!(
+            "{}:{}",
+            config.authority.clone(),
+            config.port.clone()
+        ))
+        .path_and_query(format!("/api/admin/tenants/{}", &tenant.tenant))
+        .build()
+        .unwrap()
+        .to_string()
+        .parse()
+        .unwrap();
+    trace!("::: {:?}", location);
+
+    let mut header = HeaderMap::new();
+    header.insert("Location", location);
+
+    trace!("::: {:?}", json!(tenant));
+    Ok((StatusCode::CREATED, header, Json(tenant)))
+}
+
+/// Update a existing Tenant: rename, suspend or change the tier.
+#[utoipa::path(
+    tag = "Administration",
+    put,
+    path = "/api/admin/tenants/{tenant}",
+    responses(
+        (status = OK, description = "Success.", body = tenants::Model),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = NOT_FOUND, description = "Tenant not founded.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(
+        ("tenant" = Uuid, Path, description = "Tenant Unique ID.")
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn update(
+    Path(tenant): Path<Uuid>,
+    state: State<Arc<AppState>>,
+    Json(params): Json<TenantParam>,
+) -> Result<impl IntoResponse, Error> {
+    debug!(
+        "Update a existing tenant (tenant: {:?} - params: {:?})",
+        tenant, params
+    );
+
+    let db = state.system_connection().await?;
+
+    let mut data: ActiveModel = find(tenant, &db).await?.into();
+    data.name = Set(params.name);
+    if let Some(status) = params.status {
+        data.status = Set(status);
+    }
+    data.tier = Set(params.tier);
+
+    let data: Model = match data.update(&db).await {
+        Ok(v) => v,
+        Err(_) => return Err(Error::TenantUpdate),
+    };
+
+    match db.commit().await {
+        Ok(it) => it,
+        Err(_) => return Err(Error::DatabaseTransaction),
+    };
+
+    trace!("::: {:?}", json!(data));
+    Ok(Json(data))
+}
+
+/// Usage of the system by a Tenant.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TenantUsage {
+    /// Tenant Unique Identifier.
+    pub tenant: Uuid,
+    /// Number of registered Users.
+    pub users: u64,
+    /// Number of Projects.
+    pub projects: u64,
+    /// Number of Frontiers.
+    pub frontiers: u64,
+    /// Number of Functions.
+    pub functions: u64,
+    /// Function points of all Frontiers (EFP for enhancements, AFP otherwise).
+    pub points: f64,
+}
+
+/// Usage statistics of a Tenant.
+#[utoipa::path(
+    tag = "Administration",
+    get,
+    path = "/api/admin/tenants/{tenant}/usage",
+    responses(
+        (status = OK, description = "Success.", body = TenantUsage),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = NOT_FOUND, description = "Tenant not founded.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(
+        ("tenant" = Uuid, Path, description = "Tenant Unique ID.")
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn usage(
+    Path(tenant): Path<Uuid>,
+    state: State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    debug!("Usage statistics of a tenant (tenant: {:?})", tenant);
+
+    let db = state.system_connection().await?;
+    let tenant = find(tenant, &db).await?.tenant;
+
+    // Sizes of all Functions and the factors of all Frontiers, instead of a summary by Frontier.
+    let frontiers = Frontiers::find()
+        .filter(frontiers::Column::Tenant.eq(tenant))
+        .all(&db)
+        .await?;
+    let mut functions: HashMap<Uuid, Vec<functions_sizes::Model>> = HashMap::new();
+    for function in FunctionsSizes::find()
+        .filter(functions_sizes::Column::Tenant.eq(tenant))
+        .all(&db)
+        .await?
+    {
+        functions
+            .entry(function.frontier)
+            .or_default()
+            .push(function);
+    }
+    let mut tdis: HashMap<Uuid, i32> = HashMap::new();
+    for factor in Factors::find()
+        .filter(factors::Column::Tenant.eq(tenant))
+        .all(&db)
+        .await?
+    {
+        *tdis.entry(factor.frontier).or_default() += adjustment::influence(&factor.influence);
+    }
+    let mut points = 0.0;
+    for frontier in frontiers.iter() {
+        let items: Vec<&functions_sizes::Model> = functions
+            .get(&frontier.frontier)
+            .map(|f| f.iter().collect())
+            .unwrap_or_default();
+        let tdi = tdis.get(&frontier.frontier).copied().unwrap_or_default();
+        points += summaries::points(frontier, &items, tdi);
+    }
+
+    let data = TenantUsage {
+        tenant,
+        users: Users::find()
+            .filter(users::Column::Tenant.eq(tenant))
+            .count(&db)
+            .await?,
+        projects: Projects::find()
+            .filter(projects::Column::Tenant.eq(tenant))
+            .count(&db)
+            .await?,
+        frontiers: frontiers.len() as u64,
+        functions: functions.values().map(|f| f.len() as u64).sum(),
+        points: analysis::round(points),
+    };
+
+    trace!("::: {:?}", json!(data));
+    Ok(Json(data))
+}
+
+async fn find(tenant: Uuid, db: &DatabaseTransaction) -> Result<Model, Error> {
+    match Tenants::find_by_id(tenant).one(db).await? {
+        Some(v) => Ok(v),
+        None => Err(Error::NotFound),
+    }
+}
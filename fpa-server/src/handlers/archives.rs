@@ -0,0 +1,720 @@
+use std::{collections::HashMap, sync::Arc};
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode, Uri},
+    response::IntoResponse,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use log::{debug, trace};
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, DatabaseTransaction, EntityTrait, ModelTrait,
+    QueryFilter, QueryOrder, Set,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    ctx::Context,
+    error::{Error, ErrorResponse},
+    handlers::{
+        audits,
+        frontiers::{add_empiricals, add_factors},
+        functions::{
+            insert_function_data, insert_function_transaction, translate, DERReference, Function,
+            FunctionAIEParam, FunctionALIParam, FunctionCEParam, FunctionData, FunctionEEParam,
+            FunctionParam, FunctionSEParam, ALR, DET, RLR,
+        },
+        members,
+    },
+    model::{
+        empiricals, factors, frontiers, functions,
+        prelude::*,
+        projects::{self, Model},
+        sea_orm_active_enums::{
+            AuditAction, AuditEntity, ChangeType, CountingMethod, CountingType, EmpiricalType,
+            FactorType, FunctionType, ImpactType, InfluenceType, MemberType,
+        },
+    },
+    state::AppState,
+    tenants::{self, Resource},
+};
+
+/// Version of the archive schema, increased on incompatible changes.
+pub const ARCHIVE_VERSION: u32 = 1;
+
+/// Adjustment Factor of a Frontier in an archive.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ArchiveFactor {
+    /// Adjustment Factor.
+    pub factor: FactorType,
+    /// Influence value for the factor.
+    pub influence: InfluenceType,
+}
+
+/// Empirical Factor of a Frontier in an archive.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ArchiveEmpirical {
+    /// Empirical`s Factor.
+    pub empirical: EmpiricalType,
+    /// Percent of influence for the Empirical`s Factor.
+    pub value: i32,
+}
+
+/// Reference to a DER of a Data Function, by name.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ArchiveReference {
+    /// Name of the Data Function.
+    pub alr: String,
+    /// Name of the RLR in the Data Function.
+    pub rlr: String,
+    /// Name of the DER in the RLR.
+    pub der: String,
+}
+
+/// Data Element Type of a Transaction Function in an archive.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ArchiveDET {
+    /// Name of the DET.
+    pub name: String,
+    /// Description of the DET.
+    pub description: Option<String>,
+    /// DER of an associated Data Function traced by the DET.
+    pub reference: Option<ArchiveReference>,
+}
+
+/// Function of a Frontier in an archive.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ArchiveFunction {
+    /// Type of the Function.
+    pub r#type: FunctionType,
+    /// Name of the Function.
+    pub name: String,
+    /// Description of the Function.
+    pub description: Option<String>,
+    /// Change made to the Function in an enhancement counting.
+    pub change: Option<ChangeType>,
+    /// Impact factor (SISP) applied to the Function.
+    pub impact: Option<ImpactType>,
+    /// Name of the Enhancement Frontier that last changed the Function on a baseline.
+    pub enhancement: Option<String>,
+    /// Set of Record Layout Reference (Data Functions).
+    #[serde(default)]
+    pub rlrs: Vec<RLR>,
+    /// Names of the Data Functions of the Frontier (Transaction Functions).
+    #[serde(default)]
+    pub alrs: Vec<String>,
+    /// Set of Data Element Types (Transaction Functions).
+    #[serde(default)]
+    pub dets: Vec<ArchiveDET>,
+}
+
+/// Frontier of the Project in an archive.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ArchiveFrontier {
+    /// Frontier's name.
+    pub name: String,
+    /// Description for the Frontier.
+    pub description: Option<String>,
+    /// Type of counting for the Frontier.
+    pub counting: CountingType,
+    /// Method of counting for the Frontier.
+    pub method: CountingMethod,
+    /// Adjustments Factors of the Frontier.
+    #[serde(default)]
+    pub factors: Vec<ArchiveFactor>,
+    /// Empiricals Factors of the Frontier.
+    #[serde(default)]
+    pub empiricals: Vec<ArchiveEmpirical>,
+    /// Functions of the Frontier.
+    #[serde(default)]
+    pub functions: Vec<ArchiveFunction>,
+}
+
+/// Self-contained copy of a Project, with the records referenced by name.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct Archive {
+    /// Version of the archive schema.
+    pub version: u32,
+    /// Export time.
+    pub time: Option<DateTime<Utc>>,
+    /// Project's name.
+    pub name: String,
+    /// Project's description.
+    pub description: Option<String>,
+    /// Frontiers of the Project.
+    #[serde(default)]
+    pub frontiers: Vec<ArchiveFrontier>,
+}
+
+/// Record of the archive with a name already in use.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ArchiveConflict {
+    /// Type of the record (Project, Frontier or Function).
+    pub entity: AuditEntity,
+    /// Name in conflict.
+    pub name: String,
+    /// Frontier of the record, for the Functions.
+    pub frontier: Option<String>,
+}
+
+/// Import params.
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ImportParams {
+    /// Name of the new Project, in place of the name of the archive.
+    name: Option<String>,
+}
+
+/// Export a Project as a self-contained archive.
+#[utoipa::path(
+    tag = "Archives",
+    get,
+    path = "/api/projects/{project}/export",
+    responses(
+        (status = OK, description = "Success.", body = Archive),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = NOT_FOUND, description = "Project not founded.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(
+        ("project" = Uuid, Path, description = "Project Unique ID."),
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn export(
+    Path(project): Path<Uuid>,
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    debug!("Export a Project (project: {}).", project);
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Viewer, &ctx, &db).await?;
+
+    let project = match Projects::find_by_id(project).one(&db).await? {
+        Some(v) => v,
+        None => return Err(Error::NotFound),
+    };
+
+    let frontiers = project
+        .find_related(Frontiers)
+        .order_by_asc(frontiers::Column::Name)
+        .all(&db)
+        .await?;
+    let names: HashMap<Uuid, String> = frontiers
+        .iter()
+        .map(|f| (f.frontier, f.name.clone()))
+        .collect();
+
+    let mut archive = Archive {
+        version: ARCHIVE_VERSION,
+        time: Some(Utc::now()),
+        name: project.name,
+        description: project.description,
+        frontiers: Vec::new(),
+    };
+    for frontier in frontiers {
+        archive.frontiers.push(pack(frontier, &names, &db).await?);
+    }
+
+    trace!("::: {:?}", json!(archive));
+    Ok(Json(archive))
+}
+
+/// Copy of a Frontier with its factors, empiricals and functions, referenced by name.
+async fn pack(
+    frontier: frontiers::Model,
+    names: &HashMap<Uuid, String>,
+    db: &DatabaseTransaction,
+) -> Result<ArchiveFrontier, Error> {
+    trace!("Pack Frontier: {:?}", frontier.frontier);
+
+    let factors = frontier
+        .find_related(Factors)
+        .order_by_asc(factors::Column::Factor)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|f| ArchiveFactor {
+            factor: f.factor,
+            influence: f.influence,
+        })
+        .collect();
+    let empiricals = frontier
+        .find_related(Empiricals)
+        .order_by_asc(empiricals::Column::Empirical)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|e| ArchiveEmpirical {
+            empirical: e.empirical,
+            value: e.value,
+        })
+        .collect();
+
+    let mut result = Vec::<ArchiveFunction>::new();
+    let functions = Functions::find()
+        .filter(functions::Column::Frontier.eq(frontier.frontier))
+        .order_by_asc(functions::Column::Type)
+        .order_by_asc(functions::Column::Name)
+        .all(db)
+        .await?;
+    for function in functions {
+        result.push(pack_function(translate(function, db).await?, names));
+    }
+
+    Ok(ArchiveFrontier {
+        name: frontier.name,
+        description: frontier.description,
+        counting: frontier.counting,
+        method: frontier.method,
+        factors,
+        empiricals,
+        functions: result,
+    })
+}
+
+fn pack_function(function: Function, names: &HashMap<Uuid, String>) -> ArchiveFunction {
+    let enhancement = function.enhancement().and_then(|e| names.get(&e).cloned());
+    let r#type = function.r#type();
+    let (name, description, change, impact, rlrs, alrs, dets) = match function {
+        Function::ALI(f) => (
+            f.name,
+            f.description,
+            f.change,
+            f.impact,
+            f.rlrs,
+            vec![],
+            vec![],
+        ),
+        Function::AIE(f) => (
+            f.name,
+            f.description,
+            f.change,
+            f.impact,
+            f.rlrs,
+            vec![],
+            vec![],
+        ),
+        Function::EE(f) => (
+            f.name,
+            f.description,
+            f.change,
+            f.impact,
+            vec![],
+            f.alrs,
+            f.dets,
+        ),
+        Function::CE(f) => (
+            f.name,
+            f.description,
+            f.change,
+            f.impact,
+            vec![],
+            f.alrs,
+            f.dets,
+        ),
+        Function::SE(f) => (
+            f.name,
+            f.description,
+            f.change,
+            f.impact,
+            vec![],
+            f.alrs,
+            f.dets,
+        ),
+    };
+
+    let alrs: Vec<(Uuid, String)> = alrs
+        .into_iter()
+        .map(|alr| match alr {
+            FunctionData::ALI(f) => (f.id, f.name),
+            FunctionData::AIE(f) => (f.id, f.name),
+        })
+        .collect();
+    let dets = dets
+        .into_iter()
+        .map(|det| ArchiveDET {
+            name: det.name,
+            description: det.description,
+            reference: det.reference.and_then(|r| {
+                alrs.iter()
+                    .find(|(id, _)| *id == r.alr)
+                    .map(|(_, alr)| ArchiveReference {
+                        alr: alr.clone(),
+                        rlr: r.rlr,
+                        der: r.der,
+                    })
+            }),
+        })
+        .collect();
+
+    ArchiveFunction {
+        r#type,
+        name,
+        description,
+        change,
+        impact,
+        enhancement,
+        rlrs,
+        alrs: alrs.into_iter().map(|(_, name)| name).collect(),
+        dets,
+    }
+}
+
+/// Create a new Project from an archive, with new unique identifiers on the Tenant of the User.
+///
+/// Names already in use are reported without changes, the Project can be renamed with the `name` param.
+#[utoipa::path(
+    tag = "Archives",
+    post,
+    path = "/api/projects/import",
+    responses(
+        (status = CREATED, description = "Success.", body = projects::Model, headers(("Location", description = "New project address."))),
+        (status = BAD_REQUEST, description = "Archive version not supported or with invalid references.", body = ErrorResponse),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = CONFLICT, description = "Names of the archive already in use.", body = Vec<ArchiveConflict>),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(ImportParams),
+    security(("fpa-security" = []))
+)]
+pub async fn import(
+    params: Query<ImportParams>,
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+    Json(archive): Json<Archive>,
+) -> Result<impl IntoResponse, Error> {
+    debug!(
+        "Import a Project (params: {:?} - archive: {} version {}).",
+        params, archive.name, archive.version
+    );
+
+    if archive.version != ARCHIVE_VERSION {
+        return Err(Error::ArchiveVersion);
+    }
+    let name = params.name.clone().unwrap_or(archive.name.clone());
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+
+    let conflicts = conflicts(&name, &archive, &db).await?;
+    if !conflicts.is_empty() {
+        trace!("::: {:?}", json!(conflicts));
+        return Ok((StatusCode::CONFLICT, Json(conflicts)).into_response());
+    }
+
+    let config = state.configuration();
+    tenants::quota(Resource::Projects, config, &db).await?;
+
+    // The importer is the owner, registered first to access the new Project.
+    let id = Uuid::now_v7();
+    members::register(id, *ctx.id(), MemberType::Owner, &ctx, &db).await?;
+
+    let project = projects::ActiveModel {
+        project: Set(id),
+        tenant: Set(*ctx.tenant()),
+        user: Set(*ctx.id()),
+        time: Set(Utc::now().into()),
+        name: Set(name),
+        description: Set(archive.description),
+    };
+    let project: Model = match project.insert(&db).await {
+        Ok(v) => v,
+        Err(e) => {
+            match e.sql_err().unwrap() {
+                sea_orm::SqlErr::UniqueConstraintViolation(_) => {
+                    return Err(Error::ProjectNameDuplicated)
+                }
+                _ => return Err(Error::ProjectCreate),
+            };
+        }
+    };
+
+    let mut frontiers = HashMap::<String, Uuid>::new();
+    let mut enhancements = Vec::<(Uuid, String)>::new();
+    for frontier in archive.frontiers {
+        tenants::quota(Resource::Frontiers, config, &db).await?;
+        let (frontier, mut changed) = unpack(project.project, frontier, &ctx, &state, &db).await?;
+        frontiers.insert(frontier.name, frontier.frontier);
+        enhancements.append(&mut changed);
+    }
+
+    for (function, enhancement) in enhancements {
+        let enhancement = match frontiers.get(&enhancement) {
+            Some(v) => *v,
+            None => return Err(Error::ArchiveInvalid),
+        };
+        Functions::update_many()
+            .col_expr(functions::Column::Enhancement, Expr::value(enhancement))
+            .filter(functions::Column::Function.eq(function))
+            .exec(&db)
+            .await?;
+    }
+
+    audits::register(
+        project.project,
+        AuditEntity::Project,
+        project.project,
+        AuditAction::Create,
+        None,
+        Some(json!(project)),
+        &ctx,
+        &db,
+    )
+    .await?;
+
+    match db.commit().await {
+        Ok(it) => it,
+        Err(_) => return Err(Error::DatabaseTransaction),
+    };
+
+    let location = Uri::builder()
+        .scheme(config.scheme.clone())
+        .authority(format!(
+            "{}:{}",
+            config.authority.clone(),
+            config.port.clone()
+        ))
+        .path_and_query(format!("/api/projects/{}", &project.project))
+        .build()
+        .unwrap()
+        .to_string()
+        .parse()
+        .unwrap();
+    trace!("::: {:?}", location);
+
+    let mut header = HeaderMap::new();
+    header.insert("Location", location);
+
+    trace!("::: {:?}", json!(project));
+    Ok((StatusCode::CREATED, header, Json(project)).into_response())
+}
+
+/// Names of the archive already in use on the Tenant, or repeated on the archive.
+///
+/// Data Functions are referenced by name, so their names must be unique on the Frontier.
+async fn conflicts(
+    name: &str,
+    archive: &Archive,
+    db: &DatabaseTransaction,
+) -> Result<Vec<ArchiveConflict>, Error> {
+    let mut result = Vec::<ArchiveConflict>::new();
+
+    if Projects::find()
+        .filter(projects::Column::Name.eq(name))
+        .one(db)
+        .await?
+        .is_some()
+    {
+        result.push(ArchiveConflict {
+            entity: AuditEntity::Project,
+            name: name.to_owned(),
+            frontier: None,
+        });
+    }
+
+    for (index, frontier) in archive.frontiers.iter().enumerate() {
+        if archive.frontiers[..index]
+            .iter()
+            .any(|f| f.name == frontier.name)
+        {
+            result.push(ArchiveConflict {
+                entity: AuditEntity::Frontier,
+                name: frontier.name.clone(),
+                frontier: None,
+            });
+        }
+
+        let datas: Vec<&ArchiveFunction> = frontier
+            .functions
+            .iter()
+            .filter(|f| matches!(f.r#type, FunctionType::ALI | FunctionType::AIE))
+            .collect();
+        for (index, function) in datas.iter().enumerate() {
+            if datas[..index].iter().any(|f| f.name == function.name) {
+                result.push(ArchiveConflict {
+                    entity: AuditEntity::Function,
+                    name: function.name.clone(),
+                    frontier: Some(frontier.name.clone()),
+                });
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Stores a Frontier of the archive, with the Functions changed by an Enhancement
+/// (resolved after all Frontiers are stored).
+async fn unpack(
+    project: Uuid,
+    archive: ArchiveFrontier,
+    ctx: &Context,
+    state: &AppState,
+    db: &DatabaseTransaction,
+) -> Result<(frontiers::Model, Vec<(Uuid, String)>), Error> {
+    trace!("Unpack Frontier: {:?}", archive.name);
+
+    let frontier = frontiers::ActiveModel {
+        project: Set(project),
+        tenant: Set(*ctx.tenant()),
+        frontier: Set(Uuid::now_v7()),
+        name: Set(archive.name.to_owned()),
+        description: Set(archive.description.to_owned()),
+        counting: Set(archive.counting),
+        method: Set(archive.method),
+    };
+    let frontier = match frontier.insert(db).await {
+        Ok(v) => v,
+        Err(_) => return Err(Error::FrontierCreate),
+    };
+
+    // Factors and empiricals not informed keep the defaults of a new Frontier.
+    match add_factors(db, frontier.frontier, *ctx.tenant()).await {
+        Ok(_) => (),
+        Err(_) => return Err(Error::ProjectFactorCreate),
+    };
+    match add_empiricals(db, frontier.frontier, *ctx.tenant(), state.configuration()).await {
+        Ok(_) => (),
+        Err(_) => return Err(Error::ProjectEmpiricalCreate),
+    };
+    for factor in archive.factors {
+        let item = factors::ActiveModel {
+            frontier: Set(frontier.frontier),
+            factor: Set(factor.factor),
+            influence: Set(factor.influence),
+            ..Default::default()
+        };
+        item.update(db).await?;
+    }
+    for empirical in archive.empiricals {
+        if empirical.empirical == EmpiricalType::Productivity {
+            if empirical.value < 1 || empirical.value > 50 {
+                return Err(Error::ProductivityInvalid);
+            }
+        } else if empirical.value < 0 || empirical.value > 100 {
+            return Err(Error::EmpiricalInvalid);
+        }
+        let item = empiricals::ActiveModel {
+            frontier: Set(frontier.frontier),
+            empirical: Set(empirical.empirical),
+            value: Set(empirical.value),
+            ..Default::default()
+        };
+        item.update(db).await?;
+    }
+
+    // Data Functions go first, so the Transactions can reference them.
+    let mut functions = archive.functions;
+    functions.sort_by_key(|f| !matches!(f.r#type, FunctionType::ALI | FunctionType::AIE));
+
+    let mut datas = HashMap::<String, Uuid>::new();
+    let mut enhancements = Vec::<(Uuid, String)>::new();
+    for function in functions {
+        tenants::quota(Resource::Functions, state.configuration(), db).await?;
+        let name = function.name.clone();
+        let enhancement = function.enhancement.clone();
+        let id = match rebuild(function, &datas)? {
+            param @ (FunctionParam::ALI(_) | FunctionParam::AIE(_)) => {
+                let id = insert_function_data(param, frontier.frontier, db, ctx)
+                    .await?
+                    .0;
+                datas.insert(name, id);
+                id
+            }
+            param => {
+                insert_function_transaction(param, frontier.frontier, db, ctx)
+                    .await?
+                    .0
+            }
+        };
+        if let Some(enhancement) = enhancement {
+            enhancements.push((id, enhancement));
+        }
+    }
+
+    Ok((frontier, enhancements))
+}
+
+/// Params to store the Function, with the Data Functions of the Frontier resolved by name.
+fn rebuild(
+    function: ArchiveFunction,
+    datas: &HashMap<String, Uuid>,
+) -> Result<FunctionParam, Error> {
+    let resolve = |name: &String| match datas.get(name) {
+        Some(v) => Ok(*v),
+        None => Err(Error::ArchiveInvalid),
+    };
+
+    let mut alrs = Vec::<ALR>::new();
+    for alr in function.alrs.iter() {
+        alrs.push(ALR { id: resolve(alr)? });
+    }
+    let mut dets = Vec::<DET>::new();
+    for det in function.dets {
+        let reference = match det.reference {
+            Some(r) => Some(DERReference {
+                alr: resolve(&r.alr)?,
+                rlr: r.rlr,
+                der: r.der,
+            }),
+            None => None,
+        };
+        dets.push(DET {
+            name: det.name,
+            description: det.description,
+            reference,
+        });
+    }
+
+    let param = match function.r#type {
+        FunctionType::ALI => FunctionParam::ALI(FunctionALIParam {
+            name: function.name,
+            description: function.description,
+            change: function.change,
+            impact: function.impact,
+            rlrs: function.rlrs,
+        }),
+        FunctionType::AIE => FunctionParam::AIE(FunctionAIEParam {
+            name: function.name,
+            description: function.description,
+            change: function.change,
+            impact: function.impact,
+            rlrs: function.rlrs,
+        }),
+        FunctionType::EE => FunctionParam::EE(FunctionEEParam {
+            name: function.name,
+            description: function.description,
+            change: function.change,
+            impact: function.impact,
+            alrs,
+            dets,
+        }),
+        FunctionType::CE => FunctionParam::CE(FunctionCEParam {
+            name: function.name,
+            description: function.description,
+            change: function.change,
+            impact: function.impact,
+            alrs,
+            dets,
+        }),
+        FunctionType::SE => FunctionParam::SE(FunctionSEParam {
+            name: function.name,
+            description: function.description,
+            change: function.change,
+            impact: function.impact,
+            alrs,
+            dets,
+        }),
+    };
+
+    Ok(param)
+}
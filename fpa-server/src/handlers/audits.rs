@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    response::IntoResponse,
+    Json,
+};
+use chrono::Utc;
+use log::{debug, trace};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseTransaction, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, Set,
+};
+use serde_derive::Deserialize;
+use serde_json::{json, Value};
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+use crate::{
+    ctx::Context,
+    error::{Error, ErrorResponse},
+    handlers::members,
+    model::{
+        audits::{self, Model},
+        page::Page,
+        prelude::*,
+        sea_orm_active_enums::{AuditAction, AuditEntity, MemberType},
+    },
+    state::AppState,
+};
+
+/// Page select params.
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AuditsParams {
+    /// Index of page to select.
+    #[param(minimum = 1, default = 1)]
+    page: Option<u64>,
+    /// Page's size (records).
+    #[param(minimum = 1, maximum = 50, default = 10)]
+    size: Option<u64>,
+    /// Filter by type of the record changed.
+    entity: Option<AuditEntity>,
+    /// Filter by unique identifier of the record changed.
+    key: Option<Uuid>,
+}
+
+impl Default for AuditsParams {
+    fn default() -> Self {
+        Self {
+            page: Some(1),
+            size: Some(10),
+            entity: None,
+            key: None,
+        }
+    }
+}
+
+impl AuditsParams {
+    pub fn page(&self) -> u64 {
+        match self.page {
+            Some(v) => v,
+            None => Self::default().page.unwrap(),
+        }
+    }
+
+    pub fn size(&self) -> u64 {
+        match self.size {
+            Some(v) => v,
+            None => Self::default().size.unwrap(),
+        }
+    }
+
+    pub fn entity(&self) -> Option<AuditEntity> {
+        self.entity.clone()
+    }
+
+    pub fn key(&self) -> Option<Uuid> {
+        self.key
+    }
+}
+
+/// Search for the changes made on a Project, the latest first.
+#[utoipa::path(
+    tag = "Audit",
+    get,
+    path = "/api/projects/{project}/audit",
+    responses(
+        (status = OK, description = "Success.", body = Page<audits::Model>),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(
+        ("project" = Uuid, Path, description = "Project Unique ID."),
+        AuditsParams,
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn list(
+    Path(project): Path<Uuid>,
+    params: Query<AuditsParams>,
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    debug!(
+        "List the audit trail (project: {} - params: {:?})",
+        project, params
+    );
+
+    let mut conditions = Condition::all();
+    conditions = conditions.add(audits::Column::Project.eq(project));
+    if let Some(entity) = params.entity() {
+        conditions = conditions.add(audits::Column::Entity.eq(entity));
+    }
+    if let Some(key) = params.key() {
+        conditions = conditions.add(audits::Column::Key.eq(key));
+    }
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Viewer, &ctx, &db).await?;
+
+    let paginator = Audits::find()
+        .filter(conditions)
+        .order_by_desc(audits::Column::Time)
+        .order_by_desc(audits::Column::Audit)
+        .paginate(&db, params.size());
+
+    let items = paginator.fetch_page(params.page() - 1).await?;
+    let mut page: Page<Model> = Page::new();
+    page.pages = paginator.num_pages().await?;
+    page.index = params.page();
+    page.size = items.len() as u64;
+    page.records = paginator.num_items().await?;
+    page.items = items;
+
+    trace!("::: {:?}", json!(page));
+    Ok(Json(page))
+}
+
+/// Records a change on the Project, in the same transaction of the change.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn register(
+    project: Uuid,
+    entity: AuditEntity,
+    key: Uuid,
+    action: AuditAction,
+    before: Option<Value>,
+    after: Option<Value>,
+    ctx: &Context,
+    db: &DatabaseTransaction,
+) -> Result<(), Error> {
+    let audit = audits::ActiveModel {
+        audit: Set(Uuid::now_v7()),
+        project: Set(project),
+        tenant: Set(*ctx.tenant()),
+        user: Set(*ctx.id()),
+        time: Set(Utc::now().into()),
+        entity: Set(entity),
+        key: Set(key),
+        action: Set(action),
+        before: Set(before),
+        after: Set(after),
+    };
+    match audit.insert(db).await {
+        Ok(_) => Ok(()),
+        Err(_) => Err(Error::AuditCreate),
+    }
+}
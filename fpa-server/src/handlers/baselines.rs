@@ -0,0 +1,370 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use log::{debug, trace};
+use sea_orm::{
+    sea_query::Expr, ColumnTrait, Condition, DatabaseTransaction, EntityTrait, ModelTrait,
+    QueryFilter,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    ctx::Context,
+    error::{Error, ErrorResponse},
+    handlers::functions::{
+        insert_function_data, insert_function_transaction, translate, update_function_data,
+        update_function_transaction, DERReference, Function, FunctionAIEParam, FunctionALIParam,
+        FunctionCEParam, FunctionData, FunctionEEParam, FunctionParam, FunctionSEParam, ALR, DET,
+    },
+    handlers::{audits, members},
+    model::{
+        frontiers::{self, Entity as Frontiers},
+        functions,
+        prelude::*,
+        sea_orm_active_enums::{
+            AuditAction, AuditEntity, ChangeType, CountingType, FunctionType, MemberType,
+        },
+    },
+    state::AppState,
+    tenants::{self, Resource},
+};
+
+/// Enhancement to apply on a baseline.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BaselineParam {
+    /// Enhancement Frontier Unique Identifier.
+    pub enhancement: Uuid,
+}
+
+/// Functions of the baseline changed by an Enhancement.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct Baseline {
+    /// Baseline (Application) Frontier Unique Identifier.
+    pub baseline: Uuid,
+    /// Enhancement Frontier Unique Identifier.
+    pub enhancement: Uuid,
+    /// Number of Functions added to the baseline.
+    pub added: i32,
+    /// Number of Functions changed on the baseline.
+    pub changed: i32,
+    /// Number of Functions deleted from the baseline.
+    pub deleted: i32,
+}
+
+/// Apply the added, changed and deleted Functions of an Enhancement Frontier on a baseline.
+///
+/// Functions are matched by type and name. Conversion Functions are not part of the baseline.
+/// Each Function of the baseline added, changed or deleted is registered on the audit trail.
+#[utoipa::path(
+    tag = "Baselines",
+    post,
+    path = "/api/projects/{project}/frontiers/{frontier}/baseline",
+    responses(
+        (status = OK, description = "Success.", body = Baseline),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = NOT_FOUND, description = "Project or Frontier not founded.", body = ErrorResponse),
+        (status = NOT_ACCEPTABLE, description = "Frontier is not an Application or the Enhancement is invalid.", body = ErrorResponse),
+        (status = CONFLICT, description = "Functions of the Enhancement do not match the baseline.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(
+        ("project" = Uuid, Path, description = "Project Unique ID."),
+        ("frontier" = Uuid, Path, description = "Baseline Frontier Unique ID."),
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn apply(
+    Path((project, frontier)): Path<(Uuid, Uuid)>,
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+    Json(params): Json<BaselineParam>,
+) -> Result<impl IntoResponse, Error> {
+    debug!(
+        "Apply an Enhancement on a baseline (project: {} - frontier: {} - params: {:?})",
+        project, frontier, params
+    );
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Editor, &ctx, &db).await?;
+
+    let baseline = find_frontier(project, frontier, &db).await?;
+    let enhancement = find_frontier(project, params.enhancement, &db).await?;
+    if baseline.counting != CountingType::Application
+        || enhancement.counting != CountingType::Enhancement
+    {
+        return Err(Error::BaselineInvalid);
+    }
+
+    let mut result = Baseline {
+        baseline: baseline.frontier,
+        enhancement: enhancement.frontier,
+        added: 0,
+        changed: 0,
+        deleted: 0,
+    };
+
+    let mut functions = Functions::find()
+        .filter(functions::Column::Frontier.eq(enhancement.frontier))
+        .all(&db)
+        .await?;
+    // Data Functions go first, so the Transactions can reference them, and deletions go last.
+    functions.sort_by_key(|f| match (&f.change, &f.r#type) {
+        (Some(ChangeType::Deleted), _) => 2,
+        (_, FunctionType::ALI | FunctionType::AIE) => 0,
+        _ => 1,
+    });
+
+    for function in functions {
+        let current =
+            find_function(baseline.frontier, &function.r#type, &function.name, &db).await?;
+        let (id, action, before) = match (&function.change, current) {
+            (Some(ChangeType::Added), None) => {
+                tenants::quota(Resource::Functions, state.configuration(), &db).await?;
+                result.added += 1;
+                let param = rebase(translate(function, &db).await?, baseline.frontier, &db).await?;
+                let id = match param {
+                    FunctionParam::ALI(_) | FunctionParam::AIE(_) => {
+                        insert_function_data(param, baseline.frontier, &db, &ctx).await?
+                    }
+                    _ => insert_function_transaction(param, baseline.frontier, &db, &ctx).await?,
+                }
+                .0;
+                (id, AuditAction::Create, None)
+            }
+            (Some(ChangeType::Added), Some(_)) => return Err(Error::FunctionNameDuplicated),
+            (Some(ChangeType::Changed), Some(current)) => {
+                result.changed += 1;
+                let before = json!(translate(current.clone(), &db).await?);
+                let param = rebase(translate(function, &db).await?, baseline.frontier, &db).await?;
+                match param {
+                    FunctionParam::ALI(v) => {
+                        update_function_data(
+                            current.function,
+                            v.name,
+                            v.description,
+                            None,
+                            None,
+                            v.rlrs,
+                            &db,
+                        )
+                        .await?
+                    }
+                    FunctionParam::AIE(v) => {
+                        update_function_data(
+                            current.function,
+                            v.name,
+                            v.description,
+                            None,
+                            None,
+                            v.rlrs,
+                            &db,
+                        )
+                        .await?
+                    }
+                    _ => {
+                        update_function_transaction(
+                            current.function,
+                            current.r#type.clone(),
+                            param,
+                            &db,
+                        )
+                        .await?
+                    }
+                };
+                (current.function, AuditAction::Update, Some(before))
+            }
+            (Some(ChangeType::Deleted), Some(current)) => {
+                result.deleted += 1;
+                let id = current.function;
+                let before = json!(translate(current.clone(), &db).await?);
+                match current.delete(&db).await {
+                    Ok(_) => (),
+                    Err(_) => return Err(Error::FunctionConstraints),
+                };
+                audits::register(
+                    project,
+                    AuditEntity::Function,
+                    id,
+                    AuditAction::Delete,
+                    Some(before),
+                    None,
+                    &ctx,
+                    &db,
+                )
+                .await?;
+                continue;
+            }
+            (Some(ChangeType::Conversion), _) | (None, _) => continue,
+            (Some(_), None) => return Err(Error::BaselineFunctionNotFound),
+        };
+
+        Functions::update_many()
+            .col_expr(
+                functions::Column::Enhancement,
+                Expr::value(enhancement.frontier),
+            )
+            .filter(functions::Column::Function.eq(id))
+            .exec(&db)
+            .await?;
+
+        let after = match Functions::find_by_id(id).one(&db).await? {
+            Some(v) => json!(translate(v, &db).await?),
+            None => return Err(Error::NotFound),
+        };
+        audits::register(
+            project,
+            AuditEntity::Function,
+            id,
+            action,
+            before,
+            Some(after),
+            &ctx,
+            &db,
+        )
+        .await?;
+    }
+
+    match db.commit().await {
+        Ok(it) => it,
+        Err(_) => return Err(Error::DatabaseTransaction),
+    };
+
+    trace!("::: {:?}", json!(result));
+    Ok(Json(result))
+}
+
+async fn find_frontier(
+    project: Uuid,
+    frontier: Uuid,
+    db: &DatabaseTransaction,
+) -> Result<frontiers::Model, Error> {
+    let mut conditions = Condition::all();
+    conditions = conditions.add(frontiers::Column::Project.eq(project));
+    conditions = conditions.add(frontiers::Column::Frontier.eq(frontier));
+
+    match Frontiers::find().filter(conditions).one(db).await? {
+        Some(v) => Ok(v),
+        None => Err(Error::NotFound),
+    }
+}
+
+/// Function of the baseline with the same type and name.
+async fn find_function(
+    baseline: Uuid,
+    r#type: &FunctionType,
+    name: &str,
+    db: &DatabaseTransaction,
+) -> Result<Option<functions::Model>, Error> {
+    let mut conditions = Condition::all();
+    conditions = conditions.add(functions::Column::Frontier.eq(baseline));
+    conditions = conditions.add(functions::Column::Type.eq(r#type.clone()));
+    conditions = conditions.add(functions::Column::Name.eq(name));
+
+    Ok(Functions::find().filter(conditions).one(db).await?)
+}
+
+/// Params to store the Function on the baseline, with the Data Functions of the baseline.
+async fn rebase(
+    function: Function,
+    baseline: Uuid,
+    db: &DatabaseTransaction,
+) -> Result<FunctionParam, Error> {
+    trace!("Rebase Function: {:?}", function);
+
+    let param = match function {
+        Function::ALI(f) => FunctionParam::ALI(FunctionALIParam {
+            name: f.name,
+            description: f.description,
+            change: None,
+            impact: None,
+            rlrs: f.rlrs,
+        }),
+        Function::AIE(f) => FunctionParam::AIE(FunctionAIEParam {
+            name: f.name,
+            description: f.description,
+            change: None,
+            impact: None,
+            rlrs: f.rlrs,
+        }),
+        Function::EE(f) => {
+            let (alrs, dets) = rebase_alrs(f.alrs, f.dets, baseline, db).await?;
+            FunctionParam::EE(FunctionEEParam {
+                name: f.name,
+                description: f.description,
+                change: None,
+                impact: None,
+                alrs,
+                dets,
+            })
+        }
+        Function::CE(f) => {
+            let (alrs, dets) = rebase_alrs(f.alrs, f.dets, baseline, db).await?;
+            FunctionParam::CE(FunctionCEParam {
+                name: f.name,
+                description: f.description,
+                change: None,
+                impact: None,
+                alrs,
+                dets,
+            })
+        }
+        Function::SE(f) => {
+            let (alrs, dets) = rebase_alrs(f.alrs, f.dets, baseline, db).await?;
+            FunctionParam::SE(FunctionSEParam {
+                name: f.name,
+                description: f.description,
+                change: None,
+                impact: None,
+                alrs,
+                dets,
+            })
+        }
+    };
+
+    Ok(param)
+}
+
+/// Replace the Data Functions of the Enhancement by the ones of the baseline, including the DET references.
+async fn rebase_alrs(
+    alrs: Vec<FunctionData>,
+    dets: Vec<DET>,
+    baseline: Uuid,
+    db: &DatabaseTransaction,
+) -> Result<(Vec<ALR>, Vec<DET>), Error> {
+    let mut associations = Vec::<(Uuid, Uuid)>::new();
+    for alr in alrs {
+        let (id, r#type, name) = match alr {
+            FunctionData::ALI(f) => (f.id, FunctionType::ALI, f.name),
+            FunctionData::AIE(f) => (f.id, FunctionType::AIE, f.name),
+        };
+        match find_function(baseline, &r#type, &name, db).await? {
+            Some(v) => associations.push((id, v.function)),
+            None => return Err(Error::BaselineFunctionNotFound),
+        }
+    }
+
+    let dets = dets
+        .into_iter()
+        .map(|det| DET {
+            reference: det.reference.and_then(|r| {
+                associations
+                    .iter()
+                    .find(|(id, _)| *id == r.alr)
+                    .map(|(_, alr)| DERReference { alr: *alr, ..r })
+            }),
+            ..det
+        })
+        .collect();
+    let alrs = associations.iter().map(|(_, id)| ALR { id: *id }).collect();
+
+    Ok((alrs, dets))
+}
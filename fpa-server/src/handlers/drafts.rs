@@ -0,0 +1,308 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use log::{debug, trace};
+use sea_orm::{ColumnTrait, Condition, DatabaseTransaction, EntityTrait, QueryFilter};
+use serde::Deserialize;
+use serde_json::json;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    ctx::Context,
+    drafts::{self, DataFunction, DraftFunction},
+    error::{Error, ErrorResponse},
+    handlers::{
+        archives::ArchiveConflict,
+        audits,
+        functions::{insert_function_data, insert_function_transaction},
+        members,
+    },
+    model::{
+        ders, frontiers, functions, functions_datas,
+        prelude::*,
+        sea_orm_active_enums::{AuditAction, AuditEntity, FunctionType, MemberType},
+    },
+    state::AppState,
+    tenants::{self, Resource},
+};
+
+/// DDL to draft the Data Functions.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct DDLParam {
+    /// `CREATE TABLE` statements, with the keys and comments of the tables.
+    pub ddl: String,
+    /// Create the drafted Functions, only a preview if not confirmed (reviewed on `import/drafts`).
+    #[serde(default)]
+    pub confirm: bool,
+}
+
+/// OpenAPI document to draft the Transaction Functions.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct OpenAPIParam {
+    /// OpenAPI 3 document, in JSON or YAML.
+    pub document: String,
+    /// Create the drafted Functions, only a preview if not confirmed (reviewed on `import/drafts`).
+    #[serde(default)]
+    pub confirm: bool,
+}
+
+/// Draft the Data Functions (ALI) of a Frontier from the tables of a database.
+///
+/// Dependent tables are grouped as RLRs of the table they depend on, with a DER for each column.
+/// Columns that are only foreign keys are flagged for review.
+#[utoipa::path(
+    tag = "Functions",
+    post,
+    path = "/api/projects/{project}/frontiers/{frontier}/functions/import/ddl",
+    responses(
+        (status = OK, description = "Preview of the drafted Functions.", body = Vec<DraftFunction>),
+        (status = CREATED, description = "Drafted Functions created.", body = Vec<DraftFunction>),
+        (status = CONFLICT, description = "Names of the drafted Functions already in use.", body = Vec<ArchiveConflict>),
+        (status = BAD_REQUEST, description = "DDL without valid `CREATE TABLE` statements.", body = ErrorResponse),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = NOT_FOUND, description = "Project or Frontier not founded.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(
+        ("project" = Uuid, Path, description = "Project Unique ID."),
+        ("frontier" = Uuid, Path, description = "Frontier Unique ID."),
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn ddl(
+    Path((project, frontier)): Path<(Uuid, Uuid)>,
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+    Json(params): Json<DDLParam>,
+) -> Result<impl IntoResponse, Error> {
+    debug!(
+        "Draft Functions from a DDL (project: {} - frontier: {} - confirm: {})",
+        project, frontier, params.confirm
+    );
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Editor, &ctx, &db).await?;
+
+    let drafts = drafts::ddl::parse(&params.ddl)?;
+    confirm(project, frontier, drafts, params.confirm, &ctx, &state, db).await
+}
+
+/// Draft the Transaction Functions of a Frontier from the operations of an OpenAPI document.
+///
+/// POST, PUT, PATCH and DELETE operations are EEs, GET operations are CEs or, with derived data,
+/// SEs. The DETs come from the parameters and schemas, and the ALRs are the Data Functions of
+/// the Frontier with the names of the resources.
+#[utoipa::path(
+    tag = "Functions",
+    post,
+    path = "/api/projects/{project}/frontiers/{frontier}/functions/import/openapi",
+    responses(
+        (status = OK, description = "Preview of the drafted Functions.", body = Vec<DraftFunction>),
+        (status = CREATED, description = "Drafted Functions created.", body = Vec<DraftFunction>),
+        (status = CONFLICT, description = "Names of the drafted Functions already in use.", body = Vec<ArchiveConflict>),
+        (status = BAD_REQUEST, description = "Document without OpenAPI 3 operations.", body = ErrorResponse),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = NOT_FOUND, description = "Project or Frontier not founded.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(
+        ("project" = Uuid, Path, description = "Project Unique ID."),
+        ("frontier" = Uuid, Path, description = "Frontier Unique ID."),
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn openapi(
+    Path((project, frontier)): Path<(Uuid, Uuid)>,
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+    Json(params): Json<OpenAPIParam>,
+) -> Result<impl IntoResponse, Error> {
+    debug!(
+        "Draft Functions from an OpenAPI document (project: {} - frontier: {} - confirm: {})",
+        project, frontier, params.confirm
+    );
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Editor, &ctx, &db).await?;
+
+    let datas = datas(frontier, &db).await?;
+    let drafts = drafts::openapi::parse(&params.document, &datas)?;
+    confirm(project, frontier, drafts, params.confirm, &ctx, &state, db).await
+}
+
+/// Create the Functions reviewed from the preview of a DDL or an OpenAPI document.
+///
+/// The drafts can be changed or removed before the creation, the ALRs and the references of
+/// the DETs must be Data Functions of the Frontier.
+#[utoipa::path(
+    tag = "Functions",
+    post,
+    path = "/api/projects/{project}/frontiers/{frontier}/functions/import/drafts",
+    request_body = Vec<DraftFunction>,
+    responses(
+        (status = CREATED, description = "Reviewed Functions created.", body = Vec<DraftFunction>),
+        (status = BAD_REQUEST, description = "Invalid params.", body = ErrorResponse),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = NOT_FOUND, description = "Project or Frontier not founded.", body = ErrorResponse),
+        (status = CONFLICT, description = "Names of the reviewed Functions already in use.", body = Vec<ArchiveConflict>),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(
+        ("project" = Uuid, Path, description = "Project Unique ID."),
+        ("frontier" = Uuid, Path, description = "Frontier Unique ID."),
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn reviewed(
+    Path((project, frontier)): Path<(Uuid, Uuid)>,
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+    Json(drafts): Json<Vec<DraftFunction>>,
+) -> Result<impl IntoResponse, Error> {
+    debug!(
+        "Create the reviewed Functions (project: {} - frontier: {} - functions: {})",
+        project,
+        frontier,
+        drafts.len()
+    );
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Editor, &ctx, &db).await?;
+
+    confirm(project, frontier, drafts, true, &ctx, &state, db).await
+}
+
+/// Data Functions of the Frontier, with their DERs.
+pub(crate) async fn datas(
+    frontier: Uuid,
+    db: &DatabaseTransaction,
+) -> Result<Vec<DataFunction>, Error> {
+    let functions = FunctionsDatas::find()
+        .filter(Condition::all().add(functions_datas::Column::Frontier.eq(frontier)))
+        .all(db)
+        .await?;
+
+    let mut result = Vec::<DataFunction>::new();
+    for function in functions {
+        let ders = Ders::find()
+            .filter(Condition::all().add(ders::Column::Function.eq(function.function)))
+            .all(db)
+            .await?;
+        result.push(DataFunction {
+            id: function.function,
+            name: function.name,
+            ders: ders.into_iter().map(|d| (d.rlr, d.name)).collect(),
+        });
+    }
+
+    Ok(result)
+}
+
+/// Creates the drafted Functions when confirmed, otherwise only returns the preview.
+async fn confirm(
+    project: Uuid,
+    frontier: Uuid,
+    mut drafts: Vec<DraftFunction>,
+    confirm: bool,
+    ctx: &Context,
+    state: &AppState,
+    db: DatabaseTransaction,
+) -> Result<Response, Error> {
+    // Frontier must belong to the Project.
+    let model = match Frontiers::find()
+        .filter(
+            Condition::all()
+                .add(frontiers::Column::Project.eq(project))
+                .add(frontiers::Column::Frontier.eq(frontier)),
+        )
+        .one(&db)
+        .await?
+    {
+        Some(v) => v,
+        None => return Err(Error::NotFound),
+    };
+    if !confirm {
+        trace!("::: {:?}", json!(drafts));
+        return Ok((StatusCode::OK, Json(drafts)).into_response());
+    }
+
+    let conflicts = conflicts(&model, &drafts, &db).await?;
+    if !conflicts.is_empty() {
+        trace!("::: {:?}", json!(conflicts));
+        return Ok((StatusCode::CONFLICT, Json(conflicts)).into_response());
+    }
+
+    for draft in drafts.iter_mut() {
+        tenants::quota(Resource::Functions, state.configuration(), &db).await?;
+        let (id, function) = match draft.r#type {
+            FunctionType::ALI | FunctionType::AIE => {
+                insert_function_data(draft.param(), frontier, &db, ctx).await?
+            }
+            _ => insert_function_transaction(draft.param(), frontier, &db, ctx).await?,
+        };
+        audits::register(
+            project,
+            AuditEntity::Function,
+            id,
+            AuditAction::Create,
+            None,
+            Some(json!(function)),
+            ctx,
+            &db,
+        )
+        .await?;
+        draft.id = Some(id);
+    }
+
+    match db.commit().await {
+        Ok(it) => it,
+        Err(_) => return Err(Error::DatabaseTransaction),
+    };
+
+    trace!("::: {:?}", json!(drafts));
+    Ok((StatusCode::CREATED, Json(drafts)).into_response())
+}
+
+/// Names of the drafts already in use on the Frontier, or repeated on the drafts.
+async fn conflicts(
+    frontier: &frontiers::Model,
+    drafts: &[DraftFunction],
+    db: &DatabaseTransaction,
+) -> Result<Vec<ArchiveConflict>, Error> {
+    let names: Vec<String> = drafts.iter().map(|d| d.name.clone()).collect();
+    let used = Functions::find()
+        .filter(
+            Condition::all()
+                .add(functions::Column::Frontier.eq(frontier.frontier))
+                .add(functions::Column::Name.is_in(names)),
+        )
+        .all(db)
+        .await?;
+
+    let mut result = Vec::<ArchiveConflict>::new();
+    for (index, draft) in drafts.iter().enumerate() {
+        if used.iter().any(|f| f.name == draft.name)
+            || drafts[..index].iter().any(|d| d.name == draft.name)
+        {
+            result.push(ArchiveConflict {
+                entity: AuditEntity::Function,
+                name: draft.name.clone(),
+                frontier: Some(frontier.name.clone()),
+            });
+        }
+    }
+
+    Ok(result)
+}
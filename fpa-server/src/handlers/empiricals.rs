@@ -3,12 +3,13 @@ use std::sync::Arc;
 use crate::{
     ctx::Context,
     error::{Error, ErrorResponse},
+    handlers::{audits, members},
     model::{
         empiricals::{self, ActiveModel, Model},
         frontiers::{self, Entity as Frontiers},
         page::Page,
         prelude::*,
-        sea_orm_active_enums::EmpiricalType,
+        sea_orm_active_enums::{AuditAction, AuditEntity, EmpiricalType, MemberType},
     },
     state::AppState,
 };
@@ -35,6 +36,7 @@ use uuid::Uuid;
     responses(
         (status = OK, description = "Success", body = empiricals::Model),
         (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
         (status = NOT_FOUND, description = "Project not founded.", body = ErrorResponse),
         (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
     ),
@@ -55,7 +57,8 @@ pub async fn list(
     );
 
     let ctx = context.unwrap();
-    let db = state.connection(ctx.tenant()).await?;
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Viewer, &ctx, &db).await?;
 
     let mut conditions = Condition::all();
     conditions = conditions.add(frontiers::Column::Frontier.eq(frontier));
@@ -95,6 +98,7 @@ pub struct EmpiricalParam {
     responses(
         (status = OK, description = "Success", body = empiricals::Model),
         (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
         (status = NOT_FOUND, description = "Project not founded.", body = ErrorResponse),
         (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
     ),
@@ -126,7 +130,8 @@ pub async fn update(
     }
 
     let ctx = context.unwrap();
-    let db = state.connection(ctx.tenant()).await?;
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Editor, &ctx, &db).await?;
 
     let mut conditions = Condition::all();
     conditions = conditions.add(frontiers::Column::Project.eq(project));
@@ -143,11 +148,23 @@ pub async fn update(
         Some(v) => v,
         None => return Err(Error::NotFound),
     };
+    let before = json!(data);
 
     let mut data: ActiveModel = data.into();
     data.value = Set(params.value);
 
     let data: Model = data.update(&db).await?;
+    audits::register(
+        project,
+        AuditEntity::Empirical,
+        frontier,
+        AuditAction::Update,
+        Some(before),
+        Some(json!(data)),
+        &ctx,
+        &db,
+    )
+    .await?;
     match db.commit().await {
         Ok(it) => it,
         Err(_) => return Err(Error::DatabaseTransaction),
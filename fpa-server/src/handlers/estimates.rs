@@ -0,0 +1,210 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    response::IntoResponse,
+    Json,
+};
+use log::{debug, trace};
+use sea_orm::{ColumnTrait, Condition, DatabaseTransaction, EntityTrait, ModelTrait, QueryFilter};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    analysis::{estimation, round},
+    configuration::Configuration,
+    ctx::Context,
+    error::{Error, ErrorResponse},
+    handlers::{
+        members,
+        summaries::{self, Summary},
+    },
+    model::{
+        frontiers::{self, Entity as Frontiers},
+        prelude::*,
+        sea_orm_active_enums::{EmpiricalType, MemberType},
+    },
+    state::AppState,
+};
+
+/// Estimate params.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EstimateParams {
+    /// Number of people in the team.
+    #[param(minimum = 1, default = 1)]
+    team: Option<i32>,
+    /// Working hours per day for each person.
+    #[param(minimum = 1, maximum = 24, default = 8)]
+    hours: Option<i32>,
+    /// Cost of a working hour.
+    #[param(minimum = 0)]
+    rate: Option<f64>,
+}
+
+impl EstimateParams {
+    pub fn team(&self) -> i32 {
+        self.team.unwrap_or(1)
+    }
+
+    pub fn hours(&self) -> i32 {
+        self.hours.unwrap_or(8)
+    }
+
+    pub fn rate(&self) -> Option<f64> {
+        self.rate
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        if self.team() < 1 || !(1..=24).contains(&self.hours()) {
+            return Err(Error::ParamInvalid);
+        }
+        if let Some(rate) = self.rate {
+            if !rate.is_finite() || rate < 0.0 {
+                return Err(Error::ParamInvalid);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Hours estimated for an Empirical phase.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EstimatePhase {
+    /// Empirical`s Factor of the phase.
+    pub empirical: EmpiricalType,
+    /// Percent of the construction hours.
+    pub percent: i32,
+    /// Hours estimated for the phase.
+    pub hours: f64,
+}
+
+/// Effort, schedule and cost estimated for a Frontier.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct Estimate {
+    /// Frontier Unique Identifier.
+    pub frontier: Uuid,
+    /// Function points used in the estimate (EFP for enhancements, AFP otherwise).
+    pub points: f64,
+    /// Productivity (hours/FP).
+    pub productivity: i32,
+    /// Hours of construction.
+    pub construction: f64,
+    /// Hours for each Empirical phase.
+    pub phases: Vec<EstimatePhase>,
+    /// Total of hours (construction and phases).
+    pub hours: f64,
+    /// Number of people in the team.
+    pub team: i32,
+    /// Working hours per day for each person.
+    pub daily: i32,
+    /// Working days estimated.
+    pub duration: f64,
+    /// Cost of a working hour.
+    pub rate: Option<f64>,
+    /// Total cost, when a rate is informed.
+    pub cost: Option<f64>,
+}
+
+/// Effort, schedule and cost estimate for a selected Project and Frontier.
+#[utoipa::path(
+    tag = "Estimates",
+    get,
+    path = "/api/projects/{project}/frontiers/{frontier}/estimate",
+    responses(
+        (status = OK, description = "Success", body = Estimate),
+        (status = BAD_REQUEST, description = "Invalid params.", body = ErrorResponse),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = NOT_FOUND, description = "Project or Frontier not founded.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(
+        ("project" = Uuid, Path, description = "Project Unique ID."),
+        ("frontier" = Uuid, Path, description = "Frontier Unique ID."),
+        EstimateParams,
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn by_frontier(
+    Path((project, frontier)): Path<(Uuid, Uuid)>,
+    params: Query<EstimateParams>,
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    debug!(
+        "Estimate of a Frontier (project: {} - frontier: {} - params: {:?})",
+        project, frontier, params
+    );
+
+    params.validate()?;
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Viewer, &ctx, &db).await?;
+
+    let mut conditions = Condition::all();
+    conditions = conditions.add(frontiers::Column::Frontier.eq(frontier));
+    conditions = conditions.add(frontiers::Column::Project.eq(project));
+
+    let frontier = match Frontiers::find().filter(conditions).one(&db).await? {
+        Some(v) => v,
+        None => return Err(Error::NotFound),
+    };
+
+    let config = state.configuration();
+    let summary = summaries::calculate(&frontier, None, config, &db).await?;
+    let estimate = calculate(&frontier, &summary, &params, config, &db).await?;
+
+    trace!("::: {:?}", json!(estimate));
+    Ok(Json(estimate))
+}
+
+/// Effort, schedule and cost of the function points of a Frontier summary.
+///
+/// Frontiers without the Productivity use the one configured for the new Frontiers.
+pub(crate) async fn calculate(
+    frontier: &frontiers::Model,
+    summary: &Summary,
+    params: &EstimateParams,
+    config: &Configuration,
+    db: &DatabaseTransaction,
+) -> Result<Estimate, Error> {
+    trace!("Calculating Estimate for Frontier: {:?}", frontier.frontier);
+
+    let empiricals = frontier.find_related(Empiricals).all(db).await?;
+
+    let productivity = empiricals
+        .iter()
+        .find(|e| !estimation::is_phase(&e.empirical))
+        .map(|e| e.value)
+        .unwrap_or(config.empiricals.productivity);
+    let construction = estimation::construction(summary.points(), productivity);
+
+    let phases: Vec<EstimatePhase> = empiricals
+        .iter()
+        .filter(|e| estimation::is_phase(&e.empirical))
+        .map(|e| EstimatePhase {
+            empirical: e.empirical.clone(),
+            percent: e.value,
+            hours: estimation::phase(construction, e.value),
+        })
+        .collect();
+
+    let hours = round(construction + phases.iter().map(|p| p.hours).sum::<f64>());
+    Ok(Estimate {
+        frontier: frontier.frontier,
+        points: summary.points(),
+        productivity,
+        construction,
+        phases,
+        hours,
+        team: params.team(),
+        daily: params.hours(),
+        duration: estimation::duration(hours, params.team(), params.hours()),
+        rate: params.rate(),
+        cost: params.rate().map(|rate| estimation::cost(hours, rate)),
+    })
+}
@@ -18,12 +18,13 @@ use uuid::Uuid;
 use crate::{
     ctx::Context,
     error::{Error, ErrorResponse},
+    handlers::{audits, members},
     model::{
         factors::{self, ActiveModel, Model},
         frontiers::{self, Entity as Frontiers},
         page::Page,
         prelude::*,
-        sea_orm_active_enums::{FactorType, InfluenceType},
+        sea_orm_active_enums::{AuditAction, AuditEntity, FactorType, InfluenceType, MemberType},
     },
     state::AppState,
 };
@@ -36,6 +37,7 @@ use crate::{
     responses(
         (status = OK, description = "Success", body = factors::Model),
         (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
         (status = NOT_FOUND, description = "Project not founded.", body = ErrorResponse),
         (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
     ),
@@ -56,7 +58,8 @@ pub async fn list(
     );
 
     let ctx = context.unwrap();
-    let db = state.connection(ctx.tenant()).await?;
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Viewer, &ctx, &db).await?;
 
     let mut conditions = Condition::all();
     conditions = conditions.add(frontiers::Column::Frontier.eq(frontier));
@@ -96,6 +99,7 @@ pub struct FactorParam {
     responses(
         (status = OK, description = "Success", body = factors::Model),
         (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
         (status = NOT_FOUND, description = "Project not founded.", body = ErrorResponse),
         (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
     ),
@@ -117,7 +121,8 @@ pub async fn update(
     );
 
     let ctx = context.unwrap();
-    let db = state.connection(ctx.tenant()).await?;
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Editor, &ctx, &db).await?;
 
     let mut conditions = Condition::all();
     conditions = conditions.add(frontiers::Column::Project.eq(project));
@@ -134,11 +139,23 @@ pub async fn update(
         Some(v) => v,
         None => return Err(Error::NotFound),
     };
+    let before = json!(data);
 
     let mut data: ActiveModel = data.into();
     data.influence = Set(params.influence);
 
     let data: Model = data.update(&db).await?;
+    audits::register(
+        project,
+        AuditEntity::Factor,
+        frontier,
+        AuditAction::Update,
+        Some(before),
+        Some(json!(data)),
+        &ctx,
+        &db,
+    )
+    .await?;
     match db.commit().await {
         Ok(it) => it,
         Err(_) => return Err(Error::DatabaseTransaction),
@@ -12,6 +12,7 @@ use sea_orm::{
     ModelTrait, PaginatorTrait, QueryFilter, Set,
 };
 use serde::Deserialize;
+use serde_json::json;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
@@ -20,17 +21,22 @@ use crate::{
     model::{
         self, factors,
         prelude::Frontiers,
-        sea_orm_active_enums::{EmpiricalType, FactorType, InfluenceType},
+        sea_orm_active_enums::{
+            CountingMethod, CountingType, EmpiricalType, FactorType, InfluenceType,
+        },
     },
 };
 use crate::{
     ctx::Context,
     error::{Error, ErrorResponse},
+    handlers::{audits, members},
     model::{
         frontiers::{self, ActiveModel, Model},
         page::{Page, PageParams},
+        sea_orm_active_enums::{AuditAction, AuditEntity, MemberType},
     },
     state::AppState,
+    tenants::{self, Resource},
 };
 
 /// Search for a set of Frontiers for a Project.
@@ -41,6 +47,7 @@ use crate::{
     responses(
         (status = OK, description = "Success", body = Page<frontiers::Model>),
         (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
         (status = NOT_FOUND, description = "Project not founded.", body = ErrorResponse),
         (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
     ),
@@ -65,7 +72,9 @@ pub async fn list(
     }
 
     let ctx = context.unwrap();
-    let db = state.connection(ctx.tenant()).await?;
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Viewer, &ctx, &db).await?;
+
     let paginator = Frontiers::find()
         .filter(conditions)
         .paginate(&db, params.size());
@@ -90,6 +99,7 @@ pub async fn list(
     responses(
         (status = OK, description = "Success.", body = frontiers::Model),
         (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
         (status = NOT_FOUND, description = "Project not founded.", body = ErrorResponse),
         (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
     ),
@@ -110,7 +120,8 @@ pub async fn by_id(
     );
 
     let ctx = context.unwrap();
-    let db = state.connection(ctx.tenant()).await?;
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Viewer, &ctx, &db).await?;
 
     let mut conditions = Condition::all();
     conditions = conditions.add(frontiers::Column::Project.eq(project));
@@ -133,6 +144,10 @@ pub struct FrontierParam {
     pub name: String,
     /// Description for the Frontier.
     pub description: Option<String>,
+    /// Type of counting for the Frontier (default DEVELOPMENT on creation).
+    pub counting: Option<CountingType>,
+    /// Method of counting for the Frontier (default DETAILED on creation).
+    pub method: Option<CountingMethod>,
 }
 
 /// Create a new Frontier for a selected Project.
@@ -143,6 +158,7 @@ pub struct FrontierParam {
     responses(
         (status = CREATED, description = "Success.", body = frontiers::Model, headers(("Location", description = "New Frontier address."))),
         (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
         (status = CONFLICT, description = "The name must be unique for the selected project.", body = ErrorResponse),
         (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
     ),
@@ -163,8 +179,11 @@ pub async fn create(
     );
 
     let ctx = context.unwrap();
-    let db = state.connection(ctx.tenant()).await?;
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Editor, &ctx, &db).await?;
+
     let config = state.configuration();
+    tenants::quota(Resource::Frontiers, config, &db).await?;
 
     let frontier = frontiers::ActiveModel {
         project: Set(project.clone()),
@@ -172,6 +191,11 @@ pub async fn create(
         frontier: Set(Uuid::now_v7()),
         name: Set(params.name.to_owned()),
         description: Set(params.description.to_owned()),
+        counting: Set(params
+            .counting
+            .to_owned()
+            .unwrap_or(CountingType::Development)),
+        method: Set(params.method.to_owned().unwrap_or(CountingMethod::Detailed)),
     };
     let frontier = match frontier.insert(&db).await {
         Ok(v) => v,
@@ -197,6 +221,18 @@ pub async fn create(
         Err(_) => return Err(Error::ProjectEmpiricalCreate),
     };
 
+    audits::register(
+        project,
+        AuditEntity::Frontier,
+        frontier.frontier,
+        AuditAction::Create,
+        None,
+        Some(json!(frontier)),
+        &ctx,
+        &db,
+    )
+    .await?;
+
     match db.commit().await {
         Ok(it) => it,
         Err(_) => return Err(Error::DatabaseTransaction),
@@ -227,7 +263,11 @@ pub async fn create(
     Ok((StatusCode::CREATED, header, Json(frontier)))
 }
 
-async fn add_factors(db: &DatabaseTransaction, frontier: Uuid, tenant: Uuid) -> Result<(), DbErr> {
+pub(crate) async fn add_factors(
+    db: &DatabaseTransaction,
+    frontier: Uuid,
+    tenant: Uuid,
+) -> Result<(), DbErr> {
     for factor_type in FactorType::iter() {
         let factor = factors::ActiveModel {
             frontier: Set(frontier),
@@ -240,7 +280,7 @@ async fn add_factors(db: &DatabaseTransaction, frontier: Uuid, tenant: Uuid) ->
     Ok(())
 }
 
-async fn add_empiricals(
+pub(crate) async fn add_empiricals(
     db: &DatabaseTransaction,
     frontier: Uuid,
     tenant: Uuid,
@@ -297,6 +337,7 @@ async fn add_empiricals(
     responses(
         (status = OK, description = "Success.", body = frontiers::Model),
         (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
         (status = NOT_FOUND, description = "Project or Frontier not founded.", body = ErrorResponse),
         (status = CONFLICT, description = "The name must be unique for the selected project.", body = ErrorResponse),
         (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
@@ -319,7 +360,8 @@ pub async fn update(
     );
 
     let ctx = context.unwrap();
-    let db = state.connection(ctx.tenant()).await?;
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Editor, &ctx, &db).await?;
 
     let mut conditions = Condition::all();
     conditions = conditions.add(frontiers::Column::Project.eq(project));
@@ -330,10 +372,17 @@ pub async fn update(
         Some(v) => v,
         None => return Err(Error::NotFound),
     };
+    let before = json!(data);
 
     let mut data: ActiveModel = data.into();
     data.name = Set(params.name);
     data.description = Set(params.description);
+    if let Some(counting) = params.counting {
+        data.counting = Set(counting);
+    }
+    if let Some(method) = params.method {
+        data.method = Set(method);
+    }
 
     let data: Model = match data.update(&db).await {
         Ok(v) => v,
@@ -346,6 +395,22 @@ pub async fn update(
             };
         }
     };
+    audits::register(
+        project,
+        AuditEntity::Frontier,
+        frontier,
+        AuditAction::Update,
+        Some(before),
+        Some(json!(data)),
+        &ctx,
+        &db,
+    )
+    .await?;
+
+    match db.commit().await {
+        Ok(it) => it,
+        Err(_) => return Err(Error::DatabaseTransaction),
+    };
 
     trace!("::: {:?}", data);
     Ok((StatusCode::OK, Json(data)))
@@ -359,6 +424,7 @@ pub async fn update(
     responses(
         (status = NO_CONTENT, description = "Success."),
         (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
         (status = NOT_FOUND, description = "Project or Frontier not founded.", body = ErrorResponse),
         (status = PRECONDITION_FAILED, description = "Frontier has related records.", body = ErrorResponse),
         (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse),
@@ -380,7 +446,8 @@ pub async fn remove(
     );
 
     let ctx = context.unwrap();
-    let db = state.connection(ctx.tenant()).await?;
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Editor, &ctx, &db).await?;
 
     let mut conditions = Condition::all();
     conditions = conditions.add(frontiers::Column::Project.eq(project));
@@ -391,6 +458,7 @@ pub async fn remove(
         Some(v) => v,
         None => return Err(Error::NotFound),
     };
+    let before = json!(data);
 
     match data.delete(&db).await {
         Ok(v) => {
@@ -400,6 +468,17 @@ pub async fn remove(
         }
         Err(_) => return Err(Error::FrontierConstraints),
     };
+    audits::register(
+        project,
+        AuditEntity::Frontier,
+        frontier,
+        AuditAction::Delete,
+        Some(before),
+        None,
+        &ctx,
+        &db,
+    )
+    .await?;
     match db.commit().await {
         Ok(it) => it,
         Err(_) => return Err(Error::DatabaseTransaction),
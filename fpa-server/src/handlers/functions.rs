@@ -17,17 +17,24 @@ use serde_json::json;
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use crate::model::{alrs, ders, functions_datas, functions_transactions, prelude::*, rlrs};
+use crate::model::{
+    alrs, ders, ders_transactions, functions_datas, functions_transactions, prelude::*, rlrs,
+};
 use crate::{
+    analysis::complexity::{self, Complexity},
     ctx::Context,
     error::{Error, ErrorResponse},
+    handlers::{audits, members},
     model::{
         frontiers,
         functions::{self, Model},
         page::Page,
-        sea_orm_active_enums::FunctionType,
+        sea_orm_active_enums::{
+            AuditAction, AuditEntity, ChangeType, FunctionType, ImpactType, MemberType,
+        },
     },
     state::AppState,
+    tenants::{self, Resource},
 };
 
 /// Data Element Reference
@@ -59,8 +66,43 @@ pub struct FunctionALI {
     pub name: String,
     /// Description of the Function.
     pub description: Option<String>,
+    /// Change made to the Function in an enhancement counting.
+    pub change: Option<ChangeType>,
+    /// Impact factor (SISP) applied to the Function.
+    pub impact: Option<ImpactType>,
+    /// Enhancement Frontier that last changed the Function on a baseline.
+    pub enhancement: Option<Uuid>,
     /// Set of Record Layout Reference.
     pub rlrs: Vec<RLR>,
+    /// Complexity of the Function.
+    pub complexity: Complexity,
+    /// Unadjusted function points of the Function.
+    pub points: i32,
+}
+
+impl FunctionALI {
+    pub fn new(
+        id: Uuid,
+        name: String,
+        description: Option<String>,
+        change: Option<ChangeType>,
+        impact: Option<ImpactType>,
+        rlrs: Vec<RLR>,
+    ) -> Self {
+        let dets = rlrs.iter().map(|rlr| rlr.ders.len()).sum();
+        let complexity = complexity::data(rlrs.len(), dets);
+        Self {
+            id,
+            name,
+            description,
+            change,
+            impact,
+            enhancement: None,
+            rlrs,
+            complexity,
+            points: complexity::points(&FunctionType::ALI, &complexity),
+        }
+    }
 }
 
 /// Internal Logic File Function for create or update data.
@@ -70,7 +112,12 @@ pub struct FunctionALIParam {
     pub name: String,
     /// Description of the Function.
     pub description: Option<String>,
+    /// Change made to the Function in an enhancement counting.
+    pub change: Option<ChangeType>,
+    /// Impact factor (SISP) applied to the Function.
+    pub impact: Option<ImpactType>,
     /// Set of Record Layout Reference.
+    #[serde(default)]
     pub rlrs: Vec<RLR>,
 }
 
@@ -83,8 +130,43 @@ pub struct FunctionAIE {
     pub name: String,
     /// Description of the Function.
     pub description: Option<String>,
+    /// Change made to the Function in an enhancement counting.
+    pub change: Option<ChangeType>,
+    /// Impact factor (SISP) applied to the Function.
+    pub impact: Option<ImpactType>,
+    /// Enhancement Frontier that last changed the Function on a baseline.
+    pub enhancement: Option<Uuid>,
     /// Set of Record Layout Reference.
     pub rlrs: Vec<RLR>,
+    /// Complexity of the Function.
+    pub complexity: Complexity,
+    /// Unadjusted function points of the Function.
+    pub points: i32,
+}
+
+impl FunctionAIE {
+    pub fn new(
+        id: Uuid,
+        name: String,
+        description: Option<String>,
+        change: Option<ChangeType>,
+        impact: Option<ImpactType>,
+        rlrs: Vec<RLR>,
+    ) -> Self {
+        let dets = rlrs.iter().map(|rlr| rlr.ders.len()).sum();
+        let complexity = complexity::data(rlrs.len(), dets);
+        Self {
+            id,
+            name,
+            description,
+            change,
+            impact,
+            enhancement: None,
+            rlrs,
+            complexity,
+            points: complexity::points(&FunctionType::AIE, &complexity),
+        }
+    }
 }
 
 /// External Interface File Function for create or update data.
@@ -94,7 +176,12 @@ pub struct FunctionAIEParam {
     pub name: String,
     /// Description of the Function.
     pub description: Option<String>,
+    /// Change made to the Function in an enhancement counting.
+    pub change: Option<ChangeType>,
+    /// Impact factor (SISP) applied to the Function.
+    pub impact: Option<ImpactType>,
     /// Set of Record Layout Reference.
+    #[serde(default)]
     pub rlrs: Vec<RLR>,
 }
 
@@ -114,6 +201,28 @@ pub struct ALR {
     pub id: Uuid,
 }
 
+/// Reference to a DER of a Data Function associated with the Transaction Function.
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct DERReference {
+    /// Unique Identifier of the Data Function.
+    pub alr: Uuid,
+    /// Name of the RLR in the Data Function.
+    pub rlr: String,
+    /// Name of the DER in the RLR.
+    pub der: String,
+}
+
+/// Data Element Type crossing the boundary of the Transaction Function.
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct DET {
+    /// Unique Identifier of the DET.
+    pub name: String,
+    /// Description of the DET.
+    pub description: Option<String>,
+    /// DER of an associated Data Function traced by the DET.
+    pub reference: Option<DERReference>,
+}
+
 /// External Input Function.
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct FunctionEE {
@@ -123,8 +232,46 @@ pub struct FunctionEE {
     pub name: String,
     /// Description of the Function.
     pub description: Option<String>,
+    /// Change made to the Function in an enhancement counting.
+    pub change: Option<ChangeType>,
+    /// Impact factor (SISP) applied to the Function.
+    pub impact: Option<ImpactType>,
+    /// Enhancement Frontier that last changed the Function on a baseline.
+    pub enhancement: Option<Uuid>,
     /// Set of Data Functions (ALI and AIE).
     pub alrs: Vec<FunctionData>,
+    /// Set of Data Element Types.
+    pub dets: Vec<DET>,
+    /// Complexity of the Function.
+    pub complexity: Complexity,
+    /// Unadjusted function points of the Function.
+    pub points: i32,
+}
+
+impl FunctionEE {
+    pub fn new(
+        id: Uuid,
+        name: String,
+        description: Option<String>,
+        change: Option<ChangeType>,
+        impact: Option<ImpactType>,
+        alrs: Vec<FunctionData>,
+        dets: Vec<DET>,
+    ) -> Self {
+        let complexity = complexity::transaction(&FunctionType::EE, alrs.len(), dets.len());
+        Self {
+            id,
+            name,
+            description,
+            change,
+            impact,
+            enhancement: None,
+            alrs,
+            dets,
+            complexity,
+            points: complexity::points(&FunctionType::EE, &complexity),
+        }
+    }
 }
 
 /// External Input Function for create or update data.
@@ -134,8 +281,15 @@ pub struct FunctionEEParam {
     pub name: String,
     /// Description of the Function.
     pub description: Option<String>,
+    /// Change made to the Function in an enhancement counting.
+    pub change: Option<ChangeType>,
+    /// Impact factor (SISP) applied to the Function.
+    pub impact: Option<ImpactType>,
     /// Set of Data Functions (ALI and AIE).
     pub alrs: Vec<ALR>,
+    /// Set of Data Element Types.
+    #[serde(default)]
+    pub dets: Vec<DET>,
 }
 
 /// External Inquiry Function.
@@ -147,8 +301,46 @@ pub struct FunctionCE {
     pub name: String,
     /// Description of the Function.
     pub description: Option<String>,
+    /// Change made to the Function in an enhancement counting.
+    pub change: Option<ChangeType>,
+    /// Impact factor (SISP) applied to the Function.
+    pub impact: Option<ImpactType>,
+    /// Enhancement Frontier that last changed the Function on a baseline.
+    pub enhancement: Option<Uuid>,
     /// Set of Data Functions (ALI and AIE).
     pub alrs: Vec<FunctionData>,
+    /// Set of Data Element Types.
+    pub dets: Vec<DET>,
+    /// Complexity of the Function.
+    pub complexity: Complexity,
+    /// Unadjusted function points of the Function.
+    pub points: i32,
+}
+
+impl FunctionCE {
+    pub fn new(
+        id: Uuid,
+        name: String,
+        description: Option<String>,
+        change: Option<ChangeType>,
+        impact: Option<ImpactType>,
+        alrs: Vec<FunctionData>,
+        dets: Vec<DET>,
+    ) -> Self {
+        let complexity = complexity::transaction(&FunctionType::CE, alrs.len(), dets.len());
+        Self {
+            id,
+            name,
+            description,
+            change,
+            impact,
+            enhancement: None,
+            alrs,
+            dets,
+            complexity,
+            points: complexity::points(&FunctionType::CE, &complexity),
+        }
+    }
 }
 
 /// External Inquiry Function for create or update data.
@@ -158,8 +350,15 @@ pub struct FunctionCEParam {
     pub name: String,
     /// Description of the Function.
     pub description: Option<String>,
+    /// Change made to the Function in an enhancement counting.
+    pub change: Option<ChangeType>,
+    /// Impact factor (SISP) applied to the Function.
+    pub impact: Option<ImpactType>,
     /// Set of Data Functions (ALI and AIE).
     pub alrs: Vec<ALR>,
+    /// Set of Data Element Types.
+    #[serde(default)]
+    pub dets: Vec<DET>,
 }
 
 /// External Output Function.
@@ -171,8 +370,46 @@ pub struct FunctionSE {
     pub name: String,
     /// Description of the Function.
     pub description: Option<String>,
+    /// Change made to the Function in an enhancement counting.
+    pub change: Option<ChangeType>,
+    /// Impact factor (SISP) applied to the Function.
+    pub impact: Option<ImpactType>,
+    /// Enhancement Frontier that last changed the Function on a baseline.
+    pub enhancement: Option<Uuid>,
     /// Set of Data Functions (ALI and AIE).
     pub alrs: Vec<FunctionData>,
+    /// Set of Data Element Types.
+    pub dets: Vec<DET>,
+    /// Complexity of the Function.
+    pub complexity: Complexity,
+    /// Unadjusted function points of the Function.
+    pub points: i32,
+}
+
+impl FunctionSE {
+    pub fn new(
+        id: Uuid,
+        name: String,
+        description: Option<String>,
+        change: Option<ChangeType>,
+        impact: Option<ImpactType>,
+        alrs: Vec<FunctionData>,
+        dets: Vec<DET>,
+    ) -> Self {
+        let complexity = complexity::transaction(&FunctionType::SE, alrs.len(), dets.len());
+        Self {
+            id,
+            name,
+            description,
+            change,
+            impact,
+            enhancement: None,
+            alrs,
+            dets,
+            complexity,
+            points: complexity::points(&FunctionType::SE, &complexity),
+        }
+    }
 }
 
 /// External Output Function for create or update data.
@@ -182,8 +419,15 @@ pub struct FunctionSEParam {
     pub name: String,
     /// Description of the Function.
     pub description: Option<String>,
+    /// Change made to the Function in an enhancement counting.
+    pub change: Option<ChangeType>,
+    /// Impact factor (SISP) applied to the Function.
+    pub impact: Option<ImpactType>,
     /// Set of Data Functions (ALI and AIE).
     pub alrs: Vec<ALR>,
+    /// Set of Data Element Types.
+    #[serde(default)]
+    pub dets: Vec<DET>,
 }
 
 /// Type of the Function.
@@ -201,6 +445,120 @@ pub enum Function {
     SE(FunctionSE),
 }
 
+impl Function {
+    pub fn id(&self) -> Uuid {
+        match self {
+            Function::ALI(f) => f.id,
+            Function::AIE(f) => f.id,
+            Function::EE(f) => f.id,
+            Function::CE(f) => f.id,
+            Function::SE(f) => f.id,
+        }
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            Function::ALI(f) => f.name.clone(),
+            Function::AIE(f) => f.name.clone(),
+            Function::EE(f) => f.name.clone(),
+            Function::CE(f) => f.name.clone(),
+            Function::SE(f) => f.name.clone(),
+        }
+    }
+
+    pub fn r#type(&self) -> FunctionType {
+        match self {
+            Function::ALI(_) => FunctionType::ALI,
+            Function::AIE(_) => FunctionType::AIE,
+            Function::EE(_) => FunctionType::EE,
+            Function::CE(_) => FunctionType::CE,
+            Function::SE(_) => FunctionType::SE,
+        }
+    }
+
+    pub fn complexity(&self) -> Complexity {
+        match self {
+            Function::ALI(f) => f.complexity,
+            Function::AIE(f) => f.complexity,
+            Function::EE(f) => f.complexity,
+            Function::CE(f) => f.complexity,
+            Function::SE(f) => f.complexity,
+        }
+    }
+
+    pub fn points(&self) -> i32 {
+        match self {
+            Function::ALI(f) => f.points,
+            Function::AIE(f) => f.points,
+            Function::EE(f) => f.points,
+            Function::CE(f) => f.points,
+            Function::SE(f) => f.points,
+        }
+    }
+
+    /// Number of RLRs (Data Function) or FTRs (Transaction Function).
+    pub fn records(&self) -> usize {
+        match self {
+            Function::ALI(f) => f.rlrs.len(),
+            Function::AIE(f) => f.rlrs.len(),
+            Function::EE(f) => f.alrs.len(),
+            Function::CE(f) => f.alrs.len(),
+            Function::SE(f) => f.alrs.len(),
+        }
+    }
+
+    /// Number of DERs (Data Function) or DETs (Transaction Function).
+    pub fn elements(&self) -> usize {
+        match self {
+            Function::ALI(f) => f.rlrs.iter().map(|rlr| rlr.ders.len()).sum(),
+            Function::AIE(f) => f.rlrs.iter().map(|rlr| rlr.ders.len()).sum(),
+            Function::EE(f) => f.dets.len(),
+            Function::CE(f) => f.dets.len(),
+            Function::SE(f) => f.dets.len(),
+        }
+    }
+
+    pub fn change(&self) -> Option<ChangeType> {
+        match self {
+            Function::ALI(f) => f.change.clone(),
+            Function::AIE(f) => f.change.clone(),
+            Function::EE(f) => f.change.clone(),
+            Function::CE(f) => f.change.clone(),
+            Function::SE(f) => f.change.clone(),
+        }
+    }
+
+    pub fn impact(&self) -> Option<ImpactType> {
+        match self {
+            Function::ALI(f) => f.impact.clone(),
+            Function::AIE(f) => f.impact.clone(),
+            Function::EE(f) => f.impact.clone(),
+            Function::CE(f) => f.impact.clone(),
+            Function::SE(f) => f.impact.clone(),
+        }
+    }
+
+    pub fn enhancement(&self) -> Option<Uuid> {
+        match self {
+            Function::ALI(f) => f.enhancement,
+            Function::AIE(f) => f.enhancement,
+            Function::EE(f) => f.enhancement,
+            Function::CE(f) => f.enhancement,
+            Function::SE(f) => f.enhancement,
+        }
+    }
+
+    pub fn set_enhancement(&mut self, enhancement: Option<Uuid>) {
+        match self {
+            Function::ALI(f) => f.enhancement = enhancement,
+            Function::AIE(f) => f.enhancement = enhancement,
+            Function::EE(f) => f.enhancement = enhancement,
+            Function::CE(f) => f.enhancement = enhancement,
+            Function::SE(f) => f.enhancement = enhancement,
+        }
+    }
+}
+
 /// Type of the Function for create or update data.
 #[derive(Debug, Deserialize, ToSchema)]
 pub enum FunctionParam {
@@ -276,6 +634,7 @@ impl FunctionsParams {
     responses(
         (status = OK, description = "Success", body = Page<Function>),
         (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
         (status = NOT_FOUND, description = "Project not founded.", body = ErrorResponse),
         (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
     ),
@@ -308,7 +667,9 @@ pub async fn list(
     }
 
     let ctx = context.unwrap();
-    let db = state.connection(ctx.tenant()).await?;
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Viewer, &ctx, &db).await?;
+
     let paginator = Functions::find()
         .inner_join(frontiers::Entity)
         .filter(conditions)
@@ -336,6 +697,7 @@ pub async fn list(
     responses(
         (status = OK, description = "Success", body = Function),
         (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
         (status = NOT_FOUND, description = "Project not founded.", body = ErrorResponse),
         (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
     ),
@@ -362,7 +724,9 @@ pub async fn by_id(
     conditions = conditions.add(functions::Column::Function.eq(function));
 
     let ctx = context.unwrap();
-    let db = state.connection(ctx.tenant()).await?;
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Viewer, &ctx, &db).await?;
+
     let data = match Functions::find()
         .inner_join(frontiers::Entity)
         .filter(conditions)
@@ -380,40 +744,54 @@ pub async fn by_id(
     Ok(data)
 }
 
-async fn translate(func: Model, db: &DatabaseTransaction) -> Result<Function, Error> {
+pub(crate) async fn translate(func: Model, db: &DatabaseTransaction) -> Result<Function, Error> {
     trace!("Translate Function: {:?}", func);
-    let result = match func.r#type {
-        FunctionType::ALI => Function::ALI(FunctionALI {
-            id: func.function,
-            name: func.name,
-            description: func.description,
-            rlrs: load_rlrs(func.function, &db).await?,
-        }),
-        FunctionType::AIE => Function::AIE(FunctionAIE {
-            id: func.function,
-            name: func.name,
-            description: func.description,
-            rlrs: load_rlrs(func.function, &db).await?,
-        }),
-        FunctionType::EE => Function::EE(FunctionEE {
-            id: func.function,
-            name: func.name,
-            description: func.description,
-            alrs: load_arls(func.function, &db).await?,
-        }),
-        FunctionType::CE => Function::CE(FunctionCE {
-            id: func.function,
-            name: func.name,
-            description: func.description,
-            alrs: load_arls(func.function, &db).await?,
-        }),
-        FunctionType::SE => Function::SE(FunctionSE {
-            id: func.function,
-            name: func.name,
-            description: func.description,
-            alrs: load_arls(func.function, &db).await?,
-        }),
+    let mut result = match func.r#type {
+        FunctionType::ALI => Function::ALI(FunctionALI::new(
+            func.function,
+            func.name,
+            func.description,
+            func.change,
+            func.impact,
+            load_rlrs(func.function, &db).await?,
+        )),
+        FunctionType::AIE => Function::AIE(FunctionAIE::new(
+            func.function,
+            func.name,
+            func.description,
+            func.change,
+            func.impact,
+            load_rlrs(func.function, &db).await?,
+        )),
+        FunctionType::EE => Function::EE(FunctionEE::new(
+            func.function,
+            func.name,
+            func.description,
+            func.change,
+            func.impact,
+            load_arls(func.function, &db).await?,
+            load_dets(func.function, db).await?,
+        )),
+        FunctionType::CE => Function::CE(FunctionCE::new(
+            func.function,
+            func.name,
+            func.description,
+            func.change,
+            func.impact,
+            load_arls(func.function, &db).await?,
+            load_dets(func.function, db).await?,
+        )),
+        FunctionType::SE => Function::SE(FunctionSE::new(
+            func.function,
+            func.name,
+            func.description,
+            func.change,
+            func.impact,
+            load_arls(func.function, &db).await?,
+            load_dets(func.function, db).await?,
+        )),
     };
+    result.set_enhancement(func.enhancement);
 
     Ok(result)
 }
@@ -431,16 +809,26 @@ async fn load_arls(function: Uuid, db: &DatabaseTransaction) -> Result<Vec<Funct
     for alr in alrs {
         let data = match alr.r#type {
             FunctionType::ALI => FunctionData::ALI(FunctionALI {
-                id: alr.function,
-                name: alr.name,
-                description: alr.description,
-                rlrs: load_rlrs(alr.function, &db).await?,
+                enhancement: alr.enhancement,
+                ..FunctionALI::new(
+                    alr.function,
+                    alr.name,
+                    alr.description,
+                    alr.change,
+                    alr.impact,
+                    load_rlrs(alr.function, &db).await?,
+                )
             }),
             FunctionType::AIE => FunctionData::AIE(FunctionAIE {
-                id: alr.function,
-                name: alr.name,
-                description: alr.description,
-                rlrs: load_rlrs(alr.function, &db).await?,
+                enhancement: alr.enhancement,
+                ..FunctionAIE::new(
+                    alr.function,
+                    alr.name,
+                    alr.description,
+                    alr.change,
+                    alr.impact,
+                    load_rlrs(alr.function, &db).await?,
+                )
             }),
             _ => return Err(Error::NotFunctionData),
         };
@@ -487,6 +875,29 @@ async fn load_rlrs(function: Uuid, db: &DatabaseTransaction) -> Result<Vec<RLR>,
     Ok(result)
 }
 
+async fn load_dets(function: Uuid, db: &DatabaseTransaction) -> Result<Vec<DET>, Error> {
+    trace!("Load DETS for Function: {:?}", function);
+
+    let dets = DersTransactions::find()
+        .filter(Condition::all().add(ders_transactions::Column::Function.eq(function)))
+        .all(db)
+        .await?;
+
+    let result = dets
+        .into_iter()
+        .map(|det| DET {
+            name: det.name,
+            description: det.description,
+            reference: match (det.alr, det.rlr, det.der) {
+                (Some(alr), Some(rlr), Some(der)) => Some(DERReference { alr, rlr, der }),
+                _ => None,
+            },
+        })
+        .collect();
+
+    Ok(result)
+}
+
 /// Create a new Function for a selected Project and Frontier.
 #[utoipa::path(
     tag = "Functions",
@@ -495,6 +906,7 @@ async fn load_rlrs(function: Uuid, db: &DatabaseTransaction) -> Result<Vec<RLR>,
     responses(
         (status = CREATED, description = "Success.", body = Function, headers(("Location", description = "New function address."))),
         (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
         (status = NOT_FOUND, description = "Project or Frontier not founded.", body = ErrorResponse),
         (status = NOT_ACCEPTABLE, description = "Function Type incorrect.", body = ErrorResponse),
         (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
@@ -517,8 +929,11 @@ pub async fn create(
     );
 
     let ctx = context.unwrap();
-    let db = state.connection(ctx.tenant()).await?;
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Editor, &ctx, &db).await?;
+
     let config = state.configuration();
+    tenants::quota(Resource::Functions, config, &db).await?;
 
     // Frontier must belong to the Project.
     match Frontiers::find()
@@ -542,6 +957,17 @@ pub async fn create(
             insert_function_transaction(params, frontier, &db, &ctx).await?
         }
     };
+    audits::register(
+        project,
+        AuditEntity::Function,
+        id,
+        AuditAction::Create,
+        None,
+        Some(json!(function)),
+        &ctx,
+        &db,
+    )
+    .await?;
 
     match db.commit().await {
         Ok(it) => it,
@@ -573,7 +999,7 @@ pub async fn create(
     Ok((StatusCode::CREATED, header, Json(function)))
 }
 
-async fn insert_function_transaction(
+pub(crate) async fn insert_function_transaction(
     data: FunctionParam,
     frontier: Uuid,
     db: &DatabaseTransaction,
@@ -588,31 +1014,41 @@ async fn insert_function_transaction(
         ..Default::default()
     };
 
-    let mut alrs = Vec::<ALR>::new();
+    let alrs: Vec<ALR>;
+    let dets: Vec<DET>;
     match data {
         FunctionParam::EE(data) => {
             function.r#type = Set(FunctionType::EE);
             function.name = Set(data.name.to_owned());
             function.description = Set(data.description.to_owned());
+            function.change = Set(data.change.to_owned());
+            function.impact = Set(data.impact.to_owned());
             alrs = data.alrs;
+            dets = data.dets;
         }
         FunctionParam::CE(data) => {
             function.r#type = Set(FunctionType::CE);
             function.name = Set(data.name.to_owned());
             function.description = Set(data.description.to_owned());
+            function.change = Set(data.change.to_owned());
+            function.impact = Set(data.impact.to_owned());
             alrs = data.alrs;
+            dets = data.dets;
         }
         FunctionParam::SE(data) => {
             function.r#type = Set(FunctionType::SE);
             function.name = Set(data.name.to_owned());
             function.description = Set(data.description.to_owned());
+            function.change = Set(data.change.to_owned());
+            function.impact = Set(data.impact.to_owned());
             alrs = data.alrs;
+            dets = data.dets;
         }
         _ => return Err(Error::NotFunctionTransaction),
     };
     let function = function.insert(db).await?;
 
-    for alr in alrs {
+    for alr in alrs.iter() {
         let item = alrs::ActiveModel {
             function: Set(function.function),
             tenant: Set(ctx.tenant().clone()),
@@ -621,32 +1057,43 @@ async fn insert_function_transaction(
         item.insert(db).await?;
     }
 
+    insert_dets(function.function, function.tenant, &alrs, dets, db).await?;
+
     let result = match function.r#type {
-        FunctionType::EE => Function::EE(FunctionEE {
-            id: function.function,
-            name: function.name,
-            description: function.description,
-            alrs: load_arls(function.function, &db).await?,
-        }),
-        FunctionType::CE => Function::CE(FunctionCE {
-            id: function.function,
-            name: function.name,
-            description: function.description,
-            alrs: load_arls(function.function, &db).await?,
-        }),
-        FunctionType::SE => Function::SE(FunctionSE {
-            id: function.function,
-            name: function.name,
-            description: function.description,
-            alrs: load_arls(function.function, &db).await?,
-        }),
+        FunctionType::EE => Function::EE(FunctionEE::new(
+            function.function,
+            function.name,
+            function.description,
+            function.change,
+            function.impact,
+            load_arls(function.function, &db).await?,
+            load_dets(function.function, db).await?,
+        )),
+        FunctionType::CE => Function::CE(FunctionCE::new(
+            function.function,
+            function.name,
+            function.description,
+            function.change,
+            function.impact,
+            load_arls(function.function, &db).await?,
+            load_dets(function.function, db).await?,
+        )),
+        FunctionType::SE => Function::SE(FunctionSE::new(
+            function.function,
+            function.name,
+            function.description,
+            function.change,
+            function.impact,
+            load_arls(function.function, &db).await?,
+            load_dets(function.function, db).await?,
+        )),
         _ => return Err(Error::FunctionCreate),
     };
 
     Ok((function.function, result))
 }
 
-async fn insert_function_data(
+pub(crate) async fn insert_function_data(
     data: FunctionParam,
     frontier: Uuid,
     db: &DatabaseTransaction,
@@ -661,18 +1108,22 @@ async fn insert_function_data(
         ..Default::default()
     };
 
-    let mut rlrs = Vec::<RLR>::new();
+    let rlrs: Vec<RLR>;
     match data {
         FunctionParam::ALI(data) => {
             function.r#type = Set(FunctionType::ALI);
             function.name = Set(data.name.to_owned());
             function.description = Set(data.description.to_owned());
+            function.change = Set(data.change.to_owned());
+            function.impact = Set(data.impact.to_owned());
             rlrs = data.rlrs;
         }
         FunctionParam::AIE(data) => {
             function.r#type = Set(FunctionType::AIE);
             function.name = Set(data.name.to_owned());
             function.description = Set(data.description.to_owned());
+            function.change = Set(data.change.to_owned());
+            function.impact = Set(data.impact.to_owned());
             rlrs = data.rlrs;
         }
         _ => return Err(Error::NotFunctionData),
@@ -701,18 +1152,22 @@ async fn insert_function_data(
     }
 
     let result = match function.r#type {
-        FunctionType::ALI => Function::ALI(FunctionALI {
-            id: function.function,
-            name: function.name,
-            description: function.description,
-            rlrs: rlrs,
-        }),
-        FunctionType::AIE => Function::AIE(FunctionAIE {
-            id: function.function,
-            name: function.name,
-            description: function.description,
-            rlrs: rlrs,
-        }),
+        FunctionType::ALI => Function::ALI(FunctionALI::new(
+            function.function,
+            function.name,
+            function.description,
+            function.change,
+            function.impact,
+            rlrs,
+        )),
+        FunctionType::AIE => Function::AIE(FunctionAIE::new(
+            function.function,
+            function.name,
+            function.description,
+            function.change,
+            function.impact,
+            rlrs,
+        )),
         _ => return Err(Error::FunctionCreate),
     };
 
@@ -727,6 +1182,7 @@ async fn insert_function_data(
     responses(
         (status = OK, description = "Success.", body = Function),
         (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
         (status = NOT_FOUND, description = "Project or Frontier not founded.", body = ErrorResponse),
         (status = NOT_ACCEPTABLE, description = "The Function Type cannot be updated.", body = ErrorResponse),
         (status = CONFLICT, description = "The name must be unique for this scope.", body = ErrorResponse),
@@ -756,7 +1212,9 @@ pub async fn update(
     conditions = conditions.add(functions::Column::Function.eq(function));
 
     let ctx = context.unwrap();
-    let db = state.connection(ctx.tenant()).await?;
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Editor, &ctx, &db).await?;
+
     let data = match Functions::find()
         .inner_join(frontiers::Entity)
         .filter(conditions)
@@ -766,42 +1224,54 @@ pub async fn update(
         Some(v) => v,
         None => return Err(Error::NotFound),
     };
+    let before = json!(translate(data.clone(), &db).await?);
 
     let data = match params {
         FunctionParam::ALI(value) => {
             if data.r#type != FunctionType::ALI {
                 return Err(Error::FunctionTypeUpdateError);
             }
-            update_function_data(function, value.name, value.description, value.rlrs, &db).await?
+            update_function_data(
+                function,
+                value.name,
+                value.description,
+                value.change,
+                value.impact,
+                value.rlrs,
+                &db,
+            )
+            .await?
         }
         FunctionParam::AIE(value) => {
             if data.r#type != FunctionType::AIE {
                 return Err(Error::FunctionTypeUpdateError);
             }
-            update_function_data(function, value.name, value.description, value.rlrs, &db).await?
-        }
-        FunctionParam::EE(value) => {
-            if data.r#type != FunctionType::EE {
-                return Err(Error::FunctionTypeUpdateError);
-            }
-            update_function_transaction(function, value.name, value.description, value.alrs, &db)
-                .await?
-        }
-        FunctionParam::CE(value) => {
-            if data.r#type != FunctionType::CE {
-                return Err(Error::FunctionTypeUpdateError);
-            }
-            update_function_transaction(function, value.name, value.description, value.alrs, &db)
-                .await?
+            update_function_data(
+                function,
+                value.name,
+                value.description,
+                value.change,
+                value.impact,
+                value.rlrs,
+                &db,
+            )
+            .await?
         }
-        FunctionParam::SE(value) => {
-            if data.r#type != FunctionType::SE {
-                return Err(Error::FunctionTypeUpdateError);
-            }
-            update_function_transaction(function, value.name, value.description, value.alrs, &db)
-                .await?
+        FunctionParam::EE(_) | FunctionParam::CE(_) | FunctionParam::SE(_) => {
+            update_function_transaction(function, data.r#type, params, &db).await?
         }
     };
+    audits::register(
+        project,
+        AuditEntity::Function,
+        function,
+        AuditAction::Update,
+        Some(before),
+        Some(json!(data)),
+        &ctx,
+        &db,
+    )
+    .await?;
 
     match db.commit().await {
         Ok(it) => it,
@@ -812,10 +1282,12 @@ pub async fn update(
     Ok(Json(data))
 }
 
-async fn update_function_data(
+pub(crate) async fn update_function_data(
     function: Uuid,
     name: String,
     description: Option<String>,
+    change: Option<ChangeType>,
+    impact: Option<ImpactType>,
     rlrs: Vec<RLR>,
     db: &DatabaseTransaction,
 ) -> Result<Function, Error> {
@@ -830,6 +1302,8 @@ async fn update_function_data(
     let mut data: functions_datas::ActiveModel = data.into();
     data.name = Set(name);
     data.description = Set(description);
+    data.change = Set(change);
+    data.impact = Set(impact);
 
     let data: functions_datas::Model = match data.update(db).await {
         Ok(v) => v,
@@ -843,50 +1317,109 @@ async fn update_function_data(
         }
     };
 
-    delete_related_rlrs(function, db).await?;
-
-    for rlr in rlrs {
-        let item = rlrs::ActiveModel {
-            function: Set(function),
-            name: Set(rlr.name.clone()),
-            tenant: Set(data.tenant.clone()),
-            description: Set(rlr.description),
-        };
-        item.insert(db).await?;
-
-        for der in rlr.ders {
-            let item = ders::ActiveModel {
-                function: Set(function),
-                rlr: Set(rlr.name.clone()),
-                name: Set(der.name),
-                tenant: Set(data.tenant.clone()),
-                description: Set(der.description),
-            };
-            item.insert(db).await?;
-        }
-    }
+    update_related_rlrs(function, data.tenant, rlrs, db).await?;
 
     let data = translate(data.into(), db).await?;
     Ok(data)
 }
 
-async fn delete_related_rlrs(function: Uuid, db: &DatabaseTransaction) -> Result<(), Error> {
-    Rlrs::delete_many()
+/// Updates the RLRs and DERs of a Data Function, matched by name.
+///
+/// The DERs kept are updated in place, so the DETs of the Transaction Functions referencing them
+/// keep the reference. Only the references to the DERs removed are cleared.
+async fn update_related_rlrs(
+    function: Uuid,
+    tenant: Uuid,
+    rlrs: Vec<RLR>,
+    db: &DatabaseTransaction,
+) -> Result<(), Error> {
+    let current = Rlrs::find()
         .filter(rlrs::Column::Function.eq(function))
-        .exec(db)
+        .all(db)
         .await?;
+    for item in current.iter() {
+        if !rlrs.iter().any(|r| r.name == item.name) {
+            item.clone().delete(db).await?;
+        }
+    }
+
+    for rlr in rlrs {
+        match current.iter().find(|r| r.name == rlr.name) {
+            Some(item) => {
+                let mut item: rlrs::ActiveModel = item.clone().into();
+                item.description = Set(rlr.description);
+                item.update(db).await?;
+            }
+            None => {
+                let item = rlrs::ActiveModel {
+                    function: Set(function),
+                    name: Set(rlr.name.clone()),
+                    tenant: Set(tenant),
+                    description: Set(rlr.description),
+                };
+                item.insert(db).await?;
+            }
+        }
+
+        let current = Ders::find()
+            .filter(
+                Condition::all()
+                    .add(ders::Column::Function.eq(function))
+                    .add(ders::Column::Rlr.eq(rlr.name.clone())),
+            )
+            .all(db)
+            .await?;
+        for item in current.iter() {
+            if !rlr.ders.iter().any(|d| d.name == item.name) {
+                item.clone().delete(db).await?;
+            }
+        }
+
+        for der in rlr.ders {
+            match current.iter().find(|d| d.name == der.name) {
+                Some(item) => {
+                    let mut item: ders::ActiveModel = item.clone().into();
+                    item.description = Set(der.description);
+                    item.update(db).await?;
+                }
+                None => {
+                    let item = ders::ActiveModel {
+                        function: Set(function),
+                        rlr: Set(rlr.name.clone()),
+                        name: Set(der.name),
+                        tenant: Set(tenant),
+                        description: Set(der.description),
+                    };
+                    item.insert(db).await?;
+                }
+            }
+        }
+    }
+
     Ok(())
 }
 
-async fn update_function_transaction(
+pub(crate) async fn update_function_transaction(
     function: Uuid,
-    name: String,
-    description: Option<String>,
-    alrs: Vec<ALR>,
+    r#type: FunctionType,
+    params: FunctionParam,
     db: &DatabaseTransaction,
 ) -> Result<Function, Error> {
     trace!("Update Function Transaction: {:?}", function);
 
+    let (name, description, change, impact, alrs, dets) = match params {
+        FunctionParam::EE(v) if r#type == FunctionType::EE => {
+            (v.name, v.description, v.change, v.impact, v.alrs, v.dets)
+        }
+        FunctionParam::CE(v) if r#type == FunctionType::CE => {
+            (v.name, v.description, v.change, v.impact, v.alrs, v.dets)
+        }
+        FunctionParam::SE(v) if r#type == FunctionType::SE => {
+            (v.name, v.description, v.change, v.impact, v.alrs, v.dets)
+        }
+        _ => return Err(Error::FunctionTypeUpdateError),
+    };
+
     let data: functions_transactions::Model = FunctionsTransactions::find()
         .filter(functions_transactions::Column::Function.eq(function))
         .one(db)
@@ -896,6 +1429,8 @@ async fn update_function_transaction(
     let mut data: functions_transactions::ActiveModel = data.into();
     data.name = Set(name);
     data.description = Set(description);
+    data.change = Set(change);
+    data.impact = Set(impact);
 
     let data: functions_transactions::Model = match data.update(db).await {
         Ok(v) => v,
@@ -909,9 +1444,10 @@ async fn update_function_transaction(
         }
     };
 
+    delete_related_dets(function, db).await?;
     delete_related_alrs(function, db).await?;
 
-    for alr in alrs {
+    for alr in alrs.iter() {
         let item = alrs::ActiveModel {
             function: Set(function),
             tenant: Set(data.tenant.clone()),
@@ -920,6 +1456,8 @@ async fn update_function_transaction(
         item.insert(db).await?;
     }
 
+    insert_dets(function, data.tenant, &alrs, dets, db).await?;
+
     let data = translate(data.into(), db).await?;
     Ok(data)
 }
@@ -932,6 +1470,60 @@ async fn delete_related_alrs(function: Uuid, db: &DatabaseTransaction) -> Result
     Ok(())
 }
 
+async fn delete_related_dets(function: Uuid, db: &DatabaseTransaction) -> Result<(), Error> {
+    DersTransactions::delete_many()
+        .filter(ders_transactions::Column::Function.eq(function))
+        .exec(db)
+        .await?;
+    Ok(())
+}
+
+async fn insert_dets(
+    function: Uuid,
+    tenant: Uuid,
+    alrs: &[ALR],
+    dets: Vec<DET>,
+    db: &DatabaseTransaction,
+) -> Result<(), Error> {
+    trace!("Insert DETS for Function: {:?}", function);
+
+    for det in dets {
+        let mut item = ders_transactions::ActiveModel {
+            function: Set(function),
+            name: Set(det.name),
+            description: Set(det.description),
+            tenant: Set(tenant),
+            ..Default::default()
+        };
+
+        // The referenced DER must belong to a Data Function associated with the Transaction.
+        if let Some(reference) = det.reference {
+            if !alrs.iter().any(|alr| alr.id == reference.alr) {
+                return Err(Error::DERReferenceInvalid);
+            }
+            let der = Ders::find()
+                .filter(
+                    Condition::all()
+                        .add(ders::Column::Function.eq(reference.alr))
+                        .add(ders::Column::Rlr.eq(reference.rlr.clone()))
+                        .add(ders::Column::Name.eq(reference.der.clone())),
+                )
+                .one(db)
+                .await?;
+            if der.is_none() {
+                return Err(Error::DERReferenceInvalid);
+            }
+            item.alr = Set(Some(reference.alr));
+            item.rlr = Set(Some(reference.rlr));
+            item.der = Set(Some(reference.der));
+        }
+
+        item.insert(db).await?;
+    }
+
+    Ok(())
+}
+
 /// Remove a existing Function.
 #[utoipa::path(
     tag = "Functions",
@@ -940,6 +1532,7 @@ async fn delete_related_alrs(function: Uuid, db: &DatabaseTransaction) -> Result
     responses(
         (status = NO_CONTENT, description = "Success."),
         (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
         (status = NOT_FOUND, description = "Project or Frontier not founded.", body = ErrorResponse),
         (status = PRECONDITION_FAILED, description = "Frontier has related records.", body = ErrorResponse),
         (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse),
@@ -967,7 +1560,9 @@ pub async fn remove(
     conditions = conditions.add(functions::Column::Function.eq(function));
 
     let ctx = context.unwrap();
-    let db = state.connection(ctx.tenant()).await?;
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Editor, &ctx, &db).await?;
+
     let data = match Functions::find()
         .inner_join(frontiers::Entity)
         .filter(conditions)
@@ -977,6 +1572,7 @@ pub async fn remove(
         Some(v) => v,
         None => return Err(Error::NotFound),
     };
+    let before = json!(translate(data.clone(), &db).await?);
 
     match data.delete(&db).await {
         Ok(v) => {
@@ -988,6 +1584,17 @@ pub async fn remove(
             return Err(Error::FunctionConstraints);
         }
     };
+    audits::register(
+        project,
+        AuditEntity::Function,
+        function,
+        AuditAction::Delete,
+        Some(before),
+        None,
+        &ctx,
+        &db,
+    )
+    .await?;
     match db.commit().await {
         Ok(it) => it,
         Err(_) => return Err(Error::DatabaseTransaction),
@@ -0,0 +1,153 @@
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use log::{debug, trace};
+use sea_orm::{
+    sea_query::OnConflict, ColumnTrait, DatabaseTransaction, EntityTrait, Iterable, QueryFilter,
+    Set,
+};
+use serde::Deserialize;
+use serde_json::json;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    configuration::Configuration,
+    ctx::Context,
+    error::{Error, ErrorResponse},
+    model::{
+        impacts::{self, ActiveModel, Model},
+        page::Page,
+        prelude::*,
+        sea_orm_active_enums::ImpactType,
+    },
+    state::AppState,
+};
+
+/// Search for the Impact's Factors (SISP) of the Tenant.
+#[utoipa::path(
+    tag = "Impacts",
+    get,
+    path = "/api/impacts",
+    responses(
+        (status = OK, description = "Success", body = Page<impacts::Model>),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn list(
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    debug!("List all Impact's Factors.");
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+
+    let items = load(ctx.tenant(), state.configuration(), &db).await?;
+    let mut page: Page<Model> = Page::new();
+    page.pages = 1;
+    page.index = 1;
+    page.size = items.len() as u64;
+    page.records = items.len() as u64;
+    page.items = items;
+
+    trace!("::: {:?}", json!(page));
+    Ok(Json(page))
+}
+
+/// Impact's properties.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ImpactParam {
+    /// Impact`s Factor.
+    pub impact: ImpactType,
+    /// Percent of the function points for the Impact`s Factor.
+    pub value: i32,
+}
+
+/// Update a Impact's Factor (SISP) of the Tenant.
+#[utoipa::path(
+    tag = "Impacts",
+    put,
+    path = "/api/impacts",
+    responses(
+        (status = OK, description = "Success", body = impacts::Model),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = NOT_ACCEPTABLE, description = "Impact's value invalid.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn update(
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+    Json(params): Json<ImpactParam>,
+) -> Result<impl IntoResponse, Error> {
+    debug!("Update a Impact's Factor (params: {:?})", params);
+
+    if params.value < 0 || params.value > 100 {
+        return Err(Error::ImpactInvalid);
+    }
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+
+    let data = ActiveModel {
+        tenant: Set(*ctx.tenant()),
+        impact: Set(params.impact),
+        value: Set(params.value),
+    };
+    let data: Model = Impacts::insert(data)
+        .on_conflict(
+            OnConflict::columns([impacts::Column::Tenant, impacts::Column::Impact])
+                .update_column(impacts::Column::Value)
+                .to_owned(),
+        )
+        .exec_with_returning(&db)
+        .await?;
+    match db.commit().await {
+        Ok(it) => it,
+        Err(_) => return Err(Error::DatabaseTransaction),
+    };
+
+    trace!("::: {:?}", json!(data));
+    Ok((StatusCode::OK, Json(data)))
+}
+
+/// Impact's Factors of the Tenant, using the configured value when the Tenant has not changed it.
+pub(crate) async fn load(
+    tenant: &Uuid,
+    config: &Configuration,
+    db: &DatabaseTransaction,
+) -> Result<Vec<Model>, Error> {
+    let stored = Impacts::find()
+        .filter(impacts::Column::Tenant.eq(*tenant))
+        .all(db)
+        .await?;
+
+    let result = ImpactType::iter()
+        .map(|impact| match stored.iter().find(|i| i.impact == impact) {
+            Some(v) => v.clone(),
+            None => Model {
+                tenant: *tenant,
+                value: default(&impact, config),
+                impact,
+            },
+        })
+        .collect();
+
+    Ok(result)
+}
+
+fn default(impact: &ImpactType, config: &Configuration) -> i32 {
+    match impact {
+        ImpactType::Inclusion => config.impacts.inclusion,
+        ImpactType::Alteration => config.impacts.alteration,
+        ImpactType::Exclusion => config.impacts.exclusion,
+        ImpactType::Testing => config.impacts.testing,
+        ImpactType::Corrective => config.impacts.corrective,
+    }
+}
@@ -0,0 +1,576 @@
+use std::{collections::HashMap, sync::Arc};
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use csv::{ReaderBuilder, StringRecord, Trim};
+use log::{debug, trace, warn};
+use sea_orm::{ColumnTrait, Condition, EntityTrait, QueryFilter};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    ctx::Context,
+    drafts::DataFunction,
+    error::{Error, ErrorResponse},
+    handlers::{
+        audits, drafts,
+        functions::{
+            insert_function_data, insert_function_transaction, DERReference, Function,
+            FunctionAIEParam, FunctionALIParam, FunctionCEParam, FunctionEEParam, FunctionParam,
+            FunctionSEParam, ALR, DER, DET, RLR,
+        },
+        members,
+    },
+    model::{
+        frontiers, functions,
+        prelude::*,
+        sea_orm_active_enums::{AuditAction, AuditEntity, FunctionType, MemberType},
+    },
+    state::AppState,
+    tenants::{self, Resource},
+};
+
+/// Columns of a row of the CSV, one row for each Function, RLR, DER or DET.
+#[derive(Debug, Deserialize)]
+struct CSVRow {
+    #[serde(default)]
+    r#type: String,
+    #[serde(default)]
+    name: String,
+    description: Option<String>,
+    rlr: Option<String>,
+    der: Option<String>,
+    referenced_alr: Option<String>,
+}
+
+/// Type of the error found on a row of the CSV.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub enum CSVErrorType {
+    /// Type not informed or not a Function Type.
+    TypeInvalid,
+    /// Name of the Function not informed.
+    NameRequired,
+    /// Function already on the Frontier, or DER/DET repeated on the Function.
+    NameDuplicated,
+    /// Type different from the previous rows of the Function, or a Transaction Function used as
+    /// a Data Function.
+    TypeMismatch,
+    /// Referenced ALR not found on the CSV or on the Frontier.
+    ALRUnknown,
+    /// Referenced DER not found on the RLR of the ALR.
+    DERUnknown,
+}
+
+/// Error found on a row of the CSV.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CSVError {
+    /// Line of the row in the CSV (the header is the line 1).
+    pub row: u64,
+    /// Type of the error.
+    pub error: CSVErrorType,
+    /// Name of the Function of the row.
+    pub name: String,
+    /// Detail of the error.
+    pub message: String,
+}
+
+/// DET of a Transaction Function, with the names of the referenced ALR and RLR.
+struct CSVElement {
+    row: u64,
+    name: String,
+    description: Option<String>,
+    alr: Option<String>,
+    rlr: Option<String>,
+}
+
+/// Function grouped from the rows of the CSV.
+struct CSVFunction {
+    row: u64,
+    r#type: FunctionType,
+    name: String,
+    description: Option<String>,
+    rlrs: Vec<RLR>,
+    alrs: Vec<(u64, String)>,
+    dets: Vec<CSVElement>,
+}
+
+impl CSVFunction {
+    fn is_data(&self) -> bool {
+        matches!(self.r#type, FunctionType::ALI | FunctionType::AIE)
+    }
+}
+
+fn error(row: u64, error: CSVErrorType, name: &str, message: String) -> CSVError {
+    CSVError {
+        row,
+        error,
+        name: name.to_owned(),
+        message,
+    }
+}
+
+fn function_type(value: &str) -> Option<FunctionType> {
+    match value.to_uppercase().as_str() {
+        "ALI" => Some(FunctionType::ALI),
+        "AIE" => Some(FunctionType::AIE),
+        "EE" => Some(FunctionType::EE),
+        "CE" => Some(FunctionType::CE),
+        "SE" => Some(FunctionType::SE),
+        _ => None,
+    }
+}
+
+/// Groups the rows of the CSV by the name of the Function.
+///
+/// The description is of the most specific element of the row: the DER (or DET), the RLR or
+/// the Function. A DER without RLR belongs to an RLR with the name of the Function.
+fn group(content: &str, errors: &mut Vec<CSVError>) -> Result<Vec<CSVFunction>, Error> {
+    let mut reader = ReaderBuilder::new()
+        .trim(Trim::All)
+        .flexible(true)
+        .from_reader(content.as_bytes());
+    let headers: StringRecord = match reader.headers() {
+        Ok(v) => v.iter().map(|h| h.to_lowercase()).collect(),
+        Err(e) => {
+            warn!("Invalid CSV: {}", e);
+            return Err(Error::CSVInvalid);
+        }
+    };
+    if !headers.iter().any(|h| h == "type") || !headers.iter().any(|h| h == "name") {
+        return Err(Error::CSVInvalid);
+    }
+
+    let mut result = Vec::<CSVFunction>::new();
+    for record in reader.records() {
+        let record = match record {
+            Ok(v) => v,
+            Err(e) => {
+                warn!("Invalid CSV: {}", e);
+                return Err(Error::CSVInvalid);
+            }
+        };
+        let line = record.position().map_or(0, |p| p.line());
+        let row: CSVRow = match record.deserialize(Some(&headers)) {
+            Ok(v) => v,
+            Err(e) => {
+                warn!("Invalid CSV: {}", e);
+                return Err(Error::CSVInvalid);
+            }
+        };
+        if row.r#type.is_empty() && row.name.is_empty() {
+            continue;
+        }
+
+        if row.name.is_empty() {
+            errors.push(error(
+                line,
+                CSVErrorType::NameRequired,
+                &row.name,
+                "The name of the Function is required.".to_owned(),
+            ));
+            continue;
+        }
+        let Some(r#type) = function_type(&row.r#type) else {
+            errors.push(error(
+                line,
+                CSVErrorType::TypeInvalid,
+                &row.name,
+                format!("The type `{}` is not a Function Type.", row.r#type),
+            ));
+            continue;
+        };
+
+        let function = match result.iter_mut().position(|f| f.name == row.name) {
+            Some(v) => &mut result[v],
+            None => {
+                result.push(CSVFunction {
+                    row: line,
+                    r#type: r#type.clone(),
+                    name: row.name.clone(),
+                    description: None,
+                    rlrs: Vec::new(),
+                    alrs: Vec::new(),
+                    dets: Vec::new(),
+                });
+                result.last_mut().unwrap()
+            }
+        };
+        if function.r#type != r#type {
+            errors.push(error(
+                line,
+                CSVErrorType::TypeMismatch,
+                &row.name,
+                format!(
+                    "The type {:?} was used on the {:?} Function of the line {}.",
+                    r#type, function.r#type, function.row
+                ),
+            ));
+            continue;
+        }
+
+        if function.is_data() {
+            if row.referenced_alr.is_some() {
+                errors.push(error(
+                    line,
+                    CSVErrorType::TypeMismatch,
+                    &row.name,
+                    "Only the Transaction Functions reference ALRs.".to_owned(),
+                ));
+                continue;
+            }
+            data(function, line, row, errors);
+        } else {
+            transaction(function, line, row, errors);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Adds the RLR or the DER of the row to the Data Function.
+fn data(function: &mut CSVFunction, line: u64, row: CSVRow, errors: &mut Vec<CSVError>) {
+    if row.rlr.is_none() && row.der.is_none() {
+        function.description = row.description.or(function.description.take());
+        return;
+    }
+
+    let name = row.rlr.unwrap_or(function.name.clone());
+    let rlr = match function.rlrs.iter().position(|r| r.name == name) {
+        Some(v) => &mut function.rlrs[v],
+        None => {
+            function.rlrs.push(RLR {
+                name,
+                description: None,
+                ders: Vec::new(),
+            });
+            function.rlrs.last_mut().unwrap()
+        }
+    };
+    let Some(der) = row.der else {
+        rlr.description = row.description.or(rlr.description.take());
+        return;
+    };
+    if rlr.ders.iter().any(|d| d.name == der) {
+        errors.push(error(
+            line,
+            CSVErrorType::NameDuplicated,
+            &row.name,
+            format!("The DER `{}` is repeated on the RLR `{}`.", der, rlr.name),
+        ));
+        return;
+    }
+    rlr.ders.push(DER {
+        name: der,
+        description: row.description,
+    });
+}
+
+/// Adds the ALR or the DET of the row to the Transaction Function.
+fn transaction(function: &mut CSVFunction, line: u64, row: CSVRow, errors: &mut Vec<CSVError>) {
+    if let Some(alr) = &row.referenced_alr {
+        if !function.alrs.iter().any(|(_, a)| a == alr) {
+            function.alrs.push((line, alr.clone()));
+        }
+    }
+    if row.rlr.is_some() && row.referenced_alr.is_none() {
+        errors.push(error(
+            line,
+            CSVErrorType::TypeMismatch,
+            &row.name,
+            "The RLR of a Transaction Function is of the referenced ALR.".to_owned(),
+        ));
+        return;
+    }
+
+    let Some(der) = row.der else {
+        if row.referenced_alr.is_none() {
+            function.description = row.description.or(function.description.take());
+        }
+        return;
+    };
+    if function.dets.iter().any(|d| d.name == der) {
+        errors.push(error(
+            line,
+            CSVErrorType::NameDuplicated,
+            &row.name,
+            format!("The DET `{}` is repeated on the Function.", der),
+        ));
+        return;
+    }
+    function.dets.push(CSVElement {
+        row: line,
+        name: der,
+        description: row.description,
+        alr: row.referenced_alr,
+        rlr: row.rlr,
+    });
+}
+
+/// Checks the names of the Functions and the references against the Frontier.
+///
+/// A DET referencing an ALR is traced to the DER with its name, searched on all the RLRs of the
+/// ALR when the RLR is not informed.
+fn resolve(
+    functions: &mut [CSVFunction],
+    datas: &[DataFunction],
+    names: &[String],
+    errors: &mut Vec<CSVError>,
+) {
+    // DERs of the Data Functions, from the CSV and from the Frontier.
+    let mut ders = HashMap::<String, Vec<(String, String)>>::new();
+    for function in functions.iter().filter(|f| f.is_data()) {
+        let items = function
+            .rlrs
+            .iter()
+            .flat_map(|r| r.ders.iter().map(|d| (r.name.clone(), d.name.clone())))
+            .collect();
+        ders.insert(function.name.clone(), items);
+    }
+    for data in datas {
+        ders.entry(data.name.clone())
+            .or_insert_with(|| data.ders.clone());
+    }
+    let transactions: Vec<String> = functions
+        .iter()
+        .filter(|f| !f.is_data())
+        .map(|f| f.name.clone())
+        .collect();
+
+    for function in functions.iter_mut() {
+        if names.contains(&function.name) {
+            errors.push(error(
+                function.row,
+                CSVErrorType::NameDuplicated,
+                &function.name,
+                "A Function with this name already exists on the Frontier.".to_owned(),
+            ));
+        }
+
+        for (row, alr) in function.alrs.iter() {
+            if ders.contains_key(alr) {
+                continue;
+            }
+            let (kind, message) = if names.contains(alr) || transactions.contains(alr) {
+                (
+                    CSVErrorType::TypeMismatch,
+                    format!("The referenced ALR `{}` is not a Data Function.", alr),
+                )
+            } else {
+                (
+                    CSVErrorType::ALRUnknown,
+                    format!("The referenced ALR `{}` was not found.", alr),
+                )
+            };
+            errors.push(error(*row, kind, &function.name, message));
+        }
+
+        for det in function.dets.iter_mut() {
+            let Some(items) = det.alr.as_ref().and_then(|alr| ders.get(alr)) else {
+                continue;
+            };
+            let found = items
+                .iter()
+                .find(|(rlr, der)| *der == det.name && det.rlr.as_ref().is_none_or(|r| r == rlr));
+            match found {
+                Some((rlr, _)) => det.rlr = Some(rlr.clone()),
+                None if det.rlr.is_some() => errors.push(error(
+                    det.row,
+                    CSVErrorType::DERUnknown,
+                    &function.name,
+                    format!(
+                        "The DER `{}` was not found on the RLR `{}` of the ALR `{}`.",
+                        det.name,
+                        det.rlr.as_deref().unwrap_or_default(),
+                        det.alr.as_deref().unwrap_or_default()
+                    ),
+                )),
+                None => (),
+            }
+        }
+    }
+}
+
+/// Params of a Function grouped from the CSV, with the ALRs identified by name.
+fn param(function: CSVFunction, ids: &HashMap<String, Uuid>) -> FunctionParam {
+    let alrs: Vec<ALR> = function
+        .alrs
+        .iter()
+        .filter_map(|(_, alr)| ids.get(alr).map(|id| ALR { id: *id }))
+        .collect();
+    let dets: Vec<DET> = function
+        .dets
+        .into_iter()
+        .map(|det| DET {
+            reference: match (det.alr.and_then(|a| ids.get(&a)), det.rlr) {
+                (Some(alr), Some(rlr)) => Some(DERReference {
+                    alr: *alr,
+                    rlr,
+                    der: det.name.clone(),
+                }),
+                _ => None,
+            },
+            name: det.name,
+            description: det.description,
+        })
+        .collect();
+
+    match function.r#type {
+        FunctionType::ALI => FunctionParam::ALI(FunctionALIParam {
+            name: function.name,
+            description: function.description,
+            change: None,
+            impact: None,
+            rlrs: function.rlrs,
+        }),
+        FunctionType::AIE => FunctionParam::AIE(FunctionAIEParam {
+            name: function.name,
+            description: function.description,
+            change: None,
+            impact: None,
+            rlrs: function.rlrs,
+        }),
+        FunctionType::EE => FunctionParam::EE(FunctionEEParam {
+            name: function.name,
+            description: function.description,
+            change: None,
+            impact: None,
+            alrs,
+            dets,
+        }),
+        FunctionType::CE => FunctionParam::CE(FunctionCEParam {
+            name: function.name,
+            description: function.description,
+            change: None,
+            impact: None,
+            alrs,
+            dets,
+        }),
+        FunctionType::SE => FunctionParam::SE(FunctionSEParam {
+            name: function.name,
+            description: function.description,
+            change: None,
+            impact: None,
+            alrs,
+            dets,
+        }),
+    }
+}
+
+/// Import the Functions of a Frontier from a CSV, with one row for each Function or DER.
+///
+/// The columns are `type`, `name`, `description`, `rlr`, `der` and `referenced_alr`, in any
+/// order. The rows of a Data Function add its RLRs and DERs, and the rows of a Transaction
+/// Function add its ALRs (by name, from the CSV or the Frontier) and DETs (`der`), traced to the
+/// DER with the same name on the `referenced_alr`. All the Functions are created, or none when
+/// a row has errors.
+#[utoipa::path(
+    tag = "Functions",
+    post,
+    path = "/api/projects/{project}/frontiers/{frontier}/functions/import/csv",
+    request_body(content = String, content_type = "text/csv", description = "CSV with a header row."),
+    responses(
+        (status = CREATED, description = "Functions created.", body = Vec<Function>),
+        (status = BAD_REQUEST, description = "Content is not a CSV with the `type` and `name` columns.", body = ErrorResponse),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = NOT_FOUND, description = "Project or Frontier not founded.", body = ErrorResponse),
+        (status = UNPROCESSABLE_ENTITY, description = "Rows of the CSV with errors.", body = Vec<CSVError>),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(
+        ("project" = Uuid, Path, description = "Project Unique ID."),
+        ("frontier" = Uuid, Path, description = "Frontier Unique ID."),
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn csv(
+    Path((project, frontier)): Path<(Uuid, Uuid)>,
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+    content: String,
+) -> Result<impl IntoResponse, Error> {
+    debug!(
+        "Import Functions from a CSV (project: {} - frontier: {} - {} bytes)",
+        project,
+        frontier,
+        content.len()
+    );
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Editor, &ctx, &db).await?;
+
+    // Frontier must belong to the Project.
+    if Frontiers::find()
+        .filter(
+            Condition::all()
+                .add(frontiers::Column::Project.eq(project))
+                .add(frontiers::Column::Frontier.eq(frontier)),
+        )
+        .one(&db)
+        .await?
+        .is_none()
+    {
+        return Err(Error::NotFound);
+    }
+
+    let datas = drafts::datas(frontier, &db).await?;
+    let names: Vec<String> = Functions::find()
+        .filter(Condition::all().add(functions::Column::Frontier.eq(frontier)))
+        .all(&db)
+        .await?
+        .into_iter()
+        .map(|f| f.name)
+        .collect();
+
+    let mut errors = Vec::<CSVError>::new();
+    let mut items = group(&content, &mut errors)?;
+    resolve(&mut items, &datas, &names, &mut errors);
+    if !errors.is_empty() {
+        errors.sort_by_key(|e| e.row);
+        trace!("::: {:?}", json!(errors));
+        return Ok((StatusCode::UNPROCESSABLE_ENTITY, Json(errors)).into_response());
+    }
+
+    // Data Functions first, they are referenced by the Transaction Functions.
+    items.sort_by_key(|f| !f.is_data());
+    let mut ids: HashMap<String, Uuid> = datas.iter().map(|d| (d.name.clone(), d.id)).collect();
+    let mut result = Vec::<Function>::new();
+    for item in items {
+        tenants::quota(Resource::Functions, state.configuration(), &db).await?;
+        let (id, function) = if item.is_data() {
+            let name = item.name.clone();
+            let created = insert_function_data(param(item, &ids), frontier, &db, &ctx).await?;
+            ids.insert(name, created.0);
+            created
+        } else {
+            insert_function_transaction(param(item, &ids), frontier, &db, &ctx).await?
+        };
+        audits::register(
+            project,
+            AuditEntity::Function,
+            id,
+            AuditAction::Create,
+            None,
+            Some(json!(function)),
+            &ctx,
+            &db,
+        )
+        .await?;
+        result.push(function);
+    }
+
+    match db.commit().await {
+        Ok(it) => it,
+        Err(_) => return Err(Error::DatabaseTransaction),
+    };
+
+    trace!("::: {:?}", json!(result));
+    Ok((StatusCode::CREATED, Json(result)).into_response())
+}
@@ -0,0 +1,253 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use chrono::{DateTime, TimeDelta, Utc};
+use log::{debug, trace, warn};
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, ModelTrait, QueryFilter, Set};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    ctx::{Context, Role},
+    error::{Error, ErrorResponse},
+    model::{
+        keys::{self, ActiveModel, Model},
+        page::Page,
+        prelude::*,
+        sea_orm_active_enums::KeyScope,
+    },
+    state::AppState,
+};
+
+/// Prefix of the secrets of the API keys, to tell them apart from the JWT tokens.
+pub(crate) const PREFIX: &str = "fpa_";
+
+/// Days until the expiration of the API keys created without the expiration time.
+const EXPIRATION: i64 = 90;
+
+/// Search for the API keys of the User.
+#[utoipa::path(
+    tag = "Keys",
+    get,
+    path = "/api/keys",
+    responses(
+        (status = OK, description = "Success.", body = Page<keys::Model>),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn list(
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    debug!("List all API keys of the User");
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+
+    let items = Keys::find()
+        .filter(keys::Column::User.eq(*ctx.id()))
+        .all(&db)
+        .await?;
+    let mut page: Page<Model> = Page::new();
+    page.pages = 1;
+    page.index = 1;
+    page.size = items.len() as u64;
+    page.records = items.len() as u64;
+    page.items = items;
+
+    trace!("::: {:?}", json!(page));
+    Ok(Json(page))
+}
+
+/// API key properties.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct KeyParam {
+    /// Name of the API key.
+    pub name: String,
+    /// Access granted by the API key (read only by default).
+    pub scope: Option<KeyScope>,
+    /// Expiration time of the API key, 90 days after the creation if not informed.
+    pub expires: Option<DateTime<Utc>>,
+}
+
+/// New API key, with the secret used on the requests.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct KeyCreated {
+    /// API key registered for the User.
+    pub key: Model,
+    /// Secret of the API key, informed only on the creation (`Authorization: Bearer <secret>`).
+    pub secret: String,
+}
+
+/// Create a new API key for the User.
+///
+/// Write access is granted only to analysts, and API keys can't create other API keys.
+#[utoipa::path(
+    tag = "Keys",
+    post,
+    path = "/api/keys",
+    responses(
+        (status = CREATED, description = "Success.", body = KeyCreated),
+        (status = BAD_REQUEST, description = "Invalid params.", body = ErrorResponse),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for the scope of the key, or authenticated by an API key.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn create(
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+    Json(params): Json<KeyParam>,
+) -> Result<impl IntoResponse, Error> {
+    debug!("Create a new API key ({:?})", params);
+
+    let ctx = context.unwrap();
+    if let Some(key) = ctx.key() {
+        warn!("API key {} used to create another API key.", key);
+        return Err(Error::Forbidden);
+    }
+    let scope = params.scope.unwrap_or(KeyScope::Read);
+    if scope == KeyScope::Write && !ctx.has(Role::Analyst) {
+        return Err(Error::Forbidden);
+    }
+    let expires = params
+        .expires
+        .unwrap_or_else(|| Utc::now() + TimeDelta::days(EXPIRATION));
+    if expires <= Utc::now() {
+        return Err(Error::ParamInvalid);
+    }
+
+    let db = state.connection(&ctx).await?;
+
+    let secret = format!(
+        "{}{}{}",
+        PREFIX,
+        Uuid::new_v4().simple(),
+        Uuid::new_v4().simple()
+    );
+    let key = ActiveModel {
+        key: Set(Uuid::now_v7()),
+        user: Set(*ctx.id()),
+        tenant: Set(*ctx.tenant()),
+        name: Set(params.name),
+        hash: Set(hash(&secret)),
+        scope: Set(scope),
+        roles: Set(ctx.roles().iter().map(|r| r.name().to_owned()).collect()),
+        expires: Set(expires.into()),
+        time: Set(Utc::now().into()),
+    };
+    let key: Model = match key.insert(&db).await {
+        Ok(v) => v,
+        Err(_) => return Err(Error::ApiKeyCreate),
+    };
+
+    match db.commit().await {
+        Ok(it) => it,
+        Err(_) => return Err(Error::DatabaseTransaction),
+    };
+
+    trace!("::: {:?}", json!(key));
+    Ok((StatusCode::CREATED, Json(KeyCreated { key, secret })))
+}
+
+/// Revoke an API key of the User.
+#[utoipa::path(
+    tag = "Keys",
+    delete,
+    path = "/api/keys/{key}",
+    responses(
+        (status = NO_CONTENT, description = "Success."),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = NOT_FOUND, description = "API key not founded.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(
+        ("key" = Uuid, Path, description = "API key Unique ID.")
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn remove(
+    Path(key): Path<Uuid>,
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    debug!("Revoke an API key (key: {})", key);
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+
+    let data = match Keys::find_by_id(key).one(&db).await? {
+        Some(v) if v.user == *ctx.id() => v,
+        _ => return Err(Error::NotFound),
+    };
+
+    match data.delete(&db).await {
+        Ok(v) => {
+            if v.rows_affected != 1 {
+                return Err(Error::MultipleRowsAffected);
+            }
+        }
+        Err(_) => return Err(Error::DatabaseTransaction),
+    };
+    match db.commit().await {
+        Ok(it) => it,
+        Err(_) => return Err(Error::DatabaseTransaction),
+    };
+
+    trace!("::: API key {} revoked.", key);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Context of the User owner of the API key. Unknown or expired keys are not authenticated.
+///
+/// The roles are the ones of the User on the creation of the key, limited by the scope.
+pub(crate) async fn authenticate(secret: &str, state: &AppState) -> Result<Context, Error> {
+    let db = state.system_connection().await?;
+
+    let key = match Keys::find()
+        .filter(keys::Column::Hash.eq(hash(secret)))
+        .one(&db)
+        .await?
+    {
+        Some(v) => v,
+        None => return Err(Error::TokenInvalid),
+    };
+    if key.expires <= Utc::now() {
+        warn!("API key {} expired.", key.key);
+        return Err(Error::TokenInvalid);
+    }
+    let user = match Users::find_by_id(key.user).one(&db).await? {
+        Some(v) => v,
+        None => return Err(Error::TokenInvalid),
+    };
+
+    let limit = match key.scope {
+        KeyScope::Read => Role::Viewer,
+        KeyScope::Write => Role::Analyst,
+    };
+    let roles = key
+        .roles
+        .iter()
+        .filter_map(|r| Role::from_name(r))
+        .map(|r| r.min(limit))
+        .collect();
+    Ok(Context::new(user.user, user.tenant, user.name, user.email, roles).with_key(key.key))
+}
+
+/// SHA-256 of the secret, stored in place of the secret.
+fn hash(secret: &str) -> String {
+    hex::encode(Sha256::digest(secret.as_bytes()))
+}
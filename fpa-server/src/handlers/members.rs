@@ -0,0 +1,325 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use chrono::Utc;
+use log::{debug, trace};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseTransaction, EntityTrait, IntoActiveModel,
+    ModelTrait, QueryFilter, Set,
+};
+use serde::Deserialize;
+use serde_json::json;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    ctx::{Context, Role},
+    error::{Error, ErrorResponse},
+    model::{
+        members::{self, Model},
+        page::Page,
+        prelude::*,
+        projects,
+        sea_orm_active_enums::MemberType,
+    },
+    state::AppState,
+};
+
+/// Search for the Members of a Project.
+#[utoipa::path(
+    tag = "Members",
+    get,
+    path = "/api/projects/{project}/members",
+    responses(
+        (status = OK, description = "Success.", body = Page<members::Model>),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = NOT_FOUND, description = "Project not founded.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(
+        ("project" = Uuid, Path, description = "Project Unique ID.")
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn list(
+    Path(project): Path<Uuid>,
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    debug!("List all Members of the Project (project: {})", project);
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+    require(project, MemberType::Viewer, &ctx, &db).await?;
+
+    let items = Members::find()
+        .filter(members::Column::Project.eq(project))
+        .all(&db)
+        .await?;
+    let mut page: Page<Model> = Page::new();
+    page.pages = 1;
+    page.index = 1;
+    page.size = items.len() as u64;
+    page.records = items.len() as u64;
+    page.items = items;
+
+    trace!("::: {:?}", json!(page));
+    Ok(Json(page))
+}
+
+/// User invited to the Project.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct MemberParam {
+    /// User Unique Identifier, registered on the Tenant.
+    pub user: Uuid,
+    /// Access of the User to the Project (editor or viewer).
+    pub role: MemberType,
+}
+
+/// Invite an existing User to the Project.
+#[utoipa::path(
+    tag = "Members",
+    post,
+    path = "/api/projects/{project}/members",
+    responses(
+        (status = CREATED, description = "Success.", body = members::Model),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "Only the owner invites Users to the Project.", body = ErrorResponse),
+        (status = NOT_FOUND, description = "Project not founded.", body = ErrorResponse),
+        (status = NOT_ACCEPTABLE, description = "The owner is changed only by the transfer.", body = ErrorResponse),
+        (status = CONFLICT, description = "User not registered or already a member.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(
+        ("project" = Uuid, Path, description = "Project Unique ID.")
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn invite(
+    Path(project): Path<Uuid>,
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+    Json(params): Json<MemberParam>,
+) -> Result<impl IntoResponse, Error> {
+    debug!(
+        "Invite a User to the Project (project: {} - params: {:?})",
+        project, params
+    );
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+    require(project, MemberType::Owner, &ctx, &db).await?;
+
+    if params.role == MemberType::Owner {
+        return Err(Error::MemberInvalid);
+    }
+    if Users::find_by_id(params.user).one(&db).await?.is_none() {
+        return Err(Error::MemberUserNotFound);
+    }
+    if Members::find_by_id((project, params.user))
+        .one(&db)
+        .await?
+        .is_some()
+    {
+        return Err(Error::MemberDuplicated);
+    }
+
+    let member = register(project, params.user, params.role, &ctx, &db).await?;
+
+    match db.commit().await {
+        Ok(it) => it,
+        Err(_) => return Err(Error::DatabaseTransaction),
+    };
+
+    trace!("::: {:?}", json!(member));
+    Ok((StatusCode::CREATED, Json(member)))
+}
+
+/// Remove a Member of the Project.
+#[utoipa::path(
+    tag = "Members",
+    delete,
+    path = "/api/projects/{project}/members/{user}",
+    responses(
+        (status = NO_CONTENT, description = "Success."),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "Only the owner removes Members of the Project.", body = ErrorResponse),
+        (status = NOT_FOUND, description = "Project or Member not founded.", body = ErrorResponse),
+        (status = NOT_ACCEPTABLE, description = "The owner is changed only by the transfer.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(
+        ("project" = Uuid, Path, description = "Project Unique ID."),
+        ("user" = Uuid, Path, description = "User Unique ID."),
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn remove(
+    Path((project, user)): Path<(Uuid, Uuid)>,
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    debug!(
+        "Remove a Member of the Project (project: {} - user: {})",
+        project, user
+    );
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+    require(project, MemberType::Owner, &ctx, &db).await?;
+
+    let member = match Members::find_by_id((project, user)).one(&db).await? {
+        Some(v) => v,
+        None => return Err(Error::NotFound),
+    };
+    if member.role == MemberType::Owner {
+        return Err(Error::MemberInvalid);
+    }
+
+    match member.delete(&db).await {
+        Ok(v) => {
+            if v.rows_affected != 1 {
+                return Err(Error::MultipleRowsAffected);
+            }
+        }
+        Err(_) => return Err(Error::DatabaseTransaction),
+    };
+    match db.commit().await {
+        Ok(it) => it,
+        Err(_) => return Err(Error::DatabaseTransaction),
+    };
+
+    trace!("::: Member {} removed from the Project {}.", user, project);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// New owner of the Project.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct OwnerParam {
+    /// User Unique Identifier, registered on the Tenant.
+    pub user: Uuid,
+}
+
+/// Transfer the ownership of the Project to another User.
+///
+/// The previous owner remains as an editor of the Project.
+#[utoipa::path(
+    tag = "Members",
+    put,
+    path = "/api/projects/{project}/owner",
+    responses(
+        (status = OK, description = "Success.", body = projects::Model),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "Only the owner transfers the Project.", body = ErrorResponse),
+        (status = NOT_FOUND, description = "Project not founded.", body = ErrorResponse),
+        (status = CONFLICT, description = "User not registered on the Tenant.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(
+        ("project" = Uuid, Path, description = "Project Unique ID.")
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn transfer(
+    Path(project): Path<Uuid>,
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+    Json(params): Json<OwnerParam>,
+) -> Result<impl IntoResponse, Error> {
+    debug!(
+        "Transfer the ownership of the Project (project: {} - params: {:?})",
+        project, params
+    );
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+    require(project, MemberType::Owner, &ctx, &db).await?;
+
+    let data = match Projects::find_by_id(project).one(&db).await? {
+        Some(v) => v,
+        None => return Err(Error::NotFound),
+    };
+    if Users::find_by_id(params.user).one(&db).await?.is_none() {
+        return Err(Error::MemberUserNotFound);
+    }
+
+    // The previous owner is demoted first, the Project has only one owner.
+    let mut conditions = Condition::all();
+    conditions = conditions.add(members::Column::Project.eq(project));
+    conditions = conditions.add(members::Column::Role.eq(MemberType::Owner));
+    if let Some(owner) = Members::find().filter(conditions).one(&db).await? {
+        let mut owner = owner.into_active_model();
+        owner.role = Set(MemberType::Editor);
+        owner.update(&db).await?;
+    }
+    match Members::find_by_id((project, params.user)).one(&db).await? {
+        Some(member) => {
+            let mut member = member.into_active_model();
+            member.role = Set(MemberType::Owner);
+            member.update(&db).await?;
+        }
+        None => {
+            register(project, params.user, MemberType::Owner, &ctx, &db).await?;
+        }
+    };
+
+    let mut data: projects::ActiveModel = data.into();
+    data.user = Set(params.user);
+    let data = match data.update(&db).await {
+        Ok(v) => v,
+        Err(_) => return Err(Error::ProjectUpdate),
+    };
+
+    match db.commit().await {
+        Ok(it) => it,
+        Err(_) => return Err(Error::DatabaseTransaction),
+    };
+
+    trace!("::: {:?}", json!(data));
+    Ok(Json(data))
+}
+
+/// Checks if the User has the access to the Project. Tenant administrators access all Projects.
+pub(crate) async fn require(
+    project: Uuid,
+    role: MemberType,
+    ctx: &Context,
+    db: &DatabaseTransaction,
+) -> Result<(), Error> {
+    if ctx.has(Role::Admin) {
+        return Ok(());
+    }
+
+    match Members::find_by_id((project, *ctx.id())).one(db).await? {
+        Some(member) if member.role >= role => Ok(()),
+        Some(_) => Err(Error::Forbidden),
+        None => Err(Error::NotFound),
+    }
+}
+
+/// Register the User as a Member of the Project.
+pub(crate) async fn register(
+    project: Uuid,
+    user: Uuid,
+    role: MemberType,
+    ctx: &Context,
+    db: &DatabaseTransaction,
+) -> Result<Model, Error> {
+    let member = members::ActiveModel {
+        project: Set(project),
+        user: Set(user),
+        tenant: Set(*ctx.tenant()),
+        role: Set(role),
+        time: Set(Utc::now().into()),
+    };
+    match member.insert(db).await {
+        Ok(v) => Ok(v),
+        Err(_) => Err(Error::MemberCreate),
+    }
+}
@@ -1,28 +1,51 @@
+pub mod admin;
+pub mod archives;
+pub mod audits;
+pub mod baselines;
+pub mod drafts;
 pub mod empiricals;
+pub mod estimates;
 pub mod factors;
 pub mod frontiers;
 pub mod functions;
+pub mod impacts;
+pub mod imports;
+pub mod keys;
+pub mod members;
 pub mod projects;
+pub mod reports;
+pub mod snapshots;
+pub mod summaries;
+pub mod users;
 
 use std::{sync::Arc, time::Duration};
 
 use axum::{
-    extract::State, http::StatusCode, middleware, response::IntoResponse, routing::get, Router,
+    extract::State,
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post, put},
+    Router,
 };
 use log::trace;
 use sea_orm::{ConnectOptions, Database, DatabaseConnection};
 
 use crate::{
     auth, configuration::Configuration, ctx::Context, error::Error, mapper::response_mapper,
-    state::AppState,
+    state::AppState, tenants,
 };
 
-async fn prepare_connection(config: &Configuration) -> Result<DatabaseConnection, Error> {
+async fn prepare_connection(
+    config: &Configuration,
+    username: &str,
+    password: &str,
+) -> Result<DatabaseConnection, Error> {
     let dburl = format!(
         "{}://{}:{}@{}:{}/{}",
         &config.database.engine,
-        &config.database.username,
-        &config.database.password,
+        username,
+        password,
         &config.database.server,
         &config.database.port,
         &config.database.name,
@@ -45,32 +68,83 @@ async fn prepare_connection(config: &Configuration) -> Result<DatabaseConnection
 }
 
 pub async fn router(config: Configuration) -> Result<Router, Error> {
+    #[cfg(feature = "dev-auth")]
+    let mut config = config;
+    #[cfg(feature = "dev-auth")]
+    let dev = crate::dev_auth::prepare(&mut config);
+    #[cfg(not(feature = "dev-auth"))]
+    if config.dev_auth {
+        log::warn!("Development issuer requires the dev-auth feature, it is disabled.");
+    }
+
     trace!("Preparing database connection...");
-    let connection = prepare_connection(&config).await?;
+    let connection = prepare_connection(
+        &config,
+        &config.database.username,
+        &config.database.password,
+    )
+    .await?;
+    let system = prepare_connection(
+        &config,
+        &config.database.system_username,
+        &config.database.system_password,
+    )
+    .await?;
     trace!("Preparing application state...");
-    let state = Arc::new(AppState::new(config, connection));
+    let state = Arc::new(AppState::new(config, connection, system));
+    state.jwks().clone().spawn();
     trace!("Creating router...");
-    Ok(Router::new().nest(
+    let router = Router::new().nest(
         "/api",
         Router::new()
             .to_owned()
             .route("/projects", get(projects::list).post(projects::create))
+            .route("/projects/import", post(archives::import))
             .route(
                 "/projects/{project}",
                 get(projects::by_id)
                     .delete(projects::remove)
                     .put(projects::update),
             )
+            .route(
+                "/projects/{project}/members",
+                get(members::list).post(members::invite),
+            )
+            .route(
+                "/projects/{project}/members/{user}",
+                delete(members::remove),
+            )
+            .route("/projects/{project}/owner", put(members::transfer))
+            .route("/projects/{project}/audit", get(audits::list))
+            .route("/projects/{project}/export", get(archives::export))
+            .route("/projects/{project}/export.xlsx", get(reports::xlsx))
+            .route("/projects/{project}/report.pdf", get(reports::pdf))
             .route(
                 "/projects/{project}/frontiers",
                 get(frontiers::list).post(frontiers::create),
             )
+            .route(
+                "/projects/{project}/snapshots",
+                get(snapshots::list).post(snapshots::create),
+            )
+            .route(
+                "/projects/{project}/snapshots/{snapshot}",
+                get(snapshots::by_id),
+            )
+            .route(
+                "/projects/{project}/snapshots/{snapshot}/diff/{target}",
+                get(snapshots::diff),
+            )
             .route(
                 "/projects/{project}/frontiers/{frontier}",
                 get(frontiers::by_id)
                     .put(frontiers::update)
                     .delete(frontiers::remove),
             )
+            .route(
+                "/projects/{project}/frontiers/{frontier}/baseline",
+                post(baselines::apply),
+            )
             .route(
                 "/projects/{project}/frontiers/{frontier}/factors",
                 get(factors::list).put(factors::update),
@@ -79,25 +153,73 @@ pub async fn router(config: Configuration) -> Result<Router, Error> {
                 "/projects/{project}/frontiers/{frontier}/empiricals",
                 get(empiricals::list).put(empiricals::update),
             )
+            .route(
+                "/projects/{project}/frontiers/{frontier}/estimate",
+                get(estimates::by_frontier),
+            )
+            .route(
+                "/projects/{project}/frontiers/{frontier}/summary",
+                get(summaries::by_frontier),
+            )
             .route(
                 "/projects/{project}/frontiers/{frontier}/functions",
                 get(functions::list).post(functions::create),
             )
+            .route(
+                "/projects/{project}/frontiers/{frontier}/functions/import/ddl",
+                post(drafts::ddl),
+            )
+            .route(
+                "/projects/{project}/frontiers/{frontier}/functions/import/openapi",
+                post(drafts::openapi),
+            )
+            .route(
+                "/projects/{project}/frontiers/{frontier}/functions/import/drafts",
+                post(drafts::reviewed),
+            )
+            .route(
+                "/projects/{project}/frontiers/{frontier}/functions/import/csv",
+                post(imports::csv),
+            )
             .route(
                 "/projects/{project}/frontiers/{frontier}/functions/{function}",
                 get(functions::by_id)
                     .put(functions::update)
                     .delete(functions::remove),
             )
+            .route("/impacts", get(impacts::list).put(impacts::update))
+            .route("/users", get(users::list))
+            .route("/users/me", get(users::me))
+            .route("/keys", get(keys::list).post(keys::create))
+            .route("/keys/{key}", delete(keys::remove))
+            .route("/admin/tenants", get(admin::list).post(admin::create))
+            .route(
+                "/admin/tenants/{tenant}",
+                get(admin::by_id).put(admin::update),
+            )
+            .route("/admin/tenants/{tenant}/usage", get(admin::usage))
             .route("/health", get(health))
             .layer(middleware::map_response(response_mapper))
             .route_layer(middleware::from_fn_with_state(
                 state.clone(),
                 auth::user_register,
             ))
+            .route_layer(middleware::from_fn(auth::authorize))
+            .route_layer(middleware::from_fn_with_state(
+                state.clone(),
+                tenants::require,
+            ))
             .route_layer(middleware::from_fn_with_state(state.clone(), auth::require))
             .with_state(state),
-    ))
+    );
+
+    #[cfg(feature = "dev-auth")]
+    let router = match dev {
+        Some(dev) => router.merge(crate::dev_auth::router(dev)),
+        None => router,
+    };
+
+    Ok(router)
 }
 
 /// Checks system health.
@@ -114,7 +236,7 @@ pub async fn router(config: Configuration) -> Result<Router, Error> {
 )]
 pub async fn health(context: Option<Context>, state: State<Arc<AppState>>) -> impl IntoResponse {
     trace!("Verifying system health...");
-    let _ = match state.connection(context.unwrap().tenant()).await {
+    let _ = match state.connection(&context.unwrap()).await {
         Ok(_) => return StatusCode::NO_CONTENT,
         Err(_) => return StatusCode::SERVICE_UNAVAILABLE,
     };
@@ -1,14 +1,18 @@
 use std::sync::Arc;
 
 use crate::{
-    ctx::Context,
+    ctx::{Context, Role},
     error::{Error, ErrorResponse},
+    handlers::{audits, members},
     model::{
+        self,
         page::{Page, PageParams},
         prelude::*,
         projects::{self, ActiveModel, Model},
+        sea_orm_active_enums::{AuditAction, AuditEntity, MemberType},
     },
     state::AppState,
+    tenants::{self, Resource},
 };
 use axum::{
     extract::{Path, Query, State},
@@ -19,8 +23,8 @@ use axum::{
 use chrono::Utc;
 use log::{debug, trace};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, ModelTrait, PaginatorTrait, QueryFilter,
-    Set,
+    sea_query, ActiveModelTrait, ColumnTrait, Condition, EntityTrait, ModelTrait, PaginatorTrait,
+    QueryFilter, Set,
 };
 use serde_derive::Deserialize;
 use serde_json::json;
@@ -35,6 +39,7 @@ use uuid::Uuid;
     responses(
         (status = OK, description = "Success.", body = Page<projects::Model>),
         (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
         (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
     ),
     params(PageParams),
@@ -47,13 +52,25 @@ pub async fn list(
 ) -> Result<impl IntoResponse, Error> {
     debug!("List all projects (params: {:?})", params);
 
+    let ctx = context.unwrap();
+
     let mut conditions = Condition::all();
     if let Some(name) = params.name() {
         conditions = conditions.add(projects::Column::Name.contains(&name));
     }
+    if !ctx.has(Role::Admin) {
+        conditions = conditions.add(
+            projects::Column::Project.in_subquery(
+                sea_query::Query::select()
+                    .column(model::members::Column::Project)
+                    .from(Members)
+                    .and_where(model::members::Column::User.eq(*ctx.id()))
+                    .to_owned(),
+            ),
+        );
+    }
 
-    let ctx = context.unwrap();
-    let db = state.connection(ctx.tenant()).await?;
+    let db = state.connection(&ctx).await?;
     let paginator = Projects::find()
         .filter(conditions)
         .paginate(&db, params.size());
@@ -78,6 +95,7 @@ pub async fn list(
     responses(
         (status = OK, description = "Success.", body = projects::Model),
         (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
         (status = NOT_FOUND, description = "Project not founded.", body = ErrorResponse),
         (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
     ),
@@ -94,7 +112,8 @@ pub async fn by_id(
     debug!("Select a specific project (project: {:?})", project);
 
     let ctx = context.unwrap();
-    let db = state.connection(ctx.tenant()).await?;
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Viewer, &ctx, &db).await?;
 
     let data = match Projects::find_by_id(project).one(&db).await? {
         Some(v) => v,
@@ -122,6 +141,7 @@ pub struct ProjectParam {
     responses(
         (status = CREATED, description = "Success.", body = projects::Model, headers(("Location", description = "New project address."))),
         (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
         (status = CONFLICT, description = "The project name must be unique.", body = ErrorResponse),
         (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
     ),
@@ -135,11 +155,16 @@ pub async fn create(
     debug!("Create a new project ({:?})", params);
 
     let ctx = context.unwrap();
-    let db = state.connection(ctx.tenant()).await?;
+    let db = state.connection(&ctx).await?;
     let config = state.configuration();
+    tenants::quota(Resource::Projects, config, &db).await?;
+
+    // The creator is the owner, registered first to access the new Project.
+    let id = Uuid::now_v7();
+    members::register(id, *ctx.id(), MemberType::Owner, &ctx, &db).await?;
 
     let project = projects::ActiveModel {
-        project: Set(Uuid::now_v7()),
+        project: Set(id),
         tenant: Set(ctx.tenant().clone()),
         user: Set(ctx.id().clone()),
         time: Set(Utc::now().into()),
@@ -157,6 +182,17 @@ pub async fn create(
             };
         }
     };
+    audits::register(
+        project.project,
+        AuditEntity::Project,
+        project.project,
+        AuditAction::Create,
+        None,
+        Some(json!(project)),
+        &ctx,
+        &db,
+    )
+    .await?;
 
     match db.commit().await {
         Ok(it) => it,
@@ -193,6 +229,7 @@ pub async fn create(
     responses(
         (status = OK, description = "Success.", body = projects::Model),
         (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
         (status = NOT_FOUND, description = "Project not founded.", body = ErrorResponse),
         (status = CONFLICT, description = "The project name must be unique.", body = ErrorResponse),
         (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
@@ -214,13 +251,15 @@ pub async fn update(
     );
 
     let ctx = context.unwrap();
-    let db = state.connection(ctx.tenant()).await?;
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Editor, &ctx, &db).await?;
 
     let data: Option<Model> = Projects::find_by_id(project).one(&db).await?;
     let data = match data {
         Some(v) => v,
         None => return Err(Error::NotFound),
     };
+    let before = json!(data);
     let mut data: ActiveModel = data.into();
     data.name = Set(params.name);
     data.description = Set(params.description);
@@ -236,6 +275,17 @@ pub async fn update(
             };
         }
     };
+    audits::register(
+        project,
+        AuditEntity::Project,
+        project,
+        AuditAction::Update,
+        Some(before),
+        Some(json!(data)),
+        &ctx,
+        &db,
+    )
+    .await?;
 
     match db.commit().await {
         Ok(it) => it,
@@ -254,6 +304,7 @@ pub async fn update(
     responses(
         (status = NO_CONTENT, description = "Success."),
         (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
         (status = NOT_FOUND, description = "Project not founded.", body = ErrorResponse),
         (status = PRECONDITION_FAILED, description = "Project has related records.", body = ErrorResponse),
         (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse),
@@ -271,7 +322,8 @@ pub async fn remove(
     debug!("Remove a existing project (project: {:?})", project);
 
     let ctx = context.unwrap();
-    let db = state.connection(ctx.tenant()).await?;
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Owner, &ctx, &db).await?;
 
     let data: Option<Model> = Projects::find_by_id(project).one(&db).await?;
     let data = match data {
@@ -279,6 +331,18 @@ pub async fn remove(
         None => return Err(Error::NotFound),
     };
 
+    // Registered before the removal, while the members still have access to the Project.
+    audits::register(
+        project,
+        AuditEntity::Project,
+        project,
+        AuditAction::Delete,
+        Some(json!(data)),
+        None,
+        &ctx,
+        &db,
+    )
+    .await?;
     match data.delete(&db).await {
         Ok(v) => {
             if v.rows_affected != 1 {
@@ -0,0 +1,201 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::{header, HeaderMap},
+    response::IntoResponse,
+};
+use log::{debug, trace, warn};
+use sea_orm::{ColumnTrait, DatabaseTransaction, EntityTrait, ModelTrait, QueryFilter, QueryOrder};
+use uuid::Uuid;
+
+use crate::{
+    configuration::Configuration,
+    ctx::Context,
+    error::{Error, ErrorResponse},
+    handlers::{
+        estimates::{self, EstimateParams},
+        functions::translate,
+        members, summaries,
+    },
+    model::{
+        factors, frontiers, functions, prelude::*, projects::Model,
+        sea_orm_active_enums::MemberType,
+    },
+    reports::{self, Report, ReportFrontier, ReportFunction},
+    state::AppState,
+};
+
+const XLSX: &str = "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet";
+const PDF: &str = "application/pdf";
+
+/// Counting worksheet of a Project, in the IFPUG layout.
+///
+/// A sheet for each Frontier with its Functions (type, RLR/FTR and DER/DET counts, complexity
+/// and points), a sheet with the General System Characteristics and VAF of the Frontiers, and
+/// a sheet with the empiricals and the effort estimated (one person, 8 hours per day).
+#[utoipa::path(
+    tag = "Reports",
+    get,
+    path = "/api/projects/{project}/export.xlsx",
+    responses(
+        (status = OK, description = "Counting worksheet.", body = [u8], content_type = "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = NOT_FOUND, description = "Project not founded.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(
+        ("project" = Uuid, Path, description = "Project Unique ID."),
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn xlsx(
+    Path(project): Path<Uuid>,
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    debug!("Counting worksheet of a Project (project: {}).", project);
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Viewer, &ctx, &db).await?;
+
+    let project = match Projects::find_by_id(project).one(&db).await? {
+        Some(v) => v,
+        None => return Err(Error::NotFound),
+    };
+    let filename = reports::filename(&project, "xlsx");
+
+    let report = load(project, state.configuration(), &db).await?;
+    let content = reports::xlsx::render(&report)?;
+
+    trace!("::: {} ({} bytes)", filename, content.len());
+    Ok((attachment(XLSX, &filename), content))
+}
+
+/// Counting report of a Project, to be printed and signed.
+///
+/// A cover with the logo of the Tenant and the points of each Frontier, a page for each Frontier
+/// with its description and Functions (type, RLR/FTR and DER/DET counts, complexity and points),
+/// the General System Characteristics and VAF, the effort estimated (one person, 8 hours per
+/// day) and the fields for the signatures.
+#[utoipa::path(
+    tag = "Reports",
+    get,
+    path = "/api/projects/{project}/report.pdf",
+    responses(
+        (status = OK, description = "Counting report.", body = [u8], content_type = "application/pdf"),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = NOT_FOUND, description = "Project not founded.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(
+        ("project" = Uuid, Path, description = "Project Unique ID."),
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn pdf(
+    Path(project): Path<Uuid>,
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    debug!("Counting report of a Project (project: {}).", project);
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Viewer, &ctx, &db).await?;
+
+    let project = match Projects::find_by_id(project).one(&db).await? {
+        Some(v) => v,
+        None => return Err(Error::NotFound),
+    };
+    let filename = reports::filename(&project, "pdf");
+
+    let path = state.configuration().reports.logo_of(&project.tenant);
+    let logo = match tokio::fs::read(path).await {
+        Ok(v) => Some(v),
+        Err(e) => {
+            warn!("Logo {} not loaded: {}", path, e);
+            None
+        }
+    };
+
+    let report = load(project, state.configuration(), &db).await?;
+    let content = reports::pdf::render(&report, logo.as_deref())?;
+
+    trace!("::: {} ({} bytes)", filename, content.len());
+    Ok((attachment(PDF, &filename), content))
+}
+
+/// Headers of a file to be downloaded.
+fn attachment(content_type: &'static str, filename: &str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(header::CONTENT_TYPE, content_type.parse().unwrap());
+    headers.insert(
+        header::CONTENT_DISPOSITION,
+        format!("attachment; filename=\"{}\"", filename)
+            .parse()
+            .unwrap(),
+    );
+    headers
+}
+
+/// Counting of the Frontiers of the Project, with the summaries and estimates of the API.
+async fn load(
+    project: Model,
+    config: &Configuration,
+    db: &DatabaseTransaction,
+) -> Result<Report, Error> {
+    trace!("Load Report for Project: {:?}", project.project);
+
+    let frontiers = project
+        .find_related(Frontiers)
+        .order_by_asc(frontiers::Column::Name)
+        .all(db)
+        .await?;
+
+    let mut report = Report {
+        project,
+        frontiers: Vec::new(),
+    };
+    for frontier in frontiers {
+        let mut items = Vec::<ReportFunction>::new();
+        let functions = Functions::find()
+            .filter(functions::Column::Frontier.eq(frontier.frontier))
+            .order_by_asc(functions::Column::Type)
+            .order_by_asc(functions::Column::Name)
+            .all(db)
+            .await?;
+        for function in functions {
+            let function = translate(function, db).await?;
+            let (complexity, points) = summaries::rate(&function, &frontier.method);
+            items.push(ReportFunction {
+                function,
+                complexity,
+                points,
+            });
+        }
+
+        let factors = frontier
+            .find_related(Factors)
+            .order_by_asc(factors::Column::Factor)
+            .all(db)
+            .await?;
+        let summary = summaries::calculate(&frontier, None, config, db).await?;
+        let estimate =
+            estimates::calculate(&frontier, &summary, &EstimateParams::default(), config, db)
+                .await?;
+
+        report.frontiers.push(ReportFrontier {
+            frontier,
+            functions: items,
+            factors,
+            summary,
+            estimate,
+        });
+    }
+
+    Ok(report)
+}
@@ -0,0 +1,517 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode, Uri},
+    response::IntoResponse,
+    Json,
+};
+use chrono::Utc;
+use log::{debug, trace};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseTransaction, EntityTrait, ModelTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, Set,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    ctx::Context,
+    error::{Error, ErrorResponse},
+    handlers::functions::{translate, Function},
+    handlers::members,
+    model::{
+        frontiers, functions,
+        page::{Page, PageParams},
+        prelude::*,
+        sea_orm_active_enums::{
+            CountingMethod, CountingType, EmpiricalType, FactorType, FunctionType, InfluenceType,
+            MemberType,
+        },
+        snapshots::{self, Model},
+    },
+    state::AppState,
+};
+
+/// Adjustment Factor of a Frontier in a Snapshot.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SnapshotFactor {
+    /// Adjustment Factor.
+    pub factor: FactorType,
+    /// Influence value for the factor.
+    pub influence: InfluenceType,
+}
+
+/// Empirical Factor of a Frontier in a Snapshot.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SnapshotEmpirical {
+    /// Empirical`s Factor.
+    pub empirical: EmpiricalType,
+    /// Percent of influence for the Empirical`s Factor.
+    pub value: i32,
+}
+
+/// Frontier of the Project in a Snapshot.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SnapshotFrontier {
+    /// Frontier Unique Identifier.
+    pub frontier: Uuid,
+    /// Frontier's name.
+    pub name: String,
+    /// Description for the Frontier.
+    pub description: Option<String>,
+    /// Type of counting for the Frontier.
+    pub counting: CountingType,
+    /// Method of counting for the Frontier.
+    pub method: CountingMethod,
+    /// Adjustments Factors of the Frontier.
+    pub factors: Vec<SnapshotFactor>,
+    /// Empiricals Factors of the Frontier.
+    pub empiricals: Vec<SnapshotEmpirical>,
+    /// Functions of the Frontier.
+    pub functions: Vec<Function>,
+}
+
+/// Snapshot with the frozen counting of the Project.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SnapshotContent {
+    /// Snapshot Unique Identifier.
+    pub snapshot: Uuid,
+    /// Label of the Snapshot.
+    pub label: String,
+    /// Snapshot registration time.
+    #[schema(value_type = String, format = DateTime)]
+    pub time: sea_orm::prelude::DateTimeWithTimeZone,
+    /// Frontiers of the Project.
+    pub frontiers: Vec<SnapshotFrontier>,
+}
+
+/// Search for the Snapshots of a Project.
+#[utoipa::path(
+    tag = "Snapshots",
+    get,
+    path = "/api/projects/{project}/snapshots",
+    responses(
+        (status = OK, description = "Success", body = Page<snapshots::Model>),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(
+        ("project" = Uuid, Path, description = "Project Unique ID."),
+        PageParams,
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn list(
+    Path(project): Path<Uuid>,
+    Query(params): Query<PageParams>,
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    debug!("List of Snapshots (project: {}).", project);
+
+    let mut conditions = Condition::all();
+    conditions = conditions.add(snapshots::Column::Project.eq(project));
+    if let Some(name) = params.name() {
+        conditions = conditions.add(snapshots::Column::Label.contains(&name));
+    }
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Viewer, &ctx, &db).await?;
+
+    let paginator = Snapshots::find()
+        .filter(conditions)
+        .order_by_desc(snapshots::Column::Time)
+        .paginate(&db, params.size());
+
+    let items = paginator.fetch_page(params.page() - 1).await?;
+    let mut page: Page<Model> = Page::new();
+    page.pages = paginator.num_pages().await?;
+    page.index = params.page();
+    page.size = items.len() as u64;
+    page.records = paginator.num_items().await?;
+    page.items = items;
+
+    trace!("::: {:?}", json!(page));
+    Ok(Json(page))
+}
+
+/// Select a specific Snapshot, with its frozen counting.
+#[utoipa::path(
+    tag = "Snapshots",
+    get,
+    path = "/api/projects/{project}/snapshots/{snapshot}",
+    responses(
+        (status = OK, description = "Success.", body = SnapshotContent),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = NOT_FOUND, description = "Project or Snapshot not founded.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(
+        ("project" = Uuid, Path, description = "Project Unique ID."),
+        ("snapshot" = Uuid, Path, description = "Snapshot Unique ID."),
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn by_id(
+    Path((project, snapshot)): Path<(Uuid, Uuid)>,
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    debug!(
+        "Select a specific Snapshot (project: {} - snapshot: {}).",
+        project, snapshot
+    );
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Viewer, &ctx, &db).await?;
+
+    let data = load(project, snapshot, &db).await?;
+
+    trace!("::: {:?}", json!(data));
+    Ok(Json(data))
+}
+
+/// Snapshot's properties.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SnapshotParam {
+    /// Label of the Snapshot.
+    pub label: String,
+}
+
+/// Create a new Snapshot with the current counting of a Project.
+#[utoipa::path(
+    tag = "Snapshots",
+    post,
+    path = "/api/projects/{project}/snapshots",
+    responses(
+        (status = CREATED, description = "Success.", body = snapshots::Model, headers(("Location", description = "New Snapshot address."))),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = NOT_FOUND, description = "Project not founded.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(
+        ("project" = Uuid, Path, description = "Project Unique ID."),
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn create(
+    Path(project): Path<Uuid>,
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+    Json(params): Json<SnapshotParam>,
+) -> Result<impl IntoResponse, Error> {
+    debug!(
+        "Create a new Snapshot (project: {} - params: {:?}).",
+        project, params
+    );
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Editor, &ctx, &db).await?;
+
+    let config = state.configuration();
+
+    let project = match Projects::find_by_id(project).one(&db).await? {
+        Some(v) => v,
+        None => return Err(Error::NotFound),
+    };
+
+    let mut content = Vec::<SnapshotFrontier>::new();
+    let frontiers = project
+        .find_related(Frontiers)
+        .order_by_asc(frontiers::Column::Name)
+        .all(&db)
+        .await?;
+    for frontier in frontiers {
+        content.push(freeze(frontier, &db).await?);
+    }
+
+    let snapshot = snapshots::ActiveModel {
+        snapshot: Set(Uuid::now_v7()),
+        project: Set(project.project),
+        tenant: Set(*ctx.tenant()),
+        label: Set(params.label),
+        time: Set(Utc::now().into()),
+        content: Set(json!(content)),
+    };
+    let snapshot = match snapshot.insert(&db).await {
+        Ok(v) => v,
+        Err(_) => return Err(Error::SnapshotCreate),
+    };
+
+    match db.commit().await {
+        Ok(it) => it,
+        Err(_) => return Err(Error::DatabaseTransaction),
+    };
+
+    let location = Uri::builder()
+        .scheme(config.scheme.clone())
+        .authority(format!(
+            "{}:{}",
+            config.authority.clone(),
+            config.port.clone()
+        ))
+        .path_and_query(format!(
+            "/api/projects/{}/snapshots/{}",
+            snapshot.project, snapshot.snapshot
+        ))
+        .build()
+        .unwrap()
+        .to_string()
+        .parse()
+        .unwrap();
+    trace!("::: {:?}", location);
+
+    let mut header = HeaderMap::new();
+    header.insert("Location", location);
+
+    trace!("::: {:?}", json!(snapshot));
+    Ok((StatusCode::CREATED, header, Json(snapshot)))
+}
+
+/// Copy of a Frontier with its factors, empiricals and functions.
+async fn freeze(
+    frontier: frontiers::Model,
+    db: &DatabaseTransaction,
+) -> Result<SnapshotFrontier, Error> {
+    trace!("Freeze Frontier: {:?}", frontier.frontier);
+
+    let factors = frontier
+        .find_related(Factors)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|f| SnapshotFactor {
+            factor: f.factor,
+            influence: f.influence,
+        })
+        .collect();
+    let empiricals = frontier
+        .find_related(Empiricals)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|e| SnapshotEmpirical {
+            empirical: e.empirical,
+            value: e.value,
+        })
+        .collect();
+
+    let mut result = Vec::<Function>::new();
+    let functions = Functions::find()
+        .filter(functions::Column::Frontier.eq(frontier.frontier))
+        .order_by_asc(functions::Column::Name)
+        .all(db)
+        .await?;
+    for function in functions {
+        result.push(translate(function, db).await?);
+    }
+
+    Ok(SnapshotFrontier {
+        frontier: frontier.frontier,
+        name: frontier.name,
+        description: frontier.description,
+        counting: frontier.counting,
+        method: frontier.method,
+        factors,
+        empiricals,
+        functions: result,
+    })
+}
+
+async fn load(
+    project: Uuid,
+    snapshot: Uuid,
+    db: &DatabaseTransaction,
+) -> Result<SnapshotContent, Error> {
+    let mut conditions = Condition::all();
+    conditions = conditions.add(snapshots::Column::Project.eq(project));
+    conditions = conditions.add(snapshots::Column::Snapshot.eq(snapshot));
+
+    let data = match Snapshots::find().filter(conditions).one(db).await? {
+        Some(v) => v,
+        None => return Err(Error::NotFound),
+    };
+    let frontiers = match serde_json::from_value(data.content) {
+        Ok(v) => v,
+        Err(_) => return Err(Error::SnapshotContent),
+    };
+
+    Ok(SnapshotContent {
+        snapshot: data.snapshot,
+        label: data.label,
+        time: data.time,
+        frontiers,
+    })
+}
+
+/// Kind of difference of a Function between two Snapshots.
+#[derive(Debug, Serialize, ToSchema)]
+pub enum DiffType {
+    /// Function only in the target Snapshot.
+    Added,
+    /// Function only in the source Snapshot.
+    Removed,
+    /// Function in both Snapshots with different content.
+    Changed,
+}
+
+/// Difference of a Function between two Snapshots.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SnapshotDiffFunction {
+    /// Frontier Unique Identifier.
+    pub frontier: Uuid,
+    /// Function Unique Identifier.
+    pub function: Uuid,
+    /// Type of the Function.
+    pub r#type: FunctionType,
+    /// Name of the Function (on the target Snapshot, when present).
+    pub name: String,
+    /// Kind of difference.
+    pub diff: DiffType,
+    /// Difference of RLRs (ALRs for Transaction Functions).
+    pub rlrs: i32,
+    /// Difference of DERs (DETs for Transaction Functions).
+    pub ders: i32,
+    /// Difference of unadjusted function points.
+    pub points: i32,
+}
+
+/// Differences between two Snapshots of a Project.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SnapshotDiff {
+    /// Source Snapshot Unique Identifier.
+    pub from: Uuid,
+    /// Target Snapshot Unique Identifier.
+    pub to: Uuid,
+    /// Functions added, removed or changed.
+    pub functions: Vec<SnapshotDiffFunction>,
+    /// Difference of unadjusted function points.
+    pub points: i32,
+}
+
+/// Compare two Snapshots of a Project, function by function.
+#[utoipa::path(
+    tag = "Snapshots",
+    get,
+    path = "/api/projects/{project}/snapshots/{snapshot}/diff/{target}",
+    responses(
+        (status = OK, description = "Success.", body = SnapshotDiff),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = NOT_FOUND, description = "Project or Snapshots not founded.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(
+        ("project" = Uuid, Path, description = "Project Unique ID."),
+        ("snapshot" = Uuid, Path, description = "Source Snapshot Unique ID."),
+        ("target" = Uuid, Path, description = "Target Snapshot Unique ID."),
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn diff(
+    Path((project, snapshot, target)): Path<(Uuid, Uuid, Uuid)>,
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    debug!(
+        "Compare Snapshots (project: {} - snapshot: {} - target: {}).",
+        project, snapshot, target
+    );
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Viewer, &ctx, &db).await?;
+
+    let from = flatten(load(project, snapshot, &db).await?);
+    let to = flatten(load(project, target, &db).await?);
+
+    let mut functions = Vec::<SnapshotDiffFunction>::new();
+    for (frontier, function) in to.iter() {
+        let diff = match from.iter().find(|(_, f)| f.id() == function.id()) {
+            Some((_, previous)) if json!(previous) != json!(function) => {
+                compare(*frontier, Some(previous), function, DiffType::Changed)
+            }
+            Some(_) => continue,
+            None => compare(*frontier, None, function, DiffType::Added),
+        };
+        functions.push(diff);
+    }
+    for (frontier, function) in from.iter() {
+        if !to.iter().any(|(_, f)| f.id() == function.id()) {
+            let mut diff = compare(*frontier, None, function, DiffType::Removed);
+            diff.rlrs = -diff.rlrs;
+            diff.ders = -diff.ders;
+            diff.points = -diff.points;
+            functions.push(diff);
+        }
+    }
+
+    let result = SnapshotDiff {
+        from: snapshot,
+        to: target,
+        points: functions.iter().map(|f| f.points).sum(),
+        functions,
+    };
+
+    trace!("::: {:?}", json!(result));
+    Ok(Json(result))
+}
+
+fn flatten(snapshot: SnapshotContent) -> Vec<(Uuid, Function)> {
+    snapshot
+        .frontiers
+        .into_iter()
+        .flat_map(|frontier| {
+            let id = frontier.frontier;
+            frontier.functions.into_iter().map(move |f| (id, f))
+        })
+        .collect()
+}
+
+/// Number of RLRs (or ALRs) and DERs (or DETs) of a Function.
+fn measure(function: &Function) -> (i32, i32) {
+    let (rlrs, ders) = match function {
+        Function::ALI(f) => (f.rlrs.len(), f.rlrs.iter().map(|r| r.ders.len()).sum()),
+        Function::AIE(f) => (f.rlrs.len(), f.rlrs.iter().map(|r| r.ders.len()).sum()),
+        Function::EE(f) => (f.alrs.len(), f.dets.len()),
+        Function::CE(f) => (f.alrs.len(), f.dets.len()),
+        Function::SE(f) => (f.alrs.len(), f.dets.len()),
+    };
+    (rlrs as i32, ders as i32)
+}
+
+fn compare(
+    frontier: Uuid,
+    previous: Option<&Function>,
+    function: &Function,
+    diff: DiffType,
+) -> SnapshotDiffFunction {
+    let (rlrs, ders) = measure(function);
+    let (previous_rlrs, previous_ders, previous_points) = match previous {
+        Some(p) => {
+            let (r, d) = measure(p);
+            (r, d, p.points())
+        }
+        None => (0, 0, 0),
+    };
+
+    SnapshotDiffFunction {
+        frontier,
+        function: function.id(),
+        r#type: function.r#type(),
+        name: function.name(),
+        diff,
+        rlrs: rlrs - previous_rlrs,
+        ders: ders - previous_ders,
+        points: function.points() - previous_points,
+    }
+}
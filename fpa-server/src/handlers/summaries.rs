@@ -0,0 +1,350 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    response::IntoResponse,
+    Json,
+};
+use log::{debug, trace};
+use sea_orm::{
+    ColumnTrait, Condition, DatabaseTransaction, EntityTrait, Iterable, ModelTrait, QueryFilter,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    analysis::{
+        self, adjustment,
+        complexity::{self, Complexity},
+    },
+    configuration::Configuration,
+    ctx::Context,
+    error::{Error, ErrorResponse},
+    handlers::{
+        functions::{translate, Function},
+        impacts, members,
+    },
+    model::{
+        frontiers::{self, Entity as Frontiers},
+        functions, functions_sizes,
+        prelude::*,
+        sea_orm_active_enums::{
+            ChangeType, CountingMethod, CountingType, FunctionType, MemberType,
+        },
+    },
+    state::AppState,
+};
+
+/// Function points counted for a Function Type.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SummaryFunction {
+    /// Type of the Functions.
+    pub r#type: FunctionType,
+    /// Number of Functions of this type.
+    pub count: i32,
+    /// Number of Functions with low complexity (not rated in the indicative counting).
+    pub low: i32,
+    /// Number of Functions with average complexity.
+    pub average: i32,
+    /// Number of Functions with high complexity.
+    pub high: i32,
+    /// Unadjusted function points for this type.
+    pub points: i32,
+}
+
+/// Summary params.
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SummaryParams {
+    /// Value Adjustment Factor before the enhancement (default is the current VAF).
+    #[param(minimum = 0.65, maximum = 1.35)]
+    vafb: Option<f64>,
+}
+
+impl SummaryParams {
+    pub fn vafb(&self) -> Option<f64> {
+        self.vafb
+    }
+}
+
+/// Enhancement Function Points of a Frontier.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SummaryEnhancement {
+    /// Unadjusted function points of the added Functions.
+    pub added: i32,
+    /// Unadjusted function points of the changed Functions.
+    pub changed: i32,
+    /// Unadjusted function points of the deleted Functions.
+    pub deleted: i32,
+    /// Unadjusted function points of the conversion Functions.
+    pub conversion: i32,
+    /// Value Adjustment Factor after the enhancement.
+    pub vafa: f64,
+    /// Value Adjustment Factor before the enhancement.
+    pub vafb: f64,
+    /// Enhancement Function Points.
+    pub efp: f64,
+}
+
+/// Function Points summary of a Frontier.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct Summary {
+    /// Frontier Unique Identifier.
+    pub frontier: Uuid,
+    /// Type of counting for the Frontier.
+    pub counting: CountingType,
+    /// Method of counting that produced the function points of this summary.
+    pub method: CountingMethod,
+    /// Function points by Function Type.
+    pub functions: Vec<SummaryFunction>,
+    /// Unadjusted Function Points.
+    pub ufp: i32,
+    /// Total Degree of Influence of the Adjustments Factors.
+    pub tdi: i32,
+    /// Value Adjustment Factor.
+    pub vaf: f64,
+    /// Adjusted Function Points.
+    pub afp: f64,
+    /// Enhancement Function Points, only for enhancement countings.
+    pub enhancement: Option<SummaryEnhancement>,
+    /// SISP Function Points, weighted by the impact factors of the Functions.
+    pub sisp: f64,
+}
+
+impl Summary {
+    /// Function points of the counting: EFP for enhancements, AFP otherwise.
+    pub fn points(&self) -> f64 {
+        match &self.enhancement {
+            Some(enhancement) => enhancement.efp,
+            None => self.afp,
+        }
+    }
+}
+
+/// Function Points summary for a selected Project and Frontier.
+#[utoipa::path(
+    tag = "Summaries",
+    get,
+    path = "/api/projects/{project}/frontiers/{frontier}/summary",
+    responses(
+        (status = OK, description = "Success", body = Summary),
+        (status = BAD_REQUEST, description = "Invalid params.", body = ErrorResponse),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = NOT_FOUND, description = "Project or Frontier not founded.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(
+        ("project" = Uuid, Path, description = "Project Unique ID."),
+        ("frontier" = Uuid, Path, description = "Frontier Unique ID."),
+        SummaryParams,
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn by_frontier(
+    Path((project, frontier)): Path<(Uuid, Uuid)>,
+    params: Query<SummaryParams>,
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    debug!(
+        "Summary of a Frontier (project: {} - frontier: {} - params: {:?})",
+        project, frontier, params
+    );
+
+    if let Some(vafb) = params.vafb() {
+        if !adjustment::VAF_RANGE.contains(&vafb) {
+            return Err(Error::ParamInvalid);
+        }
+    }
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+    members::require(project, MemberType::Viewer, &ctx, &db).await?;
+
+    let mut conditions = Condition::all();
+    conditions = conditions.add(frontiers::Column::Frontier.eq(frontier));
+    conditions = conditions.add(frontiers::Column::Project.eq(project));
+
+    let frontier = match Frontiers::find().filter(conditions).one(&db).await? {
+        Some(v) => v,
+        None => return Err(Error::NotFound),
+    };
+
+    let summary = calculate(&frontier, params.vafb(), state.configuration(), &db).await?;
+
+    trace!("::: {:?}", json!(summary));
+    Ok(Json(summary))
+}
+
+/// Complexity and function points of a Function, rated by the counting method.
+///
+/// The indicative counting does not rate the complexity of the Functions.
+pub(crate) fn rate(function: &Function, method: &CountingMethod) -> (Option<Complexity>, i32) {
+    rate_type(&function.r#type(), function.complexity(), method)
+}
+
+/// Rates a Function Type with its detailed complexity, by the counting method.
+fn rate_type(
+    r#type: &FunctionType,
+    detailed: Complexity,
+    method: &CountingMethod,
+) -> (Option<Complexity>, i32) {
+    match method {
+        CountingMethod::Detailed => (Some(detailed), complexity::points(r#type, &detailed)),
+        CountingMethod::Estimated => {
+            let rating = complexity::estimated(r#type);
+            (Some(rating), complexity::points(r#type, &rating))
+        }
+        CountingMethod::Indicative => (None, complexity::indicative(r#type)),
+    }
+}
+
+/// Function points of the Frontier (EFP for enhancements, AFP otherwise), from the sizes of its
+/// Functions and the Total Degree of Influence, without loading the Functions.
+///
+/// The same as the points of the summary, with the current VAF before the enhancement.
+pub(crate) fn points(
+    frontier: &frontiers::Model,
+    functions: &[&functions_sizes::Model],
+    tdi: i32,
+) -> f64 {
+    let (mut ufp, mut added, mut changed, mut deleted, mut conversion) = (0, 0, 0, 0, 0);
+    for function in functions {
+        let detailed = match function.r#type {
+            FunctionType::ALI | FunctionType::AIE => {
+                complexity::data(function.rlrs as usize, function.ders as usize)
+            }
+            _ => complexity::transaction(
+                &function.r#type,
+                function.alrs as usize,
+                function.dets as usize,
+            ),
+        };
+        let (_, points) = rate_type(&function.r#type, detailed, &frontier.method);
+        ufp += points;
+        match function.change {
+            Some(ChangeType::Added) => added += points,
+            Some(ChangeType::Changed) => changed += points,
+            Some(ChangeType::Deleted) => deleted += points,
+            Some(ChangeType::Conversion) => conversion += points,
+            None => (),
+        }
+    }
+
+    let vaf = adjustment::vaf(tdi);
+    match frontier.counting {
+        CountingType::Enhancement => analysis::round(adjustment::efp(
+            added, changed, conversion, deleted, vaf, vaf,
+        )),
+        _ => analysis::round(f64::from(ufp) * vaf),
+    }
+}
+
+/// Counts the function points of all Functions in the Frontier, rated by its counting method.
+///
+/// For enhancement countings, `vafb` is the VAF before the enhancement, and the current VAF is used
+/// when it is not informed. Functions without a change are not part of the enhancement.
+/// Functions without an impact factor are counted at 100% on the SISP points.
+pub(crate) async fn calculate(
+    frontier: &frontiers::Model,
+    vafb: Option<f64>,
+    config: &Configuration,
+    db: &DatabaseTransaction,
+) -> Result<Summary, Error> {
+    trace!("Calculating Summary for Frontier: {:?}", frontier.frontier);
+
+    let mut items: Vec<SummaryFunction> = FunctionType::iter()
+        .map(|r#type| SummaryFunction {
+            r#type,
+            count: 0,
+            low: 0,
+            average: 0,
+            high: 0,
+            points: 0,
+        })
+        .collect();
+    let (mut added, mut changed, mut deleted, mut conversion) = (0, 0, 0, 0);
+    let mut sisp = 0.0;
+
+    let impacts = impacts::load(&frontier.tenant, config, db).await?;
+
+    let functions = Functions::find()
+        .filter(functions::Column::Frontier.eq(frontier.frontier))
+        .all(db)
+        .await?;
+    for function in functions {
+        let function = translate(function, db).await?;
+        let r#type = function.r#type();
+        let (rating, points) = rate(&function, &frontier.method);
+
+        let item = items.iter_mut().find(|i| i.r#type == r#type).unwrap();
+        item.count += 1;
+        match rating {
+            Some(Complexity::Low) => item.low += 1,
+            Some(Complexity::Average) => item.average += 1,
+            Some(Complexity::High) => item.high += 1,
+            None => (),
+        }
+        item.points += points;
+        match function.change() {
+            Some(ChangeType::Added) => added += points,
+            Some(ChangeType::Changed) => changed += points,
+            Some(ChangeType::Deleted) => deleted += points,
+            Some(ChangeType::Conversion) => conversion += points,
+            None => (),
+        }
+        let percent = match function.impact() {
+            Some(impact) => impacts
+                .iter()
+                .find(|i| i.impact == impact)
+                .map_or(100, |i| i.value),
+            None => 100,
+        };
+        sisp += adjustment::impact(points, percent);
+    }
+
+    let tdi = frontier
+        .find_related(Factors)
+        .all(db)
+        .await?
+        .iter()
+        .map(|f| adjustment::influence(&f.influence))
+        .sum();
+
+    let ufp = items.iter().map(|i| i.points).sum();
+    let vaf = adjustment::vaf(tdi);
+
+    let enhancement = match frontier.counting {
+        CountingType::Enhancement => {
+            let vafb = vafb.unwrap_or(vaf);
+            Some(SummaryEnhancement {
+                added,
+                changed,
+                deleted,
+                conversion,
+                vafa: vaf,
+                vafb,
+                efp: analysis::round(adjustment::efp(
+                    added, changed, conversion, deleted, vaf, vafb,
+                )),
+            })
+        }
+        _ => None,
+    };
+
+    Ok(Summary {
+        frontier: frontier.frontier,
+        counting: frontier.counting.clone(),
+        method: frontier.method.clone(),
+        functions: items,
+        ufp,
+        tdi,
+        vaf,
+        afp: analysis::round(f64::from(ufp) * vaf),
+        enhancement,
+        sisp: analysis::round(sisp),
+    })
+}
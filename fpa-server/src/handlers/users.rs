@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    response::IntoResponse,
+    Json,
+};
+use log::{debug, trace};
+use sea_orm::{ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder};
+use serde_json::json;
+
+use crate::{
+    ctx::Context,
+    error::{Error, ErrorResponse},
+    model::{
+        page::{Page, PageParams},
+        prelude::*,
+        users::{self, Model},
+    },
+    state::AppState,
+};
+
+/// Search for the Users of the Tenant.
+#[utoipa::path(
+    tag = "Users",
+    get,
+    path = "/api/users",
+    responses(
+        (status = OK, description = "Success.", body = Page<users::Model>),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    params(PageParams),
+    security(("fpa-security" = []))
+)]
+pub async fn list(
+    params: Query<PageParams>,
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    debug!("List all users (params: {:?})", params);
+
+    let mut conditions = Condition::all();
+    if let Some(name) = params.name() {
+        conditions = conditions.add(users::Column::Name.contains(&name));
+    }
+
+    let db = state.connection(&context.unwrap()).await?;
+    let paginator = Users::find()
+        .filter(conditions)
+        .order_by_asc(users::Column::Name)
+        .paginate(&db, params.size());
+
+    let items = paginator.fetch_page(params.page() - 1).await?;
+    let mut page: Page<Model> = Page::new();
+    page.pages = paginator.num_pages().await?;
+    page.index = params.page();
+    page.size = items.len() as u64;
+    page.records = paginator.num_items().await?;
+    page.items = items;
+
+    trace!("::: {:?}", json!(page));
+    Ok(Json(page))
+}
+
+/// Select the authenticated User.
+#[utoipa::path(
+    tag = "Users",
+    get,
+    path = "/api/users/me",
+    responses(
+        (status = OK, description = "Success.", body = users::Model),
+        (status = UNAUTHORIZED, description = "User not authorized.", body = ErrorResponse),
+        (status = FORBIDDEN, description = "User without the role for this operation.", body = ErrorResponse),
+        (status = SERVICE_UNAVAILABLE, description = "FPA Management service unavailable.", body = ErrorResponse)
+    ),
+    security(("fpa-security" = []))
+)]
+pub async fn me(
+    context: Option<Context>,
+    state: State<Arc<AppState>>,
+) -> Result<impl IntoResponse, Error> {
+    debug!("Select the authenticated user");
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+    let data = match Users::find_by_id(*ctx.id()).one(&db).await? {
+        Some(v) => v,
+        None => return Err(Error::NotFound),
+    };
+
+    trace!("::: {:?}", json!(data));
+    Ok(Json(data))
+}
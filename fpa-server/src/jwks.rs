@@ -1,13 +1,21 @@
 use std::{
     collections::HashMap,
-    sync::{Arc, Mutex, OnceLock},
+    sync::Arc,
+    time::{Duration, Instant},
 };
 
+use axum::http::{header, HeaderMap};
 use jsonwebtoken::jwk::Jwk;
-use log::{debug, info};
+use log::{debug, error, info, trace};
 use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
 
-use crate::{configuration::Configuration, error::Error};
+use crate::{configuration::Issuer, error::Error};
+
+/// Maximum time to connect to an issuer.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+/// Maximum time of each request to an issuer, including the response body.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Key {
@@ -37,49 +45,194 @@ struct Keys {
     items: Vec<Key>,
 }
 
-static KEYS: OnceLock<Arc<Mutex<HashMap<String, Key>>>> = OnceLock::new();
+/// OpenID Provider metadata, from the discovery endpoint of the issuer.
+#[derive(Debug, Deserialize)]
+struct Discovery {
+    issuer: String,
+    jwks_uri: String,
+}
 
-async fn request_jwks(tenant: String) -> Result<Keys, Error> {
-    let jwks: Keys = reqwest::Client::new()
-        .get(tenant)
-        .send()
-        .await?
-        .json()
-        .await?;
+#[derive(Debug, Default)]
+struct Cache {
+    /// Keys by issuer and kid, with the issuer that published them.
+    keys: HashMap<(String, String), (Key, Issuer)>,
+    /// Last request of the keys, successful or not.
+    fetched: Option<Instant>,
+    /// Keys are valid until this time (cache-control max-age or the refresh interval).
+    expires: Option<Instant>,
+}
 
-    Ok(jwks)
+/// Signing keys of the issuers, refreshed when they expire or an unknown key is used.
+///
+/// The keys are found by the OpenID Connect discovery of each issuer.
+#[derive(Debug)]
+pub struct Jwks {
+    issuers: Vec<Issuer>,
+    /// Interval to refresh the keys when the issuer does not inform the max-age.
+    refresh: Duration,
+    /// Minimum interval between two requests of the keys.
+    throttle: Duration,
+    /// Client shared by the requests to the issuers.
+    client: reqwest::Client,
+    cache: RwLock<Cache>,
 }
 
-pub async fn prepare(config: &Configuration) -> Result<(), Error> {
-    info!("Preparing JWKS...");
+impl Jwks {
+    pub fn new(issuers: Vec<Issuer>, refresh: Duration, throttle: Duration) -> Self {
+        Self {
+            issuers,
+            refresh,
+            throttle,
+            client: reqwest::Client::builder()
+                .connect_timeout(CONNECT_TIMEOUT)
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .expect("Failed to build the JWKS client"),
+            cache: RwLock::new(Cache::default()),
+        }
+    }
+
+    /// Key of the issuer used to sign the token. Unknown or expired keys are requested again to
+    /// the issuers, tokens of issuers not configured are rejected.
+    pub async fn key(&self, iss: &str, kid: &str) -> Result<(Key, Issuer), Error> {
+        if !self.issuers.iter().any(|i| i.issuer == iss) {
+            return Err(Error::TokenInvalid);
+        }
+        let id = (iss.to_owned(), kid.to_owned());
+        {
+            let cache = self.cache.read().await;
+            let valid = cache.expires.is_some_and(|e| e > Instant::now());
+            if let (true, Some(key)) = (valid, cache.keys.get(&id)) {
+                return Ok(key.clone());
+            }
+        }
+
+        let refreshed = self.refresh().await;
+        let cache = self.cache.read().await;
+        match (cache.keys.get(&id), refreshed) {
+            (Some(key), _) => Ok(key.clone()),
+            (None, Err(e)) => Err(e),
+            (None, Ok(_)) => Err(Error::KeyNotFound),
+        }
+    }
+
+    /// Requests the keys of all issuers, at most once for each throttle interval.
+    ///
+    /// The issuers are requested without holding the cache, the keys are swapped in at the end.
+    pub async fn refresh(&self) -> Result<(), Error> {
+        {
+            let mut cache = self.cache.write().await;
+            if cache.fetched.is_some_and(|f| f.elapsed() < self.throttle) {
+                trace!("JWKS requested recently, using the cached keys.");
+                return Ok(());
+            }
+            cache.fetched = Some(Instant::now());
+        }
 
-    let keys = Arc::new(Mutex::new(HashMap::new()));
+        info!("Refreshing JWKS...");
+        let mut responses = Vec::new();
+        for issuer in &self.issuers {
+            debug!("Requesting JWKS from {}", issuer.issuer);
+            responses.push((issuer, request_jwks(&self.client, issuer).await));
+        }
 
-    for jwks in &config.jwks {
-        debug!("Requesting JWKS from {}", jwks);
-        let ks = request_jwks(jwks.clone()).await?;
-        for key in ks.items {
-            keys.lock().unwrap().insert(key.kid.clone(), key);
+        let mut cache = self.cache.write().await;
+        let mut keys = HashMap::new();
+        let mut max_age = self.refresh;
+        let mut result = Ok(());
+        for (issuer, response) in responses {
+            match response {
+                Ok((items, age)) => {
+                    for key in items.items {
+                        let id = (issuer.issuer.clone(), key.kid.clone());
+                        keys.insert(id, (key, issuer.clone()));
+                    }
+                    if let Some(age) = age {
+                        max_age = max_age.min(age);
+                    }
+                }
+                Err(e) => {
+                    // The other issuers are not affected, the previous keys are kept.
+                    error!("JWKS not available from {}", issuer.issuer);
+                    for (id, item) in cache.keys.iter() {
+                        if id.0 == issuer.issuer {
+                            keys.insert(id.clone(), item.clone());
+                        }
+                    }
+                    result = Err(e);
+                }
+            }
         }
+
+        cache.keys = keys;
+        cache.expires = Some(Instant::now() + max_age);
+        result
     }
 
-    KEYS.set(keys).unwrap();
+    /// Time until the keys expire, limited by the throttle interval.
+    async fn next(&self) -> Duration {
+        let cache = self.cache.read().await;
+        let next = match cache.expires {
+            Some(e) => e.saturating_duration_since(Instant::now()),
+            None => Duration::ZERO,
+        };
+        next.max(self.throttle).max(Duration::from_secs(1))
+    }
 
-    Ok(())
+    /// Refreshes the keys in background, before they expire.
+    pub fn spawn(self: Arc<Self>) {
+        tokio::spawn(async move {
+            loop {
+                let next = self.next().await;
+                trace!("Next JWKS refresh in {:?}", next);
+                tokio::time::sleep(next).await;
+                let _ = self.refresh().await;
+            }
+        });
+    }
 }
 
-pub fn key(kid: String) -> Result<Key, Error> {
-    let keys = KEYS.get().unwrap().lock().unwrap();
+/// Keys of the issuer, from the `jwks_uri` of its discovery document.
+async fn request_jwks(
+    client: &reqwest::Client,
+    issuer: &Issuer,
+) -> Result<(Keys, Option<Duration>), Error> {
+    let discovery: Discovery = client
+        .get(format!(
+            "{}/.well-known/openid-configuration",
+            issuer.issuer.trim_end_matches('/')
+        ))
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    if discovery.issuer != issuer.issuer {
+        error!(
+            "Issuer {} informed by the discovery of {}",
+            discovery.issuer, issuer.issuer
+        );
+        return Err(Error::JWKSNotFound);
+    }
 
-    let key = keys.get(&kid).cloned();
-    let key = match key {
-        Some(k) => k,
-        None => return Err(Error::KeyNotFound),
-    };
+    let response = client
+        .get(discovery.jwks_uri)
+        .send()
+        .await?
+        .error_for_status()?;
+    let age = max_age(response.headers());
+    let jwks: Keys = response.json().await?;
 
-    Ok(key)
+    Ok((jwks, age))
 }
 
-pub fn is_prepared() -> bool {
-    KEYS.get().is_some()
+/// The max-age of the cache-control header.
+fn max_age(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(header::CACHE_CONTROL)?
+        .to_str()
+        .ok()?
+        .split(',')
+        .find_map(|d| d.trim().strip_prefix("max-age=")?.parse::<u64>().ok())
+        .map(Duration::from_secs)
 }
@@ -7,17 +7,23 @@ use utoipa::OpenApi;
 use utoipa_redoc::{Redoc, Servable};
 use utoipa_swagger_ui::SwaggerUi;
 
+mod analysis;
 mod auth;
-mod configuration;
+pub mod configuration;
 mod ctx;
+#[cfg(feature = "dev-auth")]
+mod dev_auth;
 mod docs;
+mod drafts;
 mod error;
 mod handlers;
-mod jwks;
+pub mod jwks;
 mod log;
 mod mapper;
 mod model;
+mod reports;
 mod state;
+mod tenants;
 
 pub async fn start() -> Result<(), Box<dyn Error>> {
     let config = configuration::prepare();
@@ -18,6 +18,8 @@ pub struct Model {
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::ders_transactions::Entity")]
+    DersTransactions,
     #[sea_orm(
         belongs_to = "super::rlrs::Entity",
         from = "(Column::Function, Column::Rlr)",
@@ -36,6 +38,12 @@ pub enum Relation {
     Tenants,
 }
 
+impl Related<super::ders_transactions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::DersTransactions.def()
+    }
+}
+
 impl Related<super::rlrs::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Rlrs.def()
@@ -1,5 +1,6 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.0.1
 
+use super::sea_orm_active_enums::{CountingMethod, CountingType};
 use sea_orm::entity::prelude::*;
 use serde::Serialize;
 use utoipa::ToSchema;
@@ -24,6 +25,10 @@ pub struct Model {
     /// Description for the Frontier.
     #[sea_orm(column_type = "Text", nullable)]
     pub description: Option<String>,
+    /// Type of counting for the Frontier.
+    pub counting: CountingType,
+    /// Method of counting for the Frontier.
+    pub method: CountingMethod,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -1,6 +1,9 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.2
 
-use super::{functions_datas, functions_transactions, sea_orm_active_enums::FunctionType};
+use super::{
+    functions_datas, functions_transactions,
+    sea_orm_active_enums::{ChangeType, FunctionType, ImpactType},
+};
 use sea_orm::entity::prelude::*;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
@@ -14,6 +17,9 @@ pub struct Model {
     pub name: String,
     #[sea_orm(column_type = "Text", nullable)]
     pub description: Option<String>,
+    pub change: Option<ChangeType>,
+    pub impact: Option<ImpactType>,
+    pub enhancement: Option<Uuid>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -59,6 +65,9 @@ impl Into<Model> for functions_datas::Model {
             name: self.name,
             description: self.description,
             r#type: self.r#type,
+            change: self.change,
+            impact: self.impact,
+            enhancement: self.enhancement,
         }
     }
 }
@@ -72,6 +81,9 @@ impl Into<Model> for functions_transactions::Model {
             name: self.name,
             description: self.description,
             r#type: self.r#type,
+            change: self.change,
+            impact: self.impact,
+            enhancement: self.enhancement,
         }
     }
 }
@@ -1,6 +1,6 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.2
 
-use super::sea_orm_active_enums::FunctionType;
+use super::sea_orm_active_enums::{ChangeType, FunctionType, ImpactType};
 use async_trait::async_trait;
 use sea_orm::entity::prelude::*;
 
@@ -15,6 +15,9 @@ pub struct Model {
     pub name: String,
     #[sea_orm(column_type = "Text", nullable)]
     pub description: Option<String>,
+    pub change: Option<ChangeType>,
+    pub impact: Option<ImpactType>,
+    pub enhancement: Option<Uuid>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
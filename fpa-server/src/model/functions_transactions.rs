@@ -1,6 +1,6 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.2
 
-use super::sea_orm_active_enums::FunctionType;
+use super::sea_orm_active_enums::{ChangeType, FunctionType, ImpactType};
 use async_trait::async_trait;
 use sea_orm::entity::prelude::*;
 
@@ -15,12 +15,17 @@ pub struct Model {
     pub name: String,
     #[sea_orm(column_type = "Text", nullable)]
     pub description: Option<String>,
+    pub change: Option<ChangeType>,
+    pub impact: Option<ImpactType>,
+    pub enhancement: Option<Uuid>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
     #[sea_orm(has_many = "super::alrs::Entity")]
     Alrs,
+    #[sea_orm(has_many = "super::ders_transactions::Entity")]
+    DersTransactions,
     #[sea_orm(
         belongs_to = "super::frontiers::Entity",
         from = "Column::Frontier",
@@ -45,6 +50,12 @@ impl Related<super::rlrs::Entity> for Entity {
     }
 }
 
+impl Related<super::ders_transactions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::DersTransactions.def()
+    }
+}
+
 impl Related<super::frontiers::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Frontiers.def()
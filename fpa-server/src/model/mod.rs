@@ -3,17 +3,25 @@
 pub mod prelude;
 
 pub mod alrs;
+pub mod audits;
 pub mod ders;
+pub mod ders_transactions;
 pub mod empiricals;
 pub mod factors;
 pub mod frontiers;
 pub mod functions;
 pub mod functions_datas;
+pub mod functions_sizes;
 pub mod functions_transactions;
+pub mod impacts;
+pub mod keys;
+pub mod members;
 pub mod projects;
 pub mod rlrs;
 pub mod sea_orm_active_enums;
+pub mod snapshots;
 pub mod tenants;
+pub mod tenants_usage;
 pub mod users;
 pub mod versions;
 
@@ -1,11 +1,20 @@
 pub use super::alrs::Entity as Alrs;
+pub use super::audits::Entity as Audits;
 pub use super::ders::Entity as Ders;
+pub use super::ders_transactions::Entity as DersTransactions;
 pub use super::empiricals::Entity as Empiricals;
 pub use super::factors::Entity as Factors;
 pub use super::frontiers::Entity as Frontiers;
 pub use super::functions::Entity as Functions;
 pub use super::functions_datas::Entity as FunctionsDatas;
+pub use super::functions_sizes::Entity as FunctionsSizes;
 pub use super::functions_transactions::Entity as FunctionsTransactions;
+pub use super::impacts::Entity as Impacts;
+pub use super::keys::Entity as Keys;
+pub use super::members::Entity as Members;
 pub use super::projects::Entity as Projects;
 pub use super::rlrs::Entity as Rlrs;
+pub use super::snapshots::Entity as Snapshots;
+pub use super::tenants::Entity as Tenants;
+pub use super::tenants_usage::Entity as TenantsUsage;
 pub use super::users::Entity as Users;
@@ -101,18 +101,43 @@ pub enum InfluenceType {
     Strong,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+/// Tenant status in the system.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    utoipa::ToSchema,
+    Serialize,
+    Deserialize,
+)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "tenant_status")]
 pub enum TenantStatus {
+    /// Full access.
     #[sea_orm(string_value = "ACTIVE")]
     Active,
+    /// No access.
     #[sea_orm(string_value = "DISABLED")]
     Disabled,
+    /// Read only access.
     #[sea_orm(string_value = "SUSPENDED")]
     Suspended,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+/// Tenant access level (quotas) on the system.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    utoipa::ToSchema,
+    Serialize,
+    Deserialize,
+)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "tenant_tier")]
 pub enum TenantTier {
     #[sea_orm(string_value = "BRONZE")]
@@ -153,3 +178,211 @@ pub enum FunctionType {
     #[sea_orm(string_value = "SE")]
     SE,
 }
+
+/// Type of counting for the Frontier.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    utoipa::ToSchema,
+    Serialize,
+    Deserialize,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "counting_type")]
+pub enum CountingType {
+    /// Development Project counting.
+    #[sea_orm(string_value = "DEVELOPMENT")]
+    Development,
+    /// Enhancement Project counting.
+    #[sea_orm(string_value = "ENHANCEMENT")]
+    Enhancement,
+    /// Application (baseline) counting.
+    #[sea_orm(string_value = "APPLICATION")]
+    Application,
+}
+
+/// Change made to the Function in an enhancement counting.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    utoipa::ToSchema,
+    Serialize,
+    Deserialize,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "change_type")]
+pub enum ChangeType {
+    /// Function added by the enhancement.
+    #[sea_orm(string_value = "ADDED")]
+    Added,
+    /// Function changed by the enhancement.
+    #[sea_orm(string_value = "CHANGED")]
+    Changed,
+    /// Function deleted by the enhancement.
+    #[sea_orm(string_value = "DELETED")]
+    Deleted,
+    /// Data conversion Function.
+    #[sea_orm(string_value = "CONVERSION")]
+    Conversion,
+}
+
+/// Impact factor (SISP) applied to the Function.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    utoipa::ToSchema,
+    Serialize,
+    Deserialize,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "impact_type")]
+pub enum ImpactType {
+    /// Inclusion of the Function.
+    #[sea_orm(string_value = "INCLUSION")]
+    Inclusion,
+    /// Alteration of the Function.
+    #[sea_orm(string_value = "ALTERATION")]
+    Alteration,
+    /// Exclusion of the Function.
+    #[sea_orm(string_value = "EXCLUSION")]
+    Exclusion,
+    /// Testing only of the Function.
+    #[sea_orm(string_value = "TESTING")]
+    Testing,
+    /// Corrective maintenance of the Function.
+    #[sea_orm(string_value = "CORRECTIVE")]
+    Corrective,
+}
+
+/// Method of counting for the Frontier.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    utoipa::ToSchema,
+    Serialize,
+    Deserialize,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "counting_method")]
+pub enum CountingMethod {
+    /// IFPUG detailed counting, using the RLRs, DERs and ALRs of the Functions.
+    #[sea_orm(string_value = "DETAILED")]
+    Detailed,
+    /// NESMA indicative counting (35 × ALI + 15 × AIE).
+    #[sea_orm(string_value = "INDICATIVE")]
+    Indicative,
+    /// NESMA estimated counting (Data Functions low, Transactions average).
+    #[sea_orm(string_value = "ESTIMATED")]
+    Estimated,
+}
+
+/// Access of the User to the Project.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    EnumIter,
+    DeriveActiveEnum,
+    utoipa::ToSchema,
+    Serialize,
+    Deserialize,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "member_type")]
+pub enum MemberType {
+    /// Reads the Project.
+    #[sea_orm(string_value = "VIEWER")]
+    Viewer,
+    /// Changes the counting of the Project.
+    #[sea_orm(string_value = "EDITOR")]
+    Editor,
+    /// Manages the Project and its Members.
+    #[sea_orm(string_value = "OWNER")]
+    Owner,
+}
+
+/// Access granted by the API key of the User.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    utoipa::ToSchema,
+    Serialize,
+    Deserialize,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "key_scope")]
+pub enum KeyScope {
+    /// Read only access, as a viewer.
+    #[sea_orm(string_value = "READ")]
+    Read,
+    /// Counts the function points, as an analyst.
+    #[sea_orm(string_value = "WRITE")]
+    Write,
+}
+
+/// Record of the Project changed by the operation.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    utoipa::ToSchema,
+    Serialize,
+    Deserialize,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "audit_entity")]
+pub enum AuditEntity {
+    #[sea_orm(string_value = "PROJECT")]
+    Project,
+    #[sea_orm(string_value = "FRONTIER")]
+    Frontier,
+    /// Adjustment factors of the Frontier.
+    #[sea_orm(string_value = "FACTOR")]
+    Factor,
+    /// Empirical adjustments of the Frontier.
+    #[sea_orm(string_value = "EMPIRICAL")]
+    Empirical,
+    #[sea_orm(string_value = "FUNCTION")]
+    Function,
+}
+
+/// Operation made on the record.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    utoipa::ToSchema,
+    Serialize,
+    Deserialize,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "audit_action")]
+pub enum AuditAction {
+    #[sea_orm(string_value = "CREATE")]
+    Create,
+    #[sea_orm(string_value = "UPDATE")]
+    Update,
+    #[sea_orm(string_value = "DELETE")]
+    Delete,
+}
@@ -3,15 +3,26 @@
 use super::sea_orm_active_enums::TenantStatus;
 use super::sea_orm_active_enums::TenantTier;
 use sea_orm::entity::prelude::*;
+use serde_derive::Serialize;
+use utoipa::ToSchema;
 
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+/// Customer of the system, owner of the Users and Projects.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, ToSchema)]
 #[sea_orm(table_name = "tenants")]
+#[schema(as=Tenant)]
+#[serde(rename = "Tenant")]
 pub struct Model {
+    /// Tenant unique identifier.
     #[sea_orm(primary_key, auto_increment = false)]
     pub tenant: Uuid,
+    /// Tenant name.
     pub name: String,
+    /// Tenant creation date and time.
+    #[schema(value_type = String, format = DateTime)]
     pub time: DateTimeWithTimeZone,
+    /// Tenant status.
     pub status: TenantStatus,
+    /// Tenant access level.
     pub tier: TenantTier,
 }
 
@@ -0,0 +1,83 @@
+//! Counting reports of a Project, rendered as documents for the clients.
+
+pub mod pdf;
+pub mod xlsx;
+
+use crate::{
+    analysis::complexity::Complexity,
+    handlers::{estimates::Estimate, functions::Function, summaries::Summary},
+    model::{
+        factors, frontiers, projects,
+        sea_orm_active_enums::{EmpiricalType, FactorType},
+    },
+};
+
+/// General System Characteristics, in the order of the IFPUG worksheet.
+pub const FACTORS: [(FactorType, &str); 14] = [
+    (FactorType::DataCommunications, "Data Communications"),
+    (
+        FactorType::DistributedDataProcessing,
+        "Distributed Data Processing",
+    ),
+    (FactorType::Performance, "Performance"),
+    (
+        FactorType::HeavilyUsedConfiguration,
+        "Heavily Used Configuration",
+    ),
+    (FactorType::TransactionRate, "Transaction Rate"),
+    (FactorType::OnlineDataEntry, "Online Data Entry"),
+    (FactorType::EndUserEfficiency, "End-User Efficiency"),
+    (FactorType::OnlineUpdate, "Online Update"),
+    (FactorType::ComplexProcessing, "Complex Processing"),
+    (FactorType::Reusability, "Reusability"),
+    (FactorType::InstallationEase, "Installation Ease"),
+    (FactorType::OperationalEase, "Operational Ease"),
+    (FactorType::MultipleSites, "Multiple Sites"),
+    (FactorType::FacilitteChange, "Facilitate Change"),
+];
+
+/// Phases of the estimate, in the order of the project lifecycle.
+pub const PHASES: [(EmpiricalType, &str); 4] = [
+    (EmpiricalType::Planning, "Planning"),
+    (EmpiricalType::Coordination, "Coordination"),
+    (EmpiricalType::Testing, "Testing"),
+    (EmpiricalType::Deployment, "Deployment"),
+];
+
+/// Function of the report, rated by the counting method of its Frontier.
+pub struct ReportFunction {
+    pub function: Function,
+    pub complexity: Option<Complexity>,
+    pub points: i32,
+}
+
+/// Frontier of the report, with its Functions (Data Functions first), factors and estimate.
+pub struct ReportFrontier {
+    pub frontier: frontiers::Model,
+    pub functions: Vec<ReportFunction>,
+    pub factors: Vec<factors::Model>,
+    pub summary: Summary,
+    pub estimate: Estimate,
+}
+
+/// Counting of a Project, with all its Frontiers.
+pub struct Report {
+    pub project: projects::Model,
+    pub frontiers: Vec<ReportFrontier>,
+}
+
+/// Name of the file for a report of the Project, without the characters not allowed in headers.
+pub fn filename(project: &projects::Model, extension: &str) -> String {
+    let name: String = project
+        .name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == ' ' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{}.{}", name.trim(), extension)
+}
@@ -0,0 +1,497 @@
+//! Counting report (PDF) to be signed: a cover page with the Frontiers, a page for each Frontier
+//! with its Functions, the General System Characteristics, the effort estimate and the signatures.
+
+use chrono::Utc;
+use log::warn;
+use printpdf::{
+    image_crate::{self, DynamicImage, RgbImage},
+    BuiltinFont, Image, ImageTransform, IndirectFontRef, Line, Mm, PdfDocument,
+    PdfDocumentReference, PdfLayerReference, Point,
+};
+
+use super::{Report, ReportFrontier, FACTORS, PHASES};
+use crate::{analysis::adjustment, error::Error};
+
+/// Size of the page (A4), in millimeters.
+const WIDTH: f32 = 210.0;
+const HEIGHT: f32 = 297.0;
+
+/// Margin of the page, in millimeters.
+const MARGIN: f32 = 20.0;
+
+/// Width of the content of the page, in millimeters.
+const CONTENT: f32 = WIDTH - 2.0 * MARGIN;
+
+/// Height of a row of the tables, in millimeters.
+const ROW: f32 = 6.0;
+
+/// Size of the text of the tables, in points.
+const TEXT: f32 = 9.0;
+
+/// Width of the logo on the cover, in millimeters.
+const LOGO: f32 = 30.0;
+
+/// Largest side of the logo, in pixels (about 200 dpi on the cover).
+const PIXELS: u32 = 240;
+
+/// Millimeters of a point.
+const POINT: f32 = 0.3528;
+
+/// Average width of a Helvetica character, relative to the size of the font.
+const CHARACTER: f32 = 0.5;
+
+/// Number of characters of the text that fits on the width (in millimeters).
+fn capacity(width: f32, size: f32) -> usize {
+    (width / (size * CHARACTER * POINT)) as usize
+}
+
+/// Text cut to fit on the width, ending with an ellipsis when cut.
+fn fit(text: &str, width: f32, size: f32) -> String {
+    let limit = capacity(width, size);
+    if text.chars().count() <= limit {
+        return text.to_owned();
+    }
+    text.chars()
+        .take(limit.saturating_sub(3))
+        .collect::<String>()
+        + "..."
+}
+
+/// Lines of the text broken on the words to fit on the width.
+fn wrap(text: &str, width: f32, size: f32) -> Vec<String> {
+    let limit = capacity(width, size);
+    let mut result = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            if !line.is_empty() && line.chars().count() + word.chars().count() >= limit {
+                result.push(line);
+                line = String::new();
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+        result.push(line);
+    }
+    result
+}
+
+/// Logo flattened on a white background, the alpha channel is not supported by the PDF writer.
+fn logo(content: &[u8]) -> Option<Image> {
+    let image = match image_crate::load_from_memory(content) {
+        Ok(v) => v.thumbnail(PIXELS, PIXELS).to_rgba8(),
+        Err(e) => {
+            warn!("Invalid logo: {}", e);
+            return None;
+        }
+    };
+    let flatten = RgbImage::from_fn(image.width(), image.height(), |x, y| {
+        let [r, g, b, a] = image.get_pixel(x, y).0;
+        let blend =
+            |c: u8| ((u16::from(c) * u16::from(a) + 255 * (255 - u16::from(a))) / 255) as u8;
+        image_crate::Rgb([blend(r), blend(g), blend(b)])
+    });
+    Some(Image::from_dynamic_image(&DynamicImage::ImageRgb8(flatten)))
+}
+
+/// Pages of the document, written from the top to the bottom.
+struct Writer {
+    document: PdfDocumentReference,
+    layer: PdfLayerReference,
+    regular: IndirectFontRef,
+    bold: IndirectFontRef,
+    title: String,
+    pages: usize,
+    /// Vertical position of the next line, from the bottom of the page.
+    y: f32,
+}
+
+impl Writer {
+    fn new(title: &str) -> Result<Self, Error> {
+        let (document, page, layer) = PdfDocument::new(title, Mm(WIDTH), Mm(HEIGHT), "Report");
+        let regular = document.add_builtin_font(BuiltinFont::Helvetica)?;
+        let bold = document.add_builtin_font(BuiltinFont::HelveticaBold)?;
+        let layer = document.get_page(page).get_layer(layer);
+
+        let writer = Self {
+            document,
+            layer,
+            regular,
+            bold,
+            title: title.to_owned(),
+            pages: 1,
+            y: HEIGHT - MARGIN,
+        };
+        writer.footer();
+        Ok(writer)
+    }
+
+    fn footer(&self) {
+        let text = format!("{} - Page {}", self.title, self.pages);
+        self.layer.use_text(
+            fit(&text, CONTENT, 8.0),
+            8.0,
+            Mm(MARGIN),
+            Mm(MARGIN / 2.0),
+            &self.regular,
+        );
+    }
+
+    fn page(&mut self) {
+        let (page, layer) = self.document.add_page(Mm(WIDTH), Mm(HEIGHT), "Report");
+        self.layer = self.document.get_page(page).get_layer(layer);
+        self.pages += 1;
+        self.y = HEIGHT - MARGIN;
+        self.footer();
+    }
+
+    /// Starts a new page when there is no room for the height (in millimeters).
+    fn reserve(&mut self, height: f32) {
+        if self.y - height < MARGIN {
+            self.page();
+        }
+    }
+
+    fn space(&mut self, height: f32) {
+        self.y -= height;
+    }
+
+    fn font(&self, bold: bool) -> &IndirectFontRef {
+        if bold {
+            &self.bold
+        } else {
+            &self.regular
+        }
+    }
+
+    fn rule(&self, x: f32, width: f32, y: f32) {
+        self.layer.add_line(Line {
+            points: vec![
+                (Point::new(Mm(x), Mm(y)), false),
+                (Point::new(Mm(x + width), Mm(y)), false),
+            ],
+            is_closed: false,
+        });
+    }
+
+    /// Writes a line of text on the left margin.
+    fn write(&mut self, text: &str, size: f32, bold: bool) {
+        let height = size * POINT * 1.5;
+        self.reserve(height);
+        self.y -= height;
+        self.layer.use_text(
+            fit(text, CONTENT, size),
+            size,
+            Mm(MARGIN),
+            Mm(self.y),
+            self.font(bold),
+        );
+    }
+
+    /// Writes the text broken in lines on the width of the page.
+    fn paragraph(&mut self, text: &str, size: f32) {
+        for line in wrap(text, CONTENT, size) {
+            self.write(&line, size, false);
+        }
+    }
+
+    /// Writes a row of a table, with the width of each column (in millimeters).
+    fn row(&mut self, widths: &[f32], values: &[String], bold: bool) {
+        self.y -= ROW;
+        let mut x = MARGIN;
+        for (width, value) in widths.iter().zip(values) {
+            self.layer.use_text(
+                fit(value, width - 2.0, TEXT),
+                TEXT,
+                Mm(x + 1.0),
+                Mm(self.y + 1.8),
+                self.font(bold),
+            );
+            x += width;
+        }
+        self.rule(MARGIN, widths.iter().sum(), self.y);
+    }
+
+    /// Writes a table, repeating the headers on each page. The last row is bold when `total`.
+    fn table(&mut self, widths: &[f32], headers: &[&str], rows: &[Vec<String>], total: bool) {
+        let headers: Vec<String> = headers.iter().map(|h| (*h).to_owned()).collect();
+        self.reserve(2.0 * ROW);
+        self.row(widths, &headers, true);
+        for (index, values) in rows.iter().enumerate() {
+            if self.y - ROW < MARGIN {
+                self.page();
+                self.row(widths, &headers, true);
+            }
+            self.row(widths, values, total && index == rows.len() - 1);
+        }
+        self.space(ROW);
+    }
+}
+
+fn decimal(value: f64) -> String {
+    format!("{:.2}", value)
+}
+
+/// Title, Project and a summary of the Frontiers.
+fn cover(writer: &mut Writer, report: &Report, logo: Option<Image>) {
+    if let Some(image) = logo {
+        let dpi = image.image.width.0 as f32 * 25.4 / LOGO;
+        let height = image.image.height.0 as f32 * 25.4 / dpi;
+        image.add_to_layer(
+            writer.layer.clone(),
+            ImageTransform {
+                translate_x: Some(Mm(MARGIN)),
+                translate_y: Some(Mm(HEIGHT - MARGIN - height)),
+                dpi: Some(dpi),
+                ..Default::default()
+            },
+        );
+        writer.space(height + 10.0);
+    }
+
+    writer.write("Function Point Counting Report", 20.0, true);
+    writer.space(4.0);
+    writer.write(&report.project.name, 16.0, true);
+    if let Some(description) = &report.project.description {
+        writer.paragraph(description, 11.0);
+    }
+    writer.write(
+        &format!("Issued on {}.", Utc::now().format("%Y-%m-%d")),
+        10.0,
+        false,
+    );
+    writer.space(8.0);
+
+    let mut rows: Vec<Vec<String>> = report
+        .frontiers
+        .iter()
+        .map(|item| {
+            vec![
+                item.frontier.name.clone(),
+                format!("{:?}", item.frontier.counting),
+                format!("{:?}", item.frontier.method),
+                decimal(item.summary.points()),
+            ]
+        })
+        .collect();
+    let total: f64 = report.frontiers.iter().map(|f| f.summary.points()).sum();
+    rows.push(vec![
+        "Total".to_owned(),
+        String::new(),
+        String::new(),
+        decimal(total),
+    ]);
+    writer.table(
+        &[80.0, 30.0, 30.0, 30.0],
+        &["Frontier", "Counting", "Method", "Function Points"],
+        &rows,
+        true,
+    );
+}
+
+/// Description of a Frontier and its Functions rated, with the totals of the counting.
+fn frontier(writer: &mut Writer, item: &ReportFrontier) {
+    let frontier = &item.frontier;
+    writer.write(&format!("Frontier: {}", frontier.name), 14.0, true);
+    if let Some(description) = &frontier.description {
+        writer.paragraph(description, 10.0);
+    }
+    writer.write(
+        &format!(
+            "Counting: {:?} - Method: {:?}",
+            frontier.counting, frontier.method
+        ),
+        10.0,
+        false,
+    );
+    writer.space(4.0);
+
+    let rows: Vec<Vec<String>> = item
+        .functions
+        .iter()
+        .map(|function| {
+            let data = &function.function;
+            vec![
+                format!("{:?}", data.r#type()),
+                data.name(),
+                data.change().map_or(String::new(), |c| format!("{:?}", c)),
+                data.records().to_string(),
+                data.elements().to_string(),
+                function
+                    .complexity
+                    .map_or(String::new(), |c| format!("{:?}", c)),
+                function.points.to_string(),
+            ]
+        })
+        .collect();
+    writer.table(
+        &[14.0, 64.0, 22.0, 18.0, 18.0, 20.0, 14.0],
+        &[
+            "Type",
+            "Name",
+            "Change",
+            "RLR/FTR",
+            "DER/DET",
+            "Complexity",
+            "Points",
+        ],
+        &rows,
+        false,
+    );
+
+    let summary = &item.summary;
+    let mut rows = vec![
+        vec![
+            "Unadjusted Function Points".to_owned(),
+            summary.ufp.to_string(),
+        ],
+        vec!["Value Adjustment Factor".to_owned(), decimal(summary.vaf)],
+        vec!["Adjusted Function Points".to_owned(), decimal(summary.afp)],
+    ];
+    if let Some(enhancement) = &summary.enhancement {
+        rows.push(vec![
+            "Enhancement Function Points".to_owned(),
+            decimal(enhancement.efp),
+        ]);
+    }
+    writer.table(&[136.0, 34.0], &["Counting", "Value"], &rows, true);
+}
+
+/// Degree of influence of the General System Characteristics, a column for each Frontier.
+fn factors(writer: &mut Writer, report: &Report) {
+    writer.write("General System Characteristics", 14.0, true);
+    writer.space(4.0);
+
+    // Columns of four Frontiers on each table.
+    for items in report.frontiers.chunks(4) {
+        let mut widths = vec![70.0];
+        widths.extend(items.iter().map(|_| 25.0));
+        let mut headers = vec!["General System Characteristic"];
+        headers.extend(items.iter().map(|i| i.frontier.name.as_str()));
+
+        let mut rows: Vec<Vec<String>> = FACTORS
+            .iter()
+            .map(|(factor, label)| {
+                let mut row = vec![(*label).to_owned()];
+                row.extend(items.iter().map(|item| {
+                    item.factors
+                        .iter()
+                        .find(|f| f.factor == *factor)
+                        .map_or(0, |f| adjustment::influence(&f.influence))
+                        .to_string()
+                }));
+                row
+            })
+            .collect();
+        let mut tdi = vec!["Total Degree of Influence".to_owned()];
+        tdi.extend(items.iter().map(|i| i.summary.tdi.to_string()));
+        rows.push(tdi);
+        let mut vaf = vec!["Value Adjustment Factor".to_owned()];
+        vaf.extend(items.iter().map(|i| decimal(i.summary.vaf)));
+        rows.push(vaf);
+
+        writer.table(&widths, &headers, &rows, true);
+    }
+}
+
+/// Effort estimated for each Frontier, and the hours of each phase.
+fn effort(writer: &mut Writer, report: &Report) {
+    writer.write("Effort Estimate", 14.0, true);
+    writer.space(4.0);
+
+    let rows: Vec<Vec<String>> = report
+        .frontiers
+        .iter()
+        .map(|item| {
+            let estimate = &item.estimate;
+            vec![
+                item.frontier.name.clone(),
+                decimal(estimate.points),
+                estimate.productivity.to_string(),
+                decimal(estimate.construction),
+                decimal(estimate.hours),
+                decimal(estimate.duration),
+            ]
+        })
+        .collect();
+    writer.table(
+        &[50.0, 20.0, 30.0, 25.0, 25.0, 20.0],
+        &[
+            "Frontier",
+            "Points",
+            "Productivity (h/FP)",
+            "Construction (h)",
+            "Total (h)",
+            "Days",
+        ],
+        &rows,
+        false,
+    );
+
+    let rows: Vec<Vec<String>> = report
+        .frontiers
+        .iter()
+        .map(|item| {
+            let mut row = vec![item.frontier.name.clone()];
+            row.extend(PHASES.iter().map(|(empirical, _)| {
+                item.estimate
+                    .phases
+                    .iter()
+                    .find(|p| p.empirical == *empirical)
+                    .map_or(String::new(), |p| {
+                        format!("{} ({}%)", decimal(p.hours), p.percent)
+                    })
+            }));
+            row
+        })
+        .collect();
+    let mut headers = vec!["Frontier"];
+    headers.extend(PHASES.iter().map(|(_, label)| *label));
+    writer.table(&[50.0, 30.0, 30.0, 30.0, 30.0], &headers, &rows, false);
+}
+
+/// Fields for the signatures of the analyst and the responsible for the Project.
+fn signatures(writer: &mut Writer) {
+    writer.reserve(50.0);
+    writer.write("Approval", 14.0, true);
+    writer.space(25.0);
+
+    let width = 75.0;
+    for (index, role) in ["Function Point Analyst", "Project Manager"]
+        .iter()
+        .enumerate()
+    {
+        let x = MARGIN + index as f32 * (CONTENT - width);
+        writer.rule(x, width, writer.y);
+        for (line, text) in [*role, "Name:", "Date:"].iter().enumerate() {
+            writer.layer.use_text(
+                *text,
+                TEXT,
+                Mm(x),
+                Mm(writer.y - 5.0 - line as f32 * 6.0),
+                writer.font(line == 0),
+            );
+        }
+    }
+    writer.space(20.0);
+}
+
+/// Renders the counting report, with the logo (PNG or JPEG) on the cover.
+pub fn render(report: &Report, logo: Option<&[u8]>) -> Result<Vec<u8>, Error> {
+    let mut writer = Writer::new(&report.project.name)?;
+    cover(&mut writer, report, logo.and_then(self::logo));
+
+    for item in report.frontiers.iter() {
+        writer.page();
+        frontier(&mut writer, item);
+    }
+
+    writer.page();
+    factors(&mut writer, report);
+    effort(&mut writer, report);
+    signatures(&mut writer);
+
+    Ok(writer.document.save_to_bytes()?)
+}
@@ -0,0 +1,214 @@
+//! Counting worksheet (XLSX) in the IFPUG layout: a sheet for each Frontier with its Functions,
+//! a sheet with the General System Characteristics and a sheet with the effort estimate.
+
+use rust_xlsxwriter::{Format, Workbook, Worksheet};
+
+use super::{Report, ReportFrontier, FACTORS, PHASES};
+use crate::{analysis::adjustment, error::Error, model::sea_orm_active_enums::EmpiricalType};
+
+const FACTORS_SHEET: &str = "Factors";
+const EFFORT_SHEET: &str = "Effort";
+
+/// Characters not allowed on the name of a sheet.
+const INVALID: [char; 7] = ['[', ']', ':', '*', '?', '/', '\\'];
+
+/// Limit of characters on the name of a sheet.
+const LIMIT: usize = 31;
+
+/// Name of the sheet for a Frontier, unique on the workbook (case insensitive).
+fn sheet(name: &str, used: &mut Vec<String>) -> String {
+    let base: String = name.chars().filter(|c| !INVALID.contains(c)).collect();
+    let base = match base.trim().trim_matches('\'') {
+        "" => "Frontier".to_owned(),
+        v => v.to_owned(),
+    };
+
+    let mut result: String = base.chars().take(LIMIT).collect();
+    let mut index = 1;
+    while used.iter().any(|u| u.eq_ignore_ascii_case(&result)) {
+        index += 1;
+        let suffix = format!(" ({})", index);
+        result = base.chars().take(LIMIT - suffix.len()).collect::<String>() + &suffix;
+    }
+    used.push(result.clone());
+    result
+}
+
+/// Formats of the cells.
+struct Formats {
+    title: Format,
+    header: Format,
+    decimal: Format,
+}
+
+/// Functions of a Frontier, with the counts of the complexity and the function points.
+fn frontier(
+    worksheet: &mut Worksheet,
+    report: &Report,
+    item: &ReportFrontier,
+    formats: &Formats,
+) -> Result<(), Error> {
+    let frontier = &item.frontier;
+    worksheet.write_string_with_format(0, 0, &report.project.name, &formats.title)?;
+    worksheet.write_string_with_format(1, 0, "Frontier", &formats.header)?;
+    worksheet.write_string(1, 1, &frontier.name)?;
+    worksheet.write_string_with_format(2, 0, "Counting", &formats.header)?;
+    worksheet.write_string(2, 1, format!("{:?}", frontier.counting))?;
+    worksheet.write_string_with_format(3, 0, "Method", &formats.header)?;
+    worksheet.write_string(3, 1, format!("{:?}", frontier.method))?;
+
+    let headers = [
+        "Type",
+        "Name",
+        "Change",
+        "RLR/FTR",
+        "DER/DET",
+        "Complexity",
+        "Points",
+    ];
+    worksheet.write_row_with_format(5, 0, headers, &formats.header)?;
+    worksheet.set_freeze_panes(6, 0)?;
+
+    let mut row = 6;
+    for function in item.functions.iter() {
+        let data = &function.function;
+        worksheet.write_string(row, 0, format!("{:?}", data.r#type()))?;
+        worksheet.write_string(row, 1, data.name())?;
+        if let Some(change) = data.change() {
+            worksheet.write_string(row, 2, format!("{:?}", change))?;
+        }
+        worksheet.write_number(row, 3, data.records() as f64)?;
+        worksheet.write_number(row, 4, data.elements() as f64)?;
+        if let Some(complexity) = function.complexity {
+            worksheet.write_string(row, 5, format!("{:?}", complexity))?;
+        }
+        worksheet.write_number(row, 6, function.points)?;
+        row += 1;
+    }
+
+    let summary = &item.summary;
+    row += 1;
+    let mut totals = vec![
+        ("Unadjusted Function Points", f64::from(summary.ufp)),
+        ("Value Adjustment Factor", summary.vaf),
+        ("Adjusted Function Points", summary.afp),
+    ];
+    if let Some(enhancement) = &summary.enhancement {
+        totals.push(("Enhancement Function Points", enhancement.efp));
+    }
+    for (label, value) in totals {
+        worksheet.write_string_with_format(row, 5, label, &formats.header)?;
+        worksheet.write_number_with_format(row, 6, value, &formats.decimal)?;
+        row += 1;
+    }
+
+    worksheet.autofit();
+    Ok(())
+}
+
+/// Degree of influence of the General System Characteristics, a column for each Frontier.
+fn factors(worksheet: &mut Worksheet, report: &Report, formats: &Formats) -> Result<(), Error> {
+    worksheet.write_string_with_format(0, 0, "General System Characteristic", &formats.header)?;
+    for (column, item) in report.frontiers.iter().enumerate() {
+        let column = column as u16 + 1;
+        worksheet.write_string_with_format(0, column, &item.frontier.name, &formats.header)?;
+
+        for (row, (factor, _)) in FACTORS.iter().enumerate() {
+            let influence = item
+                .factors
+                .iter()
+                .find(|f| f.factor == *factor)
+                .map_or(0, |f| adjustment::influence(&f.influence));
+            worksheet.write_number(row as u32 + 1, column, influence)?;
+        }
+        let row = FACTORS.len() as u32 + 1;
+        worksheet.write_number(row, column, item.summary.tdi)?;
+        worksheet.write_number_with_format(row + 1, column, item.summary.vaf, &formats.decimal)?;
+    }
+
+    for (row, (_, label)) in FACTORS.iter().enumerate() {
+        worksheet.write_string(row as u32 + 1, 0, *label)?;
+    }
+    let row = FACTORS.len() as u32 + 1;
+    worksheet.write_string_with_format(row, 0, "Total Degree of Influence", &formats.header)?;
+    worksheet.write_string_with_format(row + 1, 0, "Value Adjustment Factor", &formats.header)?;
+    worksheet.set_freeze_panes(1, 1)?;
+
+    worksheet.autofit();
+    Ok(())
+}
+
+/// Empiricals of each Frontier and the effort estimated, a row for each Frontier.
+fn effort(worksheet: &mut Worksheet, report: &Report, formats: &Formats) -> Result<(), Error> {
+    let mut headers = vec![
+        "Frontier".to_owned(),
+        "Function Points".to_owned(),
+        "Productivity (h/FP)".to_owned(),
+    ];
+    headers.extend(PHASES.iter().map(|(_, label)| format!("{} (%)", label)));
+    headers.push("Construction (h)".to_owned());
+    headers.extend(PHASES.iter().map(|(_, label)| format!("{} (h)", label)));
+    headers.push("Total (h)".to_owned());
+    headers.push("Duration (days)".to_owned());
+    worksheet.write_row_with_format(0, 0, headers, &formats.header)?;
+    worksheet.set_freeze_panes(1, 1)?;
+
+    for (row, item) in report.frontiers.iter().enumerate() {
+        let row = row as u32 + 1;
+        let estimate = &item.estimate;
+        let phase =
+            |empirical: &EmpiricalType| estimate.phases.iter().find(|p| p.empirical == *empirical);
+
+        let mut values = vec![estimate.points, f64::from(estimate.productivity)];
+        values.extend(
+            PHASES
+                .iter()
+                .map(|(empirical, _)| phase(empirical).map_or(0.0, |p| f64::from(p.percent))),
+        );
+        values.push(estimate.construction);
+        values.extend(
+            PHASES
+                .iter()
+                .map(|(empirical, _)| phase(empirical).map_or(0.0, |p| p.hours)),
+        );
+        values.push(estimate.hours);
+        values.push(estimate.duration);
+
+        worksheet.write_string(row, 0, &item.frontier.name)?;
+        worksheet.write_row_with_format(row, 1, values, &formats.decimal)?;
+    }
+
+    worksheet.autofit();
+    Ok(())
+}
+
+/// Renders the counting worksheet of the report.
+pub fn render(report: &Report) -> Result<Vec<u8>, Error> {
+    let formats = Formats {
+        title: Format::new().set_bold().set_font_size(14),
+        header: Format::new().set_bold(),
+        decimal: Format::new().set_num_format("0.00"),
+    };
+
+    let mut workbook = Workbook::new();
+    let mut used = vec![
+        "History".to_owned(),
+        FACTORS_SHEET.to_owned(),
+        EFFORT_SHEET.to_owned(),
+    ];
+    for item in report.frontiers.iter() {
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name(sheet(&item.frontier.name, &mut used))?;
+        frontier(worksheet, report, item, &formats)?;
+    }
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_name(FACTORS_SHEET)?;
+    factors(worksheet, report, &formats)?;
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_name(EFFORT_SHEET)?;
+    effort(worksheet, report, &formats)?;
+
+    Ok(workbook.save_to_buffer()?)
+}
@@ -1,24 +1,49 @@
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
 use log::trace;
 use sea_orm::{ConnectionTrait, DatabaseConnection, DatabaseTransaction, TransactionTrait};
+use tokio::sync::RwLock;
 use uuid::Uuid;
 
-use crate::{configuration::Configuration, error::Error};
+use crate::{
+    configuration::Configuration,
+    ctx::{Context, Role},
+    error::Error,
+    jwks::Jwks,
+};
 
 #[derive(Clone, Debug)]
 pub struct AppState {
     configuration: Configuration,
     connection: DatabaseConnection,
+    system: DatabaseConnection,
+    jwks: Arc<Jwks>,
+    /// Name and e-mail of the Users already registered, by their ids.
+    users: Arc<RwLock<HashMap<Uuid, (String, String)>>>,
 }
 
 impl AppState {
-    pub fn new(configuration: Configuration, connection: DatabaseConnection) -> Self {
+    pub fn new(
+        configuration: Configuration,
+        connection: DatabaseConnection,
+        system: DatabaseConnection,
+    ) -> Self {
+        let jwks = Arc::new(Jwks::new(
+            configuration.issuers.clone(),
+            Duration::from_secs(configuration.jwks_refresh),
+            Duration::from_secs(configuration.jwks_throttle),
+        ));
         Self {
             configuration,
             connection,
+            system,
+            jwks,
+            users: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
-    pub async fn connection(&self, tenant: &Uuid) -> Result<DatabaseTransaction, Error> {
+    /// Transaction scoped (RLS) to the Tenant and the Projects of the User.
+    pub async fn connection(&self, context: &Context) -> Result<DatabaseTransaction, Error> {
         trace!("New database connection.");
         let db = &self.connection;
         if db.ping().await.is_err() {
@@ -27,7 +52,12 @@ impl AppState {
 
         let trx = db.begin().await.unwrap();
 
-        let sql = format!("SET app.current_tenant = '{}';", tenant);
+        let sql = format!(
+            "SET app.current_tenant = '{}'; SET app.\"current_user\" = '{}'; SET app.current_admin = '{}';",
+            context.tenant(),
+            context.id(),
+            context.has(Role::Admin)
+        );
         if trx.execute_unprepared(sql.as_str()).await.is_err() {
             return Err(Error::DatabaseConnection);
         }
@@ -35,7 +65,41 @@ impl AppState {
         Ok(trx)
     }
 
+    /// Transaction of the administration of the Tenants, without RLS.
+    pub async fn system_connection(&self) -> Result<DatabaseTransaction, Error> {
+        trace!("New system database connection.");
+        let db = &self.system;
+        if db.ping().await.is_err() {
+            return Err(Error::DatabaseConnection);
+        }
+
+        match db.begin().await {
+            Ok(v) => Ok(v),
+            Err(_) => Err(Error::DatabaseConnection),
+        }
+    }
+
     pub fn configuration(&self) -> &Configuration {
         &self.configuration
     }
+
+    pub fn jwks(&self) -> &Arc<Jwks> {
+        &self.jwks
+    }
+
+    /// Checks if the User is registered with the name and e-mail of the Context.
+    pub async fn user_known(&self, context: &Context) -> bool {
+        match self.users.read().await.get(context.id()) {
+            Some((name, email)) => name == context.name() && email == context.email(),
+            None => false,
+        }
+    }
+
+    /// Caches the name and e-mail of the registered User.
+    pub async fn user_registered(&self, context: &Context) {
+        self.users.write().await.insert(
+            *context.id(),
+            (context.name().to_owned(), context.email().to_owned()),
+        );
+    }
 }
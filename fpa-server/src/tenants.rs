@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::State,
+    http::{Method, Request},
+    middleware::Next,
+    response::Response,
+};
+use log::{debug, trace};
+use sea_orm::{DatabaseTransaction, EntityTrait};
+
+use crate::{
+    configuration::Configuration,
+    ctx::Context,
+    error::Error,
+    model::{
+        prelude::{Tenants, TenantsUsage},
+        sea_orm_active_enums::TenantStatus,
+    },
+    state::AppState,
+};
+
+/// Records of the Tenant limited by the tier.
+#[derive(Debug)]
+pub enum Resource {
+    Projects,
+    Frontiers,
+    Functions,
+}
+
+/// Rejects the disabled Tenants and allows only reading for the suspended ones.
+pub async fn require(
+    context: Option<Context>,
+    State(state): State<Arc<AppState>>,
+    request: Request<Body>,
+    next: Next,
+) -> Result<Response, Error> {
+    debug!("Validating the tenant status.");
+
+    let ctx = context.unwrap();
+    let db = state.connection(&ctx).await?;
+    let status = Tenants::find_by_id(*ctx.tenant())
+        .one(&db)
+        .await?
+        .map(|t| t.status);
+    trace!("Tenant {} with the status {:?}.", ctx.tenant(), status);
+
+    match status {
+        Some(TenantStatus::Active) => Ok(next.run(request).await),
+        Some(TenantStatus::Suspended) => match *request.method() {
+            Method::GET | Method::HEAD | Method::OPTIONS => Ok(next.run(request).await),
+            _ => Err(Error::TenantSuspended),
+        },
+        _ => Err(Error::TenantDisabled),
+    }
+}
+
+/// Checks if the Tenant can register one more record, by the quotas of its tier.
+pub async fn quota(
+    resource: Resource,
+    config: &Configuration,
+    db: &DatabaseTransaction,
+) -> Result<(), Error> {
+    let usage = match TenantsUsage::find().one(db).await? {
+        Some(v) => v,
+        None => return Err(Error::TenantDisabled),
+    };
+    let quota = config.quotas.tier(&usage.tier);
+    trace!("Tenant usage {:?} with the quota {:?}.", usage, quota);
+
+    match resource {
+        Resource::Projects if usage.projects >= quota.projects => Err(Error::QuotaProjects),
+        Resource::Frontiers if usage.frontiers >= quota.frontiers => Err(Error::QuotaFrontiers),
+        Resource::Functions if usage.functions >= quota.functions => Err(Error::QuotaFunctions),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,100 @@
+mod shared;
+
+use anyhow::Result;
+use reqwest::StatusCode;
+use serde_json::json;
+use shared::{
+    tokens::{self, Tenant},
+    ADMIN, PASSWORD, SYSTEM,
+};
+use uuid::Uuid;
+
+const URL: &str = "http://localhost:5000/api/admin/tenants";
+
+async fn list(token: &String) -> Result<()> {
+    let response = reqwest::Client::new()
+        .get(URL)
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json = response.json::<serde_json::Value>().await?;
+    assert!(json["records"].as_u64().unwrap() >= 1);
+
+    Ok(())
+}
+
+async fn manage(token: &String) -> Result<()> {
+    let name = format!("Tenant Test {}", Uuid::now_v7());
+    let response = reqwest::Client::new()
+        .post(URL)
+        .bearer_auth(token)
+        .json(&json!({ "name": name, "tier": "Bronze" }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let json = response.json::<serde_json::Value>().await?;
+    assert_eq!(json["status"], json!("Active"));
+    let tenant = json["tenant"].as_str().unwrap().to_owned();
+
+    let response = reqwest::Client::new()
+        .put(format!("{}/{}", URL, tenant))
+        .bearer_auth(token)
+        .json(&json!({ "name": name, "status": "Suspended", "tier": "Silver" }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = response.json::<serde_json::Value>().await?;
+    assert_eq!(json["status"], json!("Suspended"));
+    assert_eq!(json["tier"], json!("Silver"));
+
+    let response = reqwest::Client::new()
+        .get(format!("{}/{}/usage", URL, tenant))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = response.json::<serde_json::Value>().await?;
+    assert_eq!(json["projects"], json!(0));
+    assert_eq!(json["points"], json!(0.0));
+
+    Ok(())
+}
+
+async fn usage(token: &String) -> Result<()> {
+    let response = reqwest::Client::new()
+        .get(format!("{}/{}/usage", URL, Uuid::nil()))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json = response.json::<serde_json::Value>().await?;
+    assert!(json["users"].as_u64().unwrap() >= 1);
+    assert!(json["projects"].as_u64().unwrap() >= 1);
+    assert!(json["points"].as_f64().unwrap() > 0.0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn execute() -> Result<()> {
+    let token = tokens::request_token(SYSTEM, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    assert!(!token.is_empty());
+
+    list(&token).await?;
+    manage(&token).await?;
+    usage(&token).await?;
+
+    // Tenant administrators do not manage the Tenants.
+    let admin = tokens::request_token(ADMIN, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    let response = reqwest::Client::new()
+        .get(URL)
+        .bearer_auth(admin)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    Ok(())
+}
@@ -0,0 +1,217 @@
+mod shared;
+
+use anyhow::Result;
+use reqwest::StatusCode;
+use serde_json::json;
+use shared::{
+    tokens::{self, Tenant},
+    ADMIN, PASSWORD, URL, USERNAME,
+};
+use uuid::Uuid;
+
+const IMPORT: &str = "http://localhost:5000/api/projects/import";
+
+async fn export(token: &String, project: &str) -> Result<serde_json::Value> {
+    let response = reqwest::Client::new()
+        .get(format!("{}/{}/export", URL, project))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json = response.json::<serde_json::Value>().await?;
+    assert_eq!(json["version"], json!(1));
+    assert!(json["frontiers"].is_array());
+
+    Ok(json)
+}
+
+async fn import(
+    token: &String,
+    archive: &serde_json::Value,
+    name: Option<&str>,
+) -> Result<(StatusCode, serde_json::Value)> {
+    let mut request = reqwest::Client::new().post(IMPORT).bearer_auth(token);
+    if let Some(name) = name {
+        request = request.query(&[("name", name)]);
+    }
+    let response = request.json(archive).send().await?;
+    let status = response.status();
+    let json = response.json::<serde_json::Value>().await?;
+    Ok((status, json))
+}
+
+fn archive(name: &str) -> serde_json::Value {
+    json!({
+        "version": 1,
+        "name": name,
+        "description": "Imported project.",
+        "frontiers": [{
+            "name": "Archived Frontier",
+            "counting": "Development",
+            "method": "Detailed",
+            "factors": [{ "factor": "DataCommunications", "influence": "Strong" }],
+            "empiricals": [{ "empirical": "Productivity", "value": 12 }],
+            "functions": [
+                {
+                    "type": "EE",
+                    "name": "Register Customer",
+                    "alrs": ["Customers"],
+                    "dets": [{
+                        "name": "name",
+                        "reference": { "alr": "Customers", "rlr": "Customer", "der": "name" }
+                    }]
+                },
+                {
+                    "type": "ALI",
+                    "name": "Customers",
+                    "rlrs": [{ "name": "Customer", "ders": [{ "name": "id" }, { "name": "name" }] }]
+                }
+            ]
+        }]
+    })
+}
+
+async fn round_trip(token: &String) -> Result<(String, String)> {
+    let name = format!("Import Test {}", Uuid::now_v7());
+    let (status, json) = import(token, &archive(&name), None).await?;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(json["name"], json!(name));
+    let project = json["project"].as_str().unwrap().to_owned();
+
+    let exported = export(token, &project).await?;
+    assert_eq!(exported["name"], json!(name));
+    let frontier = &exported["frontiers"][0];
+    assert_eq!(frontier["name"], json!("Archived Frontier"));
+    let factors = frontier["factors"].as_array().unwrap();
+    assert_eq!(factors.len(), 14);
+    assert!(factors.contains(&json!({ "factor": "DataCommunications", "influence": "Strong" })));
+    let empiricals = frontier["empiricals"].as_array().unwrap();
+    assert!(empiricals.contains(&json!({ "empirical": "Productivity", "value": 12 })));
+
+    // Data Functions first, with the references by name.
+    let functions = frontier["functions"].as_array().unwrap();
+    assert_eq!(functions[0]["name"], json!("Customers"));
+    assert_eq!(functions[0]["rlrs"][0]["ders"].as_array().unwrap().len(), 2);
+    assert_eq!(functions[1]["alrs"], json!(["Customers"]));
+    assert_eq!(
+        functions[1]["dets"][0]["reference"]["alr"],
+        json!("Customers")
+    );
+
+    // The name is in use on the Tenant, and the copy receives a new one.
+    let (status, json) = import(token, &exported, None).await?;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let conflicts = json.as_array().unwrap();
+    assert_eq!(conflicts[0]["entity"], json!("Project"));
+
+    let copy = format!("{} Copy", name);
+    let (status, json) = import(token, &exported, Some(&copy)).await?;
+    assert_eq!(status, StatusCode::CREATED);
+    let copy = json["project"].as_str().unwrap().to_owned();
+    assert_ne!(copy, project);
+
+    let mut archive = export(token, &copy).await?;
+    archive["name"] = exported["name"].clone();
+    archive["time"] = exported["time"].clone();
+    assert_eq!(archive, exported);
+
+    Ok((project, copy))
+}
+
+async fn remove(token: &String, admin: &String, project: &str) -> Result<()> {
+    let response = reqwest::Client::new()
+        .get(format!("{}/{}/frontiers", URL, project))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    let json = response.json::<serde_json::Value>().await?;
+    for frontier in json["items"].as_array().unwrap() {
+        let frontier = frontier["frontier"].as_str().unwrap();
+        let response = reqwest::Client::new()
+            .get(format!(
+                "{}/{}/frontiers/{}/functions",
+                URL, project, frontier
+            ))
+            .bearer_auth(token)
+            .send()
+            .await?;
+        let json = response.json::<serde_json::Value>().await?;
+        let mut functions: Vec<_> = json["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|f| f.as_object().unwrap().iter().next().unwrap())
+            .map(|(r#type, data)| (r#type.clone(), data.clone()))
+            .collect();
+        // Transactions first, they reference the Data Functions.
+        functions.sort_by_key(|(r#type, _)| r#type == "ALI" || r#type == "AIE");
+        for (_, data) in functions {
+            let response = reqwest::Client::new()
+                .delete(format!(
+                    "{}/{}/frontiers/{}/functions/{}",
+                    URL,
+                    project,
+                    frontier,
+                    data["id"].as_str().unwrap()
+                ))
+                .bearer_auth(token)
+                .send()
+                .await?;
+            assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        }
+        let response = reqwest::Client::new()
+            .delete(format!("{}/{}/frontiers/{}", URL, project, frontier))
+            .bearer_auth(token)
+            .send()
+            .await?;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    let response = reqwest::Client::new()
+        .delete(format!("{}/{}", URL, project))
+        .bearer_auth(admin)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    Ok(())
+}
+
+async fn invalid(token: &String) -> Result<()> {
+    let archive = json!({ "version": 99, "name": "Future Archive" });
+    let (status, json) = import(token, &archive, None).await?;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"], json!("ARCHIVE_VERSION"));
+
+    let archive = json!({
+        "version": 1,
+        "name": format!("Invalid Archive {}", Uuid::now_v7()),
+        "frontiers": [{
+            "name": "Frontier",
+            "counting": "Development",
+            "method": "Detailed",
+            "functions": [{ "type": "EE", "name": "Orphan", "alrs": ["Missing"] }]
+        }]
+    });
+    let (status, json) = import(token, &archive, None).await?;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"], json!("ARCHIVE_INVALID"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn execute() -> Result<()> {
+    let token = tokens::request_token(USERNAME, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    let admin = tokens::request_token(ADMIN, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    assert!(!token.is_empty());
+
+    let (project, copy) = round_trip(&token).await?;
+    invalid(&token).await?;
+
+    remove(&token, &admin, &copy).await?;
+    remove(&token, &admin, &project).await?;
+
+    Ok(())
+}
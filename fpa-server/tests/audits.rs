@@ -0,0 +1,145 @@
+mod shared;
+
+use anyhow::Result;
+use reqwest::StatusCode;
+use serde_json::json;
+use shared::{
+    tokens::{self, Tenant},
+    ADMIN, PASSWORD, URL, USERNAME, VIEWER,
+};
+use uuid::Uuid;
+
+async fn audit(token: &String, project: &str, query: &str) -> Result<serde_json::Value> {
+    let response = reqwest::Client::new()
+        .get(format!("{}/{}/audit{}", URL, project, query))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    Ok(response.json::<serde_json::Value>().await?)
+}
+
+async fn changes(token: &String) -> Result<String> {
+    let name = format!("Audit Test {}", Uuid::now_v7());
+    let response = reqwest::Client::new()
+        .post(URL)
+        .bearer_auth(token)
+        .json(&json!({ "name": name }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let json = response.json::<serde_json::Value>().await?;
+    let project = json["project"].as_str().unwrap().to_owned();
+
+    let response = reqwest::Client::new()
+        .put(format!("{}/{}", URL, project))
+        .bearer_auth(token)
+        .json(&json!({ "name": name, "description": "Audited project." }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = reqwest::Client::new()
+        .post(format!("{}/{}/frontiers", URL, project))
+        .bearer_auth(token)
+        .json(&json!({ "name": "Audited Frontier" }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let json = response.json::<serde_json::Value>().await?;
+    let frontier = json["frontier"].as_str().unwrap().to_owned();
+
+    let response = reqwest::Client::new()
+        .put(format!(
+            "{}/{}/frontiers/{}/factors",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .json(&json!({ "factor": "DataCommunications", "influence": "Strong" }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = reqwest::Client::new()
+        .delete(format!("{}/{}/frontiers/{}", URL, project, frontier))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    Ok(project)
+}
+
+async fn trail(token: &String, project: &str) -> Result<()> {
+    let json = audit(token, project, "").await?;
+    assert_eq!(json["records"], json!(5));
+
+    // The latest change first.
+    let items = json["items"].as_array().unwrap();
+    let actions: Vec<_> = items
+        .iter()
+        .map(|i| (i["entity"].clone(), i["action"].clone()))
+        .collect();
+    assert_eq!(
+        actions,
+        vec![
+            (json!("Frontier"), json!("Delete")),
+            (json!("Factor"), json!("Update")),
+            (json!("Frontier"), json!("Create")),
+            (json!("Project"), json!("Update")),
+            (json!("Project"), json!("Create")),
+        ]
+    );
+
+    let update = &items[3];
+    assert!(update["before"]["description"].is_null());
+    assert_eq!(update["after"]["description"], json!("Audited project."));
+    assert!(items[0]["after"].is_null());
+    assert!(items[4]["before"].is_null());
+
+    let json = audit(token, project, "?entity=Frontier").await?;
+    assert_eq!(json["records"], json!(2));
+
+    let json = audit(token, project, &format!("?key={}", project)).await?;
+    assert_eq!(json["records"], json!(2));
+
+    let json = audit(token, project, "?size=2&page=3").await?;
+    assert_eq!(json["pages"], json!(3));
+    assert_eq!(json["size"], json!(1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn execute() -> Result<()> {
+    let token = tokens::request_token(USERNAME, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    assert!(!token.is_empty());
+
+    let project = changes(&token).await?;
+    trail(&token, &project).await?;
+
+    // Only the members of the Project read its changes, it is not found by the others.
+    let viewer = tokens::request_token(VIEWER, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    let response = reqwest::Client::new()
+        .get(format!("{}/{}/audit", URL, project))
+        .bearer_auth(viewer)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // The trail is kept after the removal of the Project.
+    let admin = tokens::request_token(ADMIN, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    let response = reqwest::Client::new()
+        .delete(format!("{}/{}", URL, project))
+        .bearer_auth(&admin)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    let json = audit(&admin, &project, "").await?;
+    assert_eq!(json["records"], json!(6));
+    assert_eq!(json["items"][0]["action"], json!("Delete"));
+    assert_eq!(json["items"][0]["before"]["project"], json!(project));
+
+    Ok(())
+}
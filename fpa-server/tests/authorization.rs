@@ -0,0 +1,119 @@
+mod shared;
+
+use anyhow::Result;
+use reqwest::StatusCode;
+use serde_json::json;
+use shared::{
+    selects,
+    tokens::{self, Tenant},
+    ADMIN, PASSWORD, REVIEWER, URL, USERNAME, VIEWER,
+};
+use uuid::Uuid;
+
+async fn create(token: &String, name: &str) -> Result<reqwest::Response> {
+    let body = json!({
+        "name": name,
+        "description": "Authorization testing project.",
+    });
+    Ok(reqwest::Client::new()
+        .post(URL)
+        .bearer_auth(token)
+        .json(&body)
+        .send()
+        .await?)
+}
+
+async fn remove(token: &String, project: &Uuid) -> Result<reqwest::Response> {
+    Ok(reqwest::Client::new()
+        .delete(format!("{}/{}", URL, project))
+        .bearer_auth(token)
+        .send()
+        .await?)
+}
+
+async fn viewer(token: &String) -> Result<()> {
+    let response = reqwest::Client::new()
+        .get(URL)
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = create(token, "Authorization Viewer Test").await?;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let json = response.json::<serde_json::Value>().await?;
+    assert_eq!(json["error"], json!("AUTHORIZATION"));
+
+    Ok(())
+}
+
+async fn analyst(token: &String, admin: &String) -> Result<()> {
+    let response = create(token, "Authorization Analyst Test").await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let json = response.json::<serde_json::Value>().await?;
+    let project = Uuid::parse_str(json["project"].as_str().unwrap()).unwrap();
+
+    let response = remove(token, &project).await?;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = remove(admin, &project).await?;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    Ok(())
+}
+
+async fn baseline(token: &String, project: &Uuid, frontier: &Uuid) -> Result<reqwest::Response> {
+    Ok(reqwest::Client::new()
+        .post(format!(
+            "{}/{}/frontiers/{}/baseline",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .json(&json!({ "enhancement": frontier }))
+        .send()
+        .await?)
+}
+
+async fn reviewer(token: &String, reviewer: &String) -> Result<()> {
+    let project = selects::project(token).await?;
+    let frontier = selects::frontier(token, &project).await?;
+
+    let response = baseline(token, &project, &frontier).await?;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let json = response.json::<serde_json::Value>().await?;
+    assert_eq!(json["error"], json!("AUTHORIZATION"));
+
+    // The reviewer is authorized, the Frontier is not an Enhancement of itself.
+    let response = baseline(reviewer, &project, &frontier).await?;
+    assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+
+    Ok(())
+}
+
+async fn roles(tenant: Tenant) -> Result<()> {
+    let token = tokens::request_token(VIEWER, PASSWORD, tenant).await?;
+    assert!(!token.is_empty());
+    viewer(&token).await?;
+
+    let token = tokens::request_token(USERNAME, PASSWORD, tenant).await?;
+    let admin = tokens::request_token(ADMIN, PASSWORD, tenant).await?;
+    analyst(&token, &admin).await?;
+
+    let review = tokens::request_token(REVIEWER, PASSWORD, tenant).await?;
+    assert!(!review.is_empty());
+    reviewer(&token, &review).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn execute() -> Result<()> {
+    roles(Tenant::TENANT_DEFAULT).await?;
+    roles(Tenant::TENANT_01).await?;
+    roles(Tenant::TENANT_02).await?;
+
+    Ok(())
+}
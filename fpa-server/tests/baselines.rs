@@ -0,0 +1,260 @@
+mod shared;
+
+use anyhow::Result;
+use reqwest::StatusCode;
+use serde_json::json;
+use shared::{
+    selects,
+    tokens::{self, Tenant},
+    PASSWORD, REVIEWER, URL, USERNAME,
+};
+use uuid::Uuid;
+
+async fn create_frontier(token: &String, project: &Uuid, body: serde_json::Value) -> Result<Uuid> {
+    let response = reqwest::Client::new()
+        .post(format!("{}/{}/frontiers", URL, project))
+        .bearer_auth(token)
+        .json(&body)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let json = response.json::<serde_json::Value>().await?;
+    Ok(Uuid::parse_str(json["frontier"].as_str().unwrap()).unwrap())
+}
+
+async fn create_function(
+    token: &String,
+    project: &Uuid,
+    frontier: &Uuid,
+    r#type: &str,
+    body: serde_json::Value,
+) -> Result<Uuid> {
+    let response = reqwest::Client::new()
+        .post(format!(
+            "{}/{}/frontiers/{}/functions",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .json(&json!({ r#type: body }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let json = response.json::<serde_json::Value>().await?;
+    Ok(Uuid::parse_str(json[r#type]["id"].as_str().unwrap()).unwrap())
+}
+
+async fn list_functions(
+    token: &String,
+    project: &Uuid,
+    frontier: &Uuid,
+) -> Result<Vec<serde_json::Value>> {
+    let response = reqwest::Client::new()
+        .get(format!(
+            "{}/{}/frontiers/{}/functions",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json = response.json::<serde_json::Value>().await?;
+    Ok(json["items"].as_array().unwrap().to_vec())
+}
+
+async fn apply(
+    token: &String,
+    project: &Uuid,
+    baseline: &Uuid,
+    enhancement: &Uuid,
+) -> Result<reqwest::Response> {
+    Ok(reqwest::Client::new()
+        .post(format!(
+            "{}/{}/frontiers/{}/baseline",
+            URL, project, baseline
+        ))
+        .bearer_auth(token)
+        .json(&json!({ "enhancement": enhancement }))
+        .send()
+        .await?)
+}
+
+async fn audit(token: &String, project: &Uuid, key: &Uuid) -> Result<serde_json::Value> {
+    let response = reqwest::Client::new()
+        .get(format!(
+            "{}/{}/audit?entity=Function&key={}",
+            URL, project, key
+        ))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    Ok(response.json::<serde_json::Value>().await?)
+}
+
+async fn remove(token: &String, project: &Uuid, frontier: &Uuid) -> Result<()> {
+    for function in list_functions(token, project, frontier).await? {
+        let (_, data) = function.as_object().unwrap().iter().next().unwrap();
+        let response = reqwest::Client::new()
+            .delete(format!(
+                "{}/{}/frontiers/{}/functions/{}",
+                URL,
+                project,
+                frontier,
+                data["id"].as_str().unwrap()
+            ))
+            .bearer_auth(token)
+            .send()
+            .await?;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    let response = reqwest::Client::new()
+        .delete(format!("{}/{}/frontiers/{}", URL, project, frontier))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    Ok(())
+}
+
+async fn promote(token: &String, reviewer: &String, project: &Uuid) -> Result<()> {
+    let rlrs = json!([{
+        "name": "Customers",
+        "ders": [{ "name": "id" }, { "name": "name" }],
+    }]);
+
+    let baseline = create_frontier(
+        token,
+        project,
+        json!({ "name": "Baseline Test", "counting": "Application" }),
+    )
+    .await?;
+    let current = create_function(
+        token,
+        project,
+        &baseline,
+        "ALI",
+        json!({ "name": "Customers" }),
+    )
+    .await?;
+    let legacy = create_function(
+        token,
+        project,
+        &baseline,
+        "AIE",
+        json!({ "name": "Legacy" }),
+    )
+    .await?;
+
+    let enhancement = create_frontier(
+        token,
+        project,
+        json!({ "name": "Baseline Enhancement Test", "counting": "Enhancement" }),
+    )
+    .await?;
+    let ali = create_function(
+        token,
+        project,
+        &enhancement,
+        "ALI",
+        json!({ "name": "Customers", "change": "Changed", "rlrs": rlrs }),
+    )
+    .await?;
+    create_function(
+        token,
+        project,
+        &enhancement,
+        "AIE",
+        json!({ "name": "Legacy", "change": "Deleted" }),
+    )
+    .await?;
+    create_function(
+        token,
+        project,
+        &enhancement,
+        "EE",
+        json!({
+            "name": "Register Customer",
+            "change": "Added",
+            "alrs": [{ "id": ali }],
+            "dets": [{
+                "name": "name",
+                "reference": { "alr": ali, "rlr": "Customers", "der": "name" },
+            }],
+        }),
+    )
+    .await?;
+
+    // Only reviewers apply Enhancements on the baseline.
+    let response = apply(token, project, &baseline, &enhancement).await?;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = apply(reviewer, project, &enhancement, &baseline).await?;
+    assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+
+    let response = apply(reviewer, project, &baseline, &enhancement).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = response.json::<serde_json::Value>().await?;
+    assert_eq!(json["added"], json!(1));
+    assert_eq!(json["changed"], json!(1));
+    assert_eq!(json["deleted"], json!(1));
+
+    let functions = list_functions(token, project, &baseline).await?;
+    assert_eq!(functions.len(), 2);
+    let customers = functions.iter().find_map(|f| f.get("ALI")).unwrap();
+    assert_eq!(customers["enhancement"], json!(enhancement));
+    assert_eq!(customers["rlrs"].as_array().unwrap().len(), 1);
+    let register = functions.iter().find_map(|f| f.get("EE")).unwrap();
+    assert_eq!(register["enhancement"], json!(enhancement));
+    assert_eq!(register["change"], json!(null));
+    assert_eq!(register["alrs"][0]["ALI"]["id"], customers["id"]);
+    assert_eq!(register["dets"][0]["reference"]["alr"], customers["id"]);
+
+    // Changes of the baseline are on the audit trail.
+    let json = audit(token, project, &current).await?;
+    assert_eq!(json["items"][0]["action"], json!("Update"));
+    assert_eq!(json["items"][0]["before"]["ALI"]["rlrs"], json!([]));
+    assert_eq!(json["items"][0]["after"]["ALI"]["rlrs"], customers["rlrs"]);
+    let json = audit(token, project, &legacy).await?;
+    assert_eq!(json["items"][0]["action"], json!("Delete"));
+    let json = audit(
+        token,
+        project,
+        &Uuid::parse_str(register["id"].as_str().unwrap())?,
+    )
+    .await?;
+    assert_eq!(json["items"][0]["action"], json!("Create"));
+    assert_eq!(
+        json["items"][0]["after"]["EE"]["enhancement"],
+        json!(enhancement)
+    );
+
+    // Adding the same Functions again conflicts with the baseline.
+    let response = apply(reviewer, project, &baseline, &enhancement).await?;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    remove(token, project, &enhancement).await?;
+    remove(token, project, &baseline).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn execute() -> Result<()> {
+    let token = tokens::request_token(USERNAME, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    assert!(!token.is_empty());
+
+    let reviewer = tokens::request_token(REVIEWER, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    assert!(!reviewer.is_empty());
+
+    let project = selects::project(&token).await?;
+
+    promote(&token, &reviewer, &project).await?;
+
+    Ok(())
+}
@@ -0,0 +1,211 @@
+mod shared;
+
+use anyhow::Result;
+use reqwest::StatusCode;
+use serde_json::json;
+use shared::{
+    selects,
+    tokens::{self, Tenant},
+    PASSWORD, URL, USERNAME,
+};
+use uuid::Uuid;
+
+/// RETs, DETs and the expected complexity on each edge of the Data Function matrix.
+const DATA: [(usize, usize, &str); 12] = [
+    (1, 19, "Low"),
+    (1, 20, "Low"),
+    (1, 50, "Low"),
+    (1, 51, "Average"),
+    (2, 19, "Low"),
+    (2, 20, "Average"),
+    (5, 50, "Average"),
+    (5, 51, "High"),
+    (6, 19, "Average"),
+    (6, 20, "High"),
+    (6, 51, "High"),
+    (0, 0, "Low"),
+];
+
+/// FTRs, DETs and the expected complexity on each edge of the EE matrix.
+const EE: [(usize, usize, &str); 10] = [
+    (1, 4, "Low"),
+    (1, 5, "Low"),
+    (1, 15, "Low"),
+    (1, 16, "Average"),
+    (2, 4, "Low"),
+    (2, 5, "Average"),
+    (2, 15, "Average"),
+    (2, 16, "High"),
+    (3, 4, "Average"),
+    (3, 5, "High"),
+];
+
+/// FTRs, DETs and the expected complexity on each edge of the CE and SE matrix.
+const OUTPUT: [(usize, usize, &str); 10] = [
+    (1, 5, "Low"),
+    (1, 6, "Low"),
+    (1, 19, "Low"),
+    (1, 20, "Average"),
+    (2, 5, "Low"),
+    (2, 6, "Average"),
+    (3, 19, "Average"),
+    (3, 20, "High"),
+    (4, 5, "Average"),
+    (4, 6, "High"),
+];
+
+async fn create_frontier(token: &String, project: &Uuid) -> Result<Uuid> {
+    let response = reqwest::Client::new()
+        .post(format!("{}/{}/frontiers", URL, project))
+        .bearer_auth(token)
+        .json(&json!({ "name": "Complexity Test", "counting": "Application" }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let json = response.json::<serde_json::Value>().await?;
+    Ok(Uuid::parse_str(json["frontier"].as_str().unwrap()).unwrap())
+}
+
+async fn create_function(
+    token: &String,
+    project: &Uuid,
+    frontier: &Uuid,
+    r#type: &str,
+    body: serde_json::Value,
+) -> Result<serde_json::Value> {
+    let response = reqwest::Client::new()
+        .post(format!(
+            "{}/{}/frontiers/{}/functions",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .json(&json!({ r#type: body }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let json = response.json::<serde_json::Value>().await?;
+    Ok(json[r#type].clone())
+}
+
+/// RLRs with the DERs spread over them, the first RLR takes the remainder.
+fn rlrs(rets: usize, dets: usize) -> serde_json::Value {
+    let items: Vec<serde_json::Value> = (0..rets)
+        .map(|r| {
+            let count = dets / rets + if r == 0 { dets % rets } else { 0 };
+            let ders: Vec<serde_json::Value> = (0..count)
+                .map(|d| json!({ "name": format!("DER {:02}", d + 1) }))
+                .collect();
+            json!({ "name": format!("RLR {:02}", r + 1), "ders": ders })
+        })
+        .collect();
+    json!(items)
+}
+
+fn dets(count: usize) -> serde_json::Value {
+    let items: Vec<serde_json::Value> = (0..count)
+        .map(|d| json!({ "name": format!("DET {:02}", d + 1) }))
+        .collect();
+    json!(items)
+}
+
+async fn data(token: &String, project: &Uuid, frontier: &Uuid) -> Result<Vec<Uuid>> {
+    let mut ids = Vec::new();
+    for r#type in ["ALI", "AIE"] {
+        for (rets, dets, complexity) in DATA {
+            let body = json!({
+                "name": format!("{} {} RET {} DET", r#type, rets, dets),
+                "rlrs": rlrs(rets, dets),
+            });
+            let value = create_function(token, project, frontier, r#type, body).await?;
+            assert_eq!(
+                value["complexity"],
+                json!(complexity),
+                "{} with {} RET and {} DET",
+                r#type,
+                rets,
+                dets
+            );
+            ids.push(Uuid::parse_str(value["id"].as_str().unwrap()).unwrap());
+        }
+    }
+
+    Ok(ids)
+}
+
+async fn transactions(
+    token: &String,
+    project: &Uuid,
+    frontier: &Uuid,
+    data: &[Uuid],
+) -> Result<Vec<Uuid>> {
+    let mut ids = Vec::new();
+    let cases = EE
+        .iter()
+        .map(|c| ("EE", c))
+        .chain(OUTPUT.iter().map(|c| ("CE", c)))
+        .chain(OUTPUT.iter().map(|c| ("SE", c)));
+    for (r#type, (ftrs, count, complexity)) in cases {
+        let alrs: Vec<serde_json::Value> =
+            data[..*ftrs].iter().map(|id| json!({ "id": id })).collect();
+        let body = json!({
+            "name": format!("{} {} FTR {} DET", r#type, ftrs, count),
+            "alrs": alrs,
+            "dets": dets(*count),
+        });
+        let value = create_function(token, project, frontier, r#type, body).await?;
+        assert_eq!(
+            value["complexity"],
+            json!(complexity),
+            "{} with {} FTR and {} DET",
+            r#type,
+            ftrs,
+            count
+        );
+        ids.push(Uuid::parse_str(value["id"].as_str().unwrap()).unwrap());
+    }
+
+    Ok(ids)
+}
+
+async fn remove(token: &String, project: &Uuid, frontier: &Uuid, ids: &[Uuid]) -> Result<()> {
+    for id in ids {
+        let response = reqwest::Client::new()
+            .delete(format!(
+                "{}/{}/frontiers/{}/functions/{}",
+                URL, project, frontier, id
+            ))
+            .bearer_auth(token)
+            .send()
+            .await?;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    let response = reqwest::Client::new()
+        .delete(format!("{}/{}/frontiers/{}", URL, project, frontier))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn execute() -> Result<()> {
+    let token = tokens::request_token(USERNAME, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    assert!(!token.is_empty());
+
+    let project = selects::project(&token).await?;
+    let frontier = create_frontier(&token, &project).await?;
+
+    let data = data(&token, &project, &frontier).await?;
+    let transactions = transactions(&token, &project, &frontier, &data).await?;
+
+    // Transaction Functions first, they reference the Data Functions.
+    let ids: Vec<Uuid> = transactions.into_iter().chain(data).collect();
+    remove(&token, &project, &frontier, &ids).await?;
+
+    Ok(())
+}
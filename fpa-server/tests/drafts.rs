@@ -0,0 +1,350 @@
+mod shared;
+
+use anyhow::Result;
+use reqwest::StatusCode;
+use serde_json::json;
+use shared::{
+    tokens::{self, Tenant},
+    ADMIN, PASSWORD, URL, USERNAME,
+};
+use uuid::Uuid;
+
+const DDL: &str = "
+CREATE TABLE customers (id INT PRIMARY KEY, name VARCHAR(50));
+CREATE TABLE orders (
+    id INT PRIMARY KEY,
+    customer INT REFERENCES customers(id),
+    total DECIMAL(10, 2)
+);
+CREATE TABLE order_items (
+    order_id INT REFERENCES orders(id),
+    line INT,
+    product INT,
+    PRIMARY KEY (order_id, line)
+);
+CREATE INDEX orders_customer ON orders (customer);
+COMMENT ON TABLE orders IS 'Orders of the customers.';
+COMMENT ON COLUMN orders.total IS 'Total of the order.';
+";
+
+const OPENAPI: &str = r##"
+openapi: 3.0.3
+info:
+  title: Orders
+  version: "1.0"
+paths:
+  /orders:
+    get:
+      summary: List orders
+      parameters:
+        - { name: page, in: query, schema: { type: integer } }
+      responses:
+        "200":
+          description: Page of orders.
+          content:
+            application/json:
+              schema:
+                type: object
+                properties:
+                  records: { type: integer }
+                  items: { type: array, items: { $ref: "#/components/schemas/Order" } }
+    post:
+      operationId: createOrder
+      requestBody:
+        content:
+          application/json:
+            schema: { $ref: "#/components/schemas/Order" }
+      responses:
+        "201": { description: Order created. }
+  /customers/{customer}/statistics:
+    get:
+      parameters:
+        - { name: customer, in: path, required: true, schema: { type: integer } }
+      responses:
+        "200":
+          description: Statistics of the orders.
+          content:
+            application/json:
+              schema:
+                type: object
+                properties:
+                  orders: { type: integer }
+                  average: { type: number }
+components:
+  schemas:
+    Order:
+      type: object
+      properties:
+        customer: { type: integer }
+        total: { type: number }
+"##;
+
+async fn frontier(token: &String) -> Result<(String, String)> {
+    let response = reqwest::Client::new()
+        .post(URL)
+        .bearer_auth(token)
+        .json(&json!({ "name": format!("Draft Test {}", Uuid::now_v7()) }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let json = response.json::<serde_json::Value>().await?;
+    let project = json["project"].as_str().unwrap().to_owned();
+
+    let response = reqwest::Client::new()
+        .post(format!("{}/{}/frontiers", URL, project))
+        .bearer_auth(token)
+        .json(&json!({ "name": "Drafted Frontier" }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let json = response.json::<serde_json::Value>().await?;
+    let frontier = json["frontier"].as_str().unwrap().to_owned();
+
+    Ok((project, frontier))
+}
+
+async fn ddl(
+    token: &String,
+    project: &str,
+    frontier: &str,
+    ddl: &str,
+    confirm: bool,
+) -> Result<(StatusCode, serde_json::Value)> {
+    let response = reqwest::Client::new()
+        .post(format!(
+            "{}/{}/frontiers/{}/functions/import/ddl",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .json(&json!({ "ddl": ddl, "confirm": confirm }))
+        .send()
+        .await?;
+    let status = response.status();
+    let json = response.json::<serde_json::Value>().await?;
+    Ok((status, json))
+}
+
+async fn preview(token: &String, project: &str, frontier: &str) -> Result<()> {
+    let (status, json) = ddl(token, project, frontier, DDL, false).await?;
+    assert_eq!(status, StatusCode::OK);
+
+    let functions = json.as_array().unwrap();
+    assert_eq!(functions.len(), 2);
+    assert_eq!(functions[0]["name"], json!("customers"));
+    assert_eq!(functions[0]["id"], json!(null));
+
+    // The items are dependent records of the orders.
+    let orders = &functions[1];
+    assert_eq!(orders["type"], json!("ALI"));
+    assert_eq!(orders["name"], json!("orders"));
+    assert_eq!(orders["description"], json!("Orders of the customers."));
+    let rlrs = orders["rlrs"].as_array().unwrap();
+    assert_eq!(rlrs[0]["name"], json!("orders"));
+    assert_eq!(rlrs[1]["name"], json!("order_items"));
+    assert_eq!(rlrs[0]["ders"][1]["foreign"], json!("customers"));
+    assert_eq!(
+        rlrs[0]["ders"][2]["description"],
+        json!("Total of the order.")
+    );
+    assert_eq!(rlrs[1]["ders"][0]["foreign"], json!("orders"));
+    assert_eq!(rlrs[1]["ders"][1]["foreign"], json!(null));
+
+    let (status, json) = ddl(token, project, frontier, "CREATE INDEX ix ON t (c);", false).await?;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"], json!("DDL_INVALID"));
+
+    let (status, json) = ddl(token, project, frontier, "CREATE TABLE t (c INT,", false).await?;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"], json!("DDL_INVALID"));
+
+    Ok(())
+}
+
+async fn confirm(token: &String, project: &str, frontier: &str) -> Result<()> {
+    let (status, json) = ddl(token, project, frontier, DDL, true).await?;
+    assert_eq!(status, StatusCode::CREATED);
+
+    for function in json.as_array().unwrap() {
+        let response = reqwest::Client::new()
+            .get(format!(
+                "{}/{}/frontiers/{}/functions/{}",
+                URL,
+                project,
+                frontier,
+                function["id"].as_str().unwrap()
+            ))
+            .bearer_auth(token)
+            .send()
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let created = response.json::<serde_json::Value>().await?;
+        assert_eq!(created["ALI"]["name"], function["name"]);
+    }
+
+    // Names already in use on the Frontier.
+    let (status, json) = ddl(token, project, frontier, DDL, true).await?;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let conflicts = json.as_array().unwrap();
+    assert_eq!(conflicts.len(), 2);
+    assert_eq!(conflicts[0]["entity"], json!("Function"));
+    assert_eq!(conflicts[0]["name"], json!("customers"));
+    assert_eq!(conflicts[0]["frontier"], json!("Drafted Frontier"));
+
+    Ok(())
+}
+
+async fn reviewed(
+    token: &String,
+    project: &str,
+    frontier: &str,
+    drafts: &serde_json::Value,
+) -> Result<(StatusCode, serde_json::Value)> {
+    let response = reqwest::Client::new()
+        .post(format!(
+            "{}/{}/frontiers/{}/functions/import/drafts",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .json(drafts)
+        .send()
+        .await?;
+    let status = response.status();
+    let json = response.json::<serde_json::Value>().await?;
+    Ok((status, json))
+}
+
+async fn openapi(
+    token: &String,
+    project: &str,
+    frontier: &str,
+    document: &str,
+    confirm: bool,
+) -> Result<(StatusCode, serde_json::Value)> {
+    let response = reqwest::Client::new()
+        .post(format!(
+            "{}/{}/frontiers/{}/functions/import/openapi",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .json(&json!({ "document": document, "confirm": confirm }))
+        .send()
+        .await?;
+    let status = response.status();
+    let json = response.json::<serde_json::Value>().await?;
+    Ok((status, json))
+}
+
+async fn operations(token: &String, project: &str, frontier: &str) -> Result<()> {
+    let (status, json) = openapi(token, project, frontier, OPENAPI, false).await?;
+    assert_eq!(status, StatusCode::OK);
+
+    let functions = json.as_array().unwrap();
+    assert_eq!(functions.len(), 3);
+    assert_eq!(functions[0]["type"], json!("CE"));
+    assert_eq!(functions[0]["name"], json!("List orders"));
+    assert_eq!(functions[1]["type"], json!("EE"));
+    assert_eq!(functions[1]["name"], json!("createOrder"));
+    assert_eq!(functions[2]["type"], json!("SE"));
+
+    // Matched by the name of the resource, with the DETs traced to the DERs.
+    assert_eq!(functions[1]["alrs"][0]["name"], json!("orders"));
+    let dets = functions[1]["dets"].as_array().unwrap();
+    assert_eq!(dets.len(), 2);
+    assert_eq!(dets[0]["reference"]["rlr"], json!("orders"));
+    assert_eq!(dets[0]["reference"]["der"], json!("customer"));
+
+    let (status, json) = openapi(token, project, frontier, "swagger: '2.0'", false).await?;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"], json!("OPENAPI_INVALID"));
+
+    // The preview is reviewed before the creation: the SE is removed and the CE renamed.
+    let mut drafts = functions[..2].to_vec();
+    drafts[0]["name"] = json!("Orders");
+    let (status, json) = reviewed(token, project, frontier, &json!(drafts)).await?;
+    assert_eq!(status, StatusCode::CREATED);
+    let created = json.as_array().unwrap();
+    assert_eq!(created.len(), 2);
+    assert_eq!(created[0]["name"], json!("Orders"));
+    for function in created {
+        assert!(function["id"].is_string());
+    }
+
+    let (status, json) = reviewed(token, project, frontier, &json!([drafts[1], drafts[1]])).await?;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(json.as_array().unwrap().len(), 2);
+
+    // Only the drafts with the names in use are reported.
+    let (status, json) = openapi(token, project, frontier, OPENAPI, true).await?;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let conflicts = json.as_array().unwrap();
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0]["name"], json!("createOrder"));
+
+    Ok(())
+}
+
+async fn remove(token: &String, admin: &String, project: &str, frontier: &str) -> Result<()> {
+    let response = reqwest::Client::new()
+        .get(format!(
+            "{}/{}/frontiers/{}/functions",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    let json = response.json::<serde_json::Value>().await?;
+    let mut functions: Vec<_> = json["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| f.as_object().unwrap().iter().next().unwrap())
+        .map(|(r#type, data)| (r#type.clone(), data.clone()))
+        .collect();
+    // Transactions first, they reference the Data Functions.
+    functions.sort_by_key(|(r#type, _)| r#type == "ALI" || r#type == "AIE");
+    for (_, data) in functions {
+        let response = reqwest::Client::new()
+            .delete(format!(
+                "{}/{}/frontiers/{}/functions/{}",
+                URL,
+                project,
+                frontier,
+                data["id"].as_str().unwrap()
+            ))
+            .bearer_auth(token)
+            .send()
+            .await?;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    let response = reqwest::Client::new()
+        .delete(format!("{}/{}/frontiers/{}", URL, project, frontier))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = reqwest::Client::new()
+        .delete(format!("{}/{}", URL, project))
+        .bearer_auth(admin)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn execute() -> Result<()> {
+    let token = tokens::request_token(USERNAME, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    let admin = tokens::request_token(ADMIN, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    assert!(!token.is_empty());
+
+    let (project, frontier) = frontier(&token).await?;
+    preview(&token, &project, &frontier).await?;
+    confirm(&token, &project, &frontier).await?;
+    operations(&token, &project, &frontier).await?;
+    remove(&token, &admin, &project, &frontier).await?;
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+mod shared;
+
+use anyhow::Result;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use shared::{
+    selects,
+    tokens::{self, Tenant},
+    PASSWORD, URL, USERNAME,
+};
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+struct Phase {
+    hours: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct Data {
+    frontier: Uuid,
+    points: f64,
+    productivity: i32,
+    construction: f64,
+    phases: Vec<Phase>,
+    hours: f64,
+    team: i32,
+    daily: i32,
+    duration: f64,
+    rate: Option<f64>,
+    cost: Option<f64>,
+}
+
+async fn by_frontier(token: &String, project: &Uuid, frontier: &Uuid) -> Result<()> {
+    let response = reqwest::Client::new()
+        .get(format!(
+            "{}/{}/frontiers/{}/estimate?team=2&hours=6&rate=100",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json = response.json::<Data>().await?;
+    assert_eq!(json.frontier, *frontier);
+    assert_eq!(json.phases.len(), 4);
+    assert!((json.construction - json.points * f64::from(json.productivity)).abs() < 0.01);
+    let phases: f64 = json.phases.iter().map(|p| p.hours).sum();
+    assert!((json.hours - (json.construction + phases)).abs() < 0.01);
+    assert_eq!(json.team, 2);
+    assert_eq!(json.daily, 6);
+    assert!((json.duration - json.hours / 12.0).abs() < 0.01);
+    assert_eq!(json.rate, Some(100.0));
+    assert!((json.cost.unwrap() - json.hours * 100.0).abs() < 0.01);
+
+    Ok(())
+}
+
+async fn defaults(token: &String, project: &Uuid, frontier: &Uuid) -> Result<()> {
+    let response = reqwest::Client::new()
+        .get(format!(
+            "{}/{}/frontiers/{}/estimate",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json = response.json::<Data>().await?;
+    assert_eq!(json.team, 1);
+    assert_eq!(json.daily, 8);
+    assert_eq!(json.rate, None);
+    assert_eq!(json.cost, None);
+
+    Ok(())
+}
+
+async fn large_team(token: &String, project: &Uuid, frontier: &Uuid) -> Result<()> {
+    let response = reqwest::Client::new()
+        .get(format!(
+            "{}/{}/frontiers/{}/estimate?team={}&hours=24",
+            URL,
+            project,
+            frontier,
+            i32::MAX
+        ))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // The team by the daily hours does not overflow.
+    let json = response.json::<Data>().await?;
+    assert_eq!(json.team, i32::MAX);
+    assert!(json.duration >= 0.0);
+    assert!((json.duration - json.hours / (f64::from(i32::MAX) * 24.0)).abs() < 0.01);
+
+    Ok(())
+}
+
+async fn invalid(token: &String, project: &Uuid, frontier: &Uuid) -> Result<()> {
+    let response = reqwest::Client::new()
+        .get(format!(
+            "{}/{}/frontiers/{}/estimate?team=0",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn execute() -> Result<()> {
+    let token = tokens::request_token(USERNAME, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    assert!(!token.is_empty());
+
+    let project = selects::project(&token).await?;
+    let frontier = selects::frontier(&token, &project).await?;
+
+    by_frontier(&token, &project, &frontier).await?;
+    defaults(&token, &project, &frontier).await?;
+    large_team(&token, &project, &frontier).await?;
+    invalid(&token, &project, &frontier).await?;
+
+    Ok(())
+}
@@ -12,6 +12,7 @@ use shared::{
 use uuid::Uuid;
 
 const FRONTIER_NAME: &str = "Frontier Test";
+const FRONTIER_RENAMED: &str = "Frontier Test Renamed";
 const FRONTIER_DESCRIPTION: &str = "Descrição longa da nova fronteira de teste.";
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -19,6 +20,7 @@ struct Data {
     frontier: Uuid,
     name: String,
     description: Option<String>,
+    counting: String,
 }
 
 async fn create(token: &String, project: &Uuid) -> Result<Data> {
@@ -38,6 +40,7 @@ async fn create(token: &String, project: &Uuid) -> Result<Data> {
     assert!(!data.frontier.is_nil());
     assert_eq!(data.name, FRONTIER_NAME);
     assert_eq!(data.description, None);
+    assert_eq!(data.counting, "Development");
 
     Ok(data)
 }
@@ -94,8 +97,9 @@ async fn list(token: &String, project: &Uuid) -> Result<()> {
 
 async fn update(token: &String, project: &Uuid, data: &Data) -> Result<()> {
     let body = json!({
-        "name": data.name.clone(),
+        "name": FRONTIER_RENAMED,
         "description": Some(String::from(FRONTIER_DESCRIPTION)),
+        "counting": "Enhancement",
     });
     let response = reqwest::Client::new()
         .put(format!("{}/{}/frontiers/{}", URL, project, data.frontier))
@@ -107,8 +111,21 @@ async fn update(token: &String, project: &Uuid, data: &Data) -> Result<()> {
 
     let json = response.json::<Data>().await?;
     assert!(!json.frontier.is_nil());
-    assert_eq!(json.name, FRONTIER_NAME);
+    assert_eq!(json.name, FRONTIER_RENAMED);
     assert_eq!(json.description.unwrap(), FRONTIER_DESCRIPTION);
+    assert_eq!(json.counting, "Enhancement");
+
+    // The update is committed, not only returned.
+    let response = reqwest::Client::new()
+        .get(format!("{}/{}/frontiers/{}", URL, project, data.frontier))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = response.json::<Data>().await?;
+    assert_eq!(json.name, FRONTIER_RENAMED);
+    assert_eq!(json.description.unwrap(), FRONTIER_DESCRIPTION);
+    assert_eq!(json.counting, "Enhancement");
 
     Ok(())
 }
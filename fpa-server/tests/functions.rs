@@ -159,6 +159,8 @@ async fn create_ali(token: &String, project: &Uuid, frontier: &Uuid) -> Result<U
 
     assert_eq!(value["name"], json!("ALI Test Name"));
     assert_eq!(value["description"], json!("ALI Test Description"));
+    assert_eq!(value["complexity"], json!("Low"));
+    assert_eq!(value["points"], json!(7));
     assert!(value["rlrs"].is_array());
     assert_eq!(value["rlrs"].as_array().unwrap().len(), 1);
 
@@ -228,6 +230,8 @@ async fn create_aie(token: &String, project: &Uuid, frontier: &Uuid) -> Result<U
 
     assert_eq!(value["name"], json!("AIE Test Name"));
     assert_eq!(value["description"], json!("AIE Test Description"));
+    assert_eq!(value["complexity"], json!("Low"));
+    assert_eq!(value["points"], json!(5));
     assert!(value["rlrs"].is_array());
     assert_eq!(value["rlrs"].as_array().unwrap().len(), 1);
 
@@ -258,6 +262,21 @@ async fn create_ee(token: &String, project: &Uuid, frontier: &Uuid, data: &Data)
                     "type": "ALI",
                     "id": data.function_ali.to_string(),
                 }
+            ],
+            "dets": [
+                {
+                    "name": "DET 01 Test Name",
+                    "description": "DET Test Description",
+                    "reference": {
+                        "alr": data.function_ali.to_string(),
+                        "rlr": "RLR Test Name",
+                        "der": "DER 01 Test Name",
+                    }
+                },
+                { "name": "DET 02 Test Name" },
+                { "name": "DET 03 Test Name" },
+                { "name": "DET 04 Test Name" },
+                { "name": "DET 05 Test Name" },
             ]
         }
     });
@@ -283,6 +302,8 @@ async fn create_ee(token: &String, project: &Uuid, frontier: &Uuid, data: &Data)
 
     assert_eq!(value["name"], json!("EE Test Name"));
     assert_eq!(value["description"], json!("EE Test Description"));
+    assert_eq!(value["complexity"], json!("Low"));
+    assert_eq!(value["points"], json!(3));
     assert!(value["alrs"].is_array());
     assert_eq!(value["alrs"].as_array().unwrap().len(), 1);
 
@@ -294,9 +315,68 @@ async fn create_ee(token: &String, project: &Uuid, frontier: &Uuid, data: &Data)
     assert_eq!(ali["name"], json!("ALI Test Name"));
     assert_eq!(ali["description"], json!("ALI Test Description"));
 
+    assert!(value["dets"].is_array());
+    assert_eq!(value["dets"].as_array().unwrap().len(), 5);
+
+    let det = value["dets"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|det| det["name"] == json!("DET 01 Test Name"))
+        .unwrap();
+    assert_eq!(det["description"], json!("DET Test Description"));
+    assert_eq!(
+        det["reference"]["alr"],
+        json!(data.function_ali.to_string())
+    );
+    assert_eq!(det["reference"]["rlr"], json!("RLR Test Name"));
+    assert_eq!(det["reference"]["der"], json!("DER 01 Test Name"));
+
     Ok(id)
 }
 
+async fn create_ee_invalid_reference(
+    token: &String,
+    project: &Uuid,
+    frontier: &Uuid,
+    data: &Data,
+) -> Result<()> {
+    let body = json!({
+        "EE": {
+            "name": "EE Test Invalid Reference",
+            "alrs": [
+                {
+                    "type": "ALI",
+                    "id": data.function_ali.to_string(),
+                }
+            ],
+            "dets": [
+                {
+                    "name": "DET 01 Test Name",
+                    "reference": {
+                        "alr": data.function_aie.to_string(),
+                        "rlr": "RLR Test Name",
+                        "der": "DER 01 Test Name",
+                    }
+                },
+            ]
+        }
+    });
+
+    let response = reqwest::Client::new()
+        .post(format!(
+            "{}/{}/frontiers/{}/functions",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .json(&body)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+
+    Ok(())
+}
+
 async fn create_ce(token: &String, project: &Uuid, frontier: &Uuid, data: &Data) -> Result<Uuid> {
     let body = json!({
         "CE": {
@@ -332,6 +412,8 @@ async fn create_ce(token: &String, project: &Uuid, frontier: &Uuid, data: &Data)
 
     assert_eq!(value["name"], json!("CE Test Name"));
     assert_eq!(value["description"], json!("CE Test Description"));
+    assert_eq!(value["complexity"], json!("Low"));
+    assert_eq!(value["points"], json!(3));
     assert!(value["alrs"].is_array());
     assert_eq!(value["alrs"].as_array().unwrap().len(), 1);
 
@@ -386,6 +468,8 @@ async fn create_se(token: &String, project: &Uuid, frontier: &Uuid, data: &Data)
 
     assert_eq!(value["name"], json!("SE Test Name"));
     assert_eq!(value["description"], json!("SE Test Description"));
+    assert_eq!(value["complexity"], json!("Low"));
+    assert_eq!(value["points"], json!(4));
     assert!(value["alrs"].is_array());
     assert_eq!(value["alrs"].as_array().unwrap().len(), 2);
 
@@ -500,6 +584,8 @@ async fn update_ali(
 
     assert_eq!(value["name"], json!("ALI Test Name Updated"));
     assert_eq!(value["description"], json!("ALI Test Description Updated"));
+    assert_eq!(value["complexity"], json!("Low"));
+    assert_eq!(value["points"], json!(7));
     assert!(value["rlrs"].is_array());
     assert_eq!(value["rlrs"].as_array().unwrap().len(), 2);
 
@@ -529,6 +615,41 @@ async fn update_ali(
     Ok(())
 }
 
+/// The DETs keep the references to the DERs not removed by the update of the ALI.
+async fn kept_references(
+    token: &String,
+    project: &Uuid,
+    frontier: &Uuid,
+    data: &Data,
+) -> Result<()> {
+    let response = reqwest::Client::new()
+        .get(format!(
+            "{}/{}/frontiers/{}/functions/{}",
+            URL, project, frontier, data.function_ee
+        ))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json = response.json::<serde_json::Value>().await?;
+    let det = json["EE"]["dets"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|det| det["name"] == json!("DET 01 Test Name"))
+        .unwrap()
+        .clone();
+    assert_eq!(
+        det["reference"]["alr"],
+        json!(data.function_ali.to_string())
+    );
+    assert_eq!(det["reference"]["rlr"], json!("RLR Test Name"));
+    assert_eq!(det["reference"]["der"], json!("DER 01 Test Name"));
+
+    Ok(())
+}
+
 async fn update_aie(
     token: &String,
     project: &Uuid,
@@ -640,6 +761,10 @@ async fn update_ee(token: &String, project: &Uuid, frontier: &Uuid, data: &Data)
 
     assert_eq!(ali["id"], json!(data.function_aie.to_string()));
 
+    assert!(value["dets"].is_array());
+    assert!(value["dets"].as_array().unwrap().is_empty());
+    assert_eq!(value["complexity"], json!("Low"));
+
     Ok(())
 }
 
@@ -776,6 +901,7 @@ async fn execute() -> Result<()> {
     data.function_ali = create_ali(&token, &project, &frontier).await?;
     data.function_aie = create_aie(&token, &project, &frontier).await?;
     data.function_ee = create_ee(&token, &project, &frontier, &data).await?;
+    create_ee_invalid_reference(&token, &project, &frontier, &data).await?;
     data.function_ce = create_ce(&token, &project, &frontier, &data).await?;
     data.function_se = create_se(&token, &project, &frontier, &data).await?;
 
@@ -786,6 +912,7 @@ async fn execute() -> Result<()> {
     by_id(&token, &project, &frontier, &data.function_se).await?;
 
     update_ali(&token, &project, &frontier, &data.function_ali).await?;
+    kept_references(&token, &project, &frontier, &data).await?;
     update_aie(&token, &project, &frontier, &data.function_aie).await?;
     update_ee(&token, &project, &frontier, &data).await?;
     update_ce(&token, &project, &frontier, &data).await?;
@@ -0,0 +1,93 @@
+mod shared;
+
+use anyhow::Result;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use serde_json::json;
+use shared::{
+    tokens::{self, Tenant},
+    ADMIN, PASSWORD, USERNAME,
+};
+
+const URL: &str = "http://localhost:5000/api/impacts";
+
+#[derive(Debug, Deserialize)]
+struct Data {
+    impact: String,
+    value: i32,
+}
+
+async fn list(token: &String) -> Result<()> {
+    let response = reqwest::Client::new()
+        .get(URL)
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json = response.json::<serde_json::Value>().await?;
+    assert_eq!(json["pages"], json!(1));
+    assert_eq!(json["index"], json!(1));
+    assert_eq!(json["size"], json!(5));
+    assert_eq!(json["records"], json!(5));
+    assert!(json["items"].is_array());
+
+    let items = json["items"].as_array().unwrap();
+    let inclusion = items
+        .iter()
+        .find(|i| i["impact"] == json!("Inclusion"))
+        .unwrap();
+    assert_eq!(inclusion["value"], json!(100));
+
+    Ok(())
+}
+
+async fn update(token: &String) -> Result<()> {
+    let body = json!({
+        "impact": "Testing",
+        "value": 15,
+    });
+    let response = reqwest::Client::new()
+        .put(URL)
+        .bearer_auth(token)
+        .json(&body)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let data = response.json::<Data>().await?;
+    assert_eq!(data.impact, "Testing");
+    assert_eq!(data.value, 15);
+
+    Ok(())
+}
+
+async fn update_invalid(token: &String) -> Result<()> {
+    let body = json!({
+        "impact": "Testing",
+        "value": 101,
+    });
+    let response = reqwest::Client::new()
+        .put(URL)
+        .bearer_auth(token)
+        .json(&body)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn execute() -> Result<()> {
+    let token = tokens::request_token(USERNAME, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    assert!(!token.is_empty());
+
+    list(&token).await?;
+
+    let admin = tokens::request_token(ADMIN, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    update(&admin).await?;
+    update_invalid(&admin).await?;
+
+    Ok(())
+}
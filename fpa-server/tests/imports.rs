@@ -0,0 +1,203 @@
+mod shared;
+
+use anyhow::Result;
+use reqwest::StatusCode;
+use serde_json::json;
+use shared::{
+    tokens::{self, Tenant},
+    ADMIN, PASSWORD, URL, USERNAME,
+};
+use uuid::Uuid;
+
+const CSV: &str = "\
+type,name,description,rlr,der,referenced_alr
+ALI,Customer,Customers of the store.,,,
+ALI,Customer,,,id,
+ALI,Customer,Name of the customer.,,name,
+ALI,Customer,,Address,street,
+EE,Create Customer,Register a customer.,,,
+EE,Create Customer,,,name,Customer
+EE,Create Customer,,Address,street,Customer
+EE,Create Customer,,,confirm,
+";
+
+const ERRORS: &str = "\
+Type,Name,Description,RLR,DER,Referenced_ALR
+ALI,Customer,,,id,
+XX,Unknown Type,,,,
+CE,List Orders,,,id,Order
+EE,Update Customer,,Address,number,Customer
+EE,Remove Customer,,,,Create Customer
+";
+
+async fn frontier(token: &String) -> Result<(String, String)> {
+    let response = reqwest::Client::new()
+        .post(URL)
+        .bearer_auth(token)
+        .json(&json!({ "name": format!("Import Test {}", Uuid::now_v7()) }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let json = response.json::<serde_json::Value>().await?;
+    let project = json["project"].as_str().unwrap().to_owned();
+
+    let response = reqwest::Client::new()
+        .post(format!("{}/{}/frontiers", URL, project))
+        .bearer_auth(token)
+        .json(&json!({ "name": "Imported Frontier" }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let json = response.json::<serde_json::Value>().await?;
+    let frontier = json["frontier"].as_str().unwrap().to_owned();
+
+    Ok((project, frontier))
+}
+
+async fn csv(
+    token: &String,
+    project: &str,
+    frontier: &str,
+    content: &str,
+) -> Result<(StatusCode, serde_json::Value)> {
+    let response = reqwest::Client::new()
+        .post(format!(
+            "{}/{}/frontiers/{}/functions/import/csv",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .header("Content-Type", "text/csv")
+        .body(content.to_owned())
+        .send()
+        .await?;
+    let status = response.status();
+    let json = response.json::<serde_json::Value>().await?;
+    Ok((status, json))
+}
+
+async fn create(token: &String, project: &str, frontier: &str) -> Result<()> {
+    let (status, json) = csv(token, project, frontier, CSV).await?;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let functions = json.as_array().unwrap();
+    assert_eq!(functions.len(), 2);
+    let customer = &functions[0]["ALI"];
+    assert_eq!(customer["name"], json!("Customer"));
+    assert_eq!(customer["description"], json!("Customers of the store."));
+    let rlrs = customer["rlrs"].as_array().unwrap();
+    assert_eq!(rlrs.len(), 2);
+    assert_eq!(rlrs[0]["name"], json!("Customer"));
+    assert_eq!(
+        rlrs[0]["ders"][1]["description"],
+        json!("Name of the customer.")
+    );
+
+    // DETs traced to the DERs of the referenced ALR.
+    let transaction = &functions[1]["EE"];
+    assert_eq!(transaction["alrs"][0]["ALI"]["id"], customer["id"]);
+    let dets = transaction["dets"].as_array().unwrap();
+    assert_eq!(dets.len(), 3);
+    assert_eq!(dets[0]["reference"]["rlr"], json!("Customer"));
+    assert_eq!(dets[1]["reference"]["rlr"], json!("Address"));
+    assert_eq!(dets[2]["reference"], json!(null));
+
+    Ok(())
+}
+
+async fn errors(token: &String, project: &str, frontier: &str) -> Result<()> {
+    let (status, json) = csv(token, project, frontier, ERRORS).await?;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let errors: Vec<(u64, String)> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| {
+            (
+                e["row"].as_u64().unwrap(),
+                e["error"].as_str().unwrap().to_owned(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        errors,
+        vec![
+            (2, "NameDuplicated".to_owned()),
+            (3, "TypeInvalid".to_owned()),
+            (4, "ALRUnknown".to_owned()),
+            (5, "DERUnknown".to_owned()),
+            (6, "TypeMismatch".to_owned()),
+        ]
+    );
+
+    let (status, json) = csv(token, project, frontier, "a;b\n1;2\n").await?;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"], json!("CSV_INVALID"));
+
+    Ok(())
+}
+
+async fn remove(token: &String, admin: &String, project: &str, frontier: &str) -> Result<()> {
+    let response = reqwest::Client::new()
+        .get(format!(
+            "{}/{}/frontiers/{}/functions",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    let json = response.json::<serde_json::Value>().await?;
+    let mut functions: Vec<_> = json["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| f.as_object().unwrap().iter().next().unwrap())
+        .map(|(r#type, data)| (r#type.clone(), data.clone()))
+        .collect();
+    // Transactions first, they reference the Data Functions.
+    functions.sort_by_key(|(r#type, _)| r#type == "ALI" || r#type == "AIE");
+    for (_, data) in functions {
+        let response = reqwest::Client::new()
+            .delete(format!(
+                "{}/{}/frontiers/{}/functions/{}",
+                URL,
+                project,
+                frontier,
+                data["id"].as_str().unwrap()
+            ))
+            .bearer_auth(token)
+            .send()
+            .await?;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    let response = reqwest::Client::new()
+        .delete(format!("{}/{}/frontiers/{}", URL, project, frontier))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = reqwest::Client::new()
+        .delete(format!("{}/{}", URL, project))
+        .bearer_auth(admin)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn execute() -> Result<()> {
+    let token = tokens::request_token(USERNAME, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    let admin = tokens::request_token(ADMIN, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    assert!(!token.is_empty());
+
+    let (project, frontier) = frontier(&token).await?;
+    create(&token, &project, &frontier).await?;
+    errors(&token, &project, &frontier).await?;
+    remove(&token, &admin, &project, &frontier).await?;
+
+    Ok(())
+}
@@ -0,0 +1,210 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use anyhow::Result;
+use axum::{extract::State, http::header, response::IntoResponse, routing::get, Json, Router};
+use fpa_server::{configuration::Issuer, jwks::Jwks};
+use serde_json::json;
+use tokio::net::TcpListener;
+use uuid::Uuid;
+
+/// Stand-in for a realm of the issuer, serving the discovery and the current key.
+#[derive(Default)]
+struct Realm {
+    /// Issuer informed by the discovery document.
+    issuer: Mutex<String>,
+    kid: Mutex<String>,
+    max_age: Mutex<Option<u64>>,
+    requests: AtomicUsize,
+}
+
+impl Realm {
+    fn rotate(&self, kid: &str) {
+        *self.kid.lock().unwrap() = kid.to_owned();
+    }
+
+    fn requests(&self) -> usize {
+        self.requests.load(Ordering::SeqCst)
+    }
+}
+
+async fn certs(State(realm): State<Arc<Realm>>) -> impl IntoResponse {
+    realm.requests.fetch_add(1, Ordering::SeqCst);
+    let kid = realm.kid.lock().unwrap().clone();
+    let cache = match *realm.max_age.lock().unwrap() {
+        Some(age) => format!("public, max-age={}", age),
+        None => "no-transform".to_owned(),
+    };
+    let body = json!({
+        "keys": [{
+            "kid": kid,
+            "kty": "RSA",
+            "alg": "RS256",
+            "use": "sig",
+            "n": "test",
+            "e": "AQAB",
+            "x5c": [],
+            "x5t": "test",
+            "x5t#S256": "test",
+        }]
+    });
+    ([(header::CACHE_CONTROL, cache)], Json(body))
+}
+
+async fn discovery(State(realm): State<Arc<Realm>>) -> impl IntoResponse {
+    let issuer = realm.issuer.lock().unwrap().clone();
+    Json(json!({
+        "issuer": issuer,
+        "jwks_uri": format!("{}/certs", issuer),
+    }))
+}
+
+async fn serve(kid: &str, max_age: Option<u64>) -> Result<(Arc<Realm>, Vec<Issuer>)> {
+    let realm = Arc::new(Realm::default());
+    realm.rotate(kid);
+    *realm.max_age.lock().unwrap() = max_age;
+
+    let router = Router::new()
+        .route("/.well-known/openid-configuration", get(discovery))
+        .route("/certs", get(certs))
+        .with_state(realm.clone());
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("http://{}", listener.local_addr()?);
+    *realm.issuer.lock().unwrap() = url.clone();
+    tokio::spawn(async move { axum::serve(listener, router).await });
+
+    let issuers = vec![Issuer {
+        issuer: url,
+        tenant: Uuid::from_u128(1),
+    }];
+    Ok((realm, issuers))
+}
+
+#[tokio::test]
+async fn rotation() -> Result<()> {
+    let (realm, issuers) = serve("key-01", None).await?;
+    let iss = issuers[0].issuer.clone();
+    let jwks = Jwks::new(issuers, Duration::from_secs(300), Duration::ZERO);
+
+    assert_eq!(jwks.key(&iss, "key-01").await.unwrap().0.kid, "key-01");
+    assert_eq!(jwks.key(&iss, "key-01").await.unwrap().0.kid, "key-01");
+    assert_eq!(realm.requests(), 1);
+
+    // Unknown keys are requested again to the issuer.
+    realm.rotate("key-02");
+    assert_eq!(jwks.key(&iss, "key-02").await.unwrap().0.kid, "key-02");
+    assert_eq!(realm.requests(), 2);
+    assert!(jwks.key(&iss, "key-01").await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn throttle() -> Result<()> {
+    let (realm, issuers) = serve("key-01", None).await?;
+    let iss = issuers[0].issuer.clone();
+    let jwks = Jwks::new(issuers, Duration::from_secs(300), Duration::from_secs(60));
+
+    assert!(jwks.key(&iss, "key-01").await.is_ok());
+    for _ in 0..5 {
+        assert!(jwks.key(&iss, "unknown").await.is_err());
+    }
+    assert_eq!(realm.requests(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn max_age() -> Result<()> {
+    let (realm, issuers) = serve("key-01", Some(1)).await?;
+    let iss = issuers[0].issuer.clone();
+    let jwks = Jwks::new(issuers, Duration::from_secs(300), Duration::ZERO);
+
+    assert!(jwks.key(&iss, "key-01").await.is_ok());
+    assert!(jwks.key(&iss, "key-01").await.is_ok());
+    assert_eq!(realm.requests(), 1);
+
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    assert!(jwks.key(&iss, "key-01").await.is_ok());
+    assert_eq!(realm.requests(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn background() -> Result<()> {
+    let (realm, issuers) = serve("key-01", Some(1)).await?;
+    let iss = issuers[0].issuer.clone();
+    let jwks = Arc::new(Jwks::new(issuers, Duration::from_secs(300), Duration::ZERO));
+    jwks.clone().spawn();
+
+    tokio::time::sleep(Duration::from_millis(2500)).await;
+    assert!(realm.requests() >= 2);
+
+    // The rotated key is already in the cache.
+    realm.rotate("key-02");
+    tokio::time::sleep(Duration::from_millis(1500)).await;
+    let requests = realm.requests();
+    assert!(jwks.key(&iss, "key-02").await.is_ok());
+    assert_eq!(realm.requests(), requests);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn tenant() -> Result<()> {
+    let (_, issuers) = serve("key-01", None).await?;
+    let iss = issuers[0].issuer.clone();
+    let jwks = Jwks::new(issuers.clone(), Duration::from_secs(300), Duration::ZERO);
+
+    let (_, issuer) = jwks.key(&iss, "key-01").await.unwrap();
+    assert_eq!(issuer.issuer, issuers[0].issuer);
+    assert_eq!(issuer.tenant, Uuid::from_u128(1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn impersonation() -> Result<()> {
+    let (realm, issuers) = serve("key-01", None).await?;
+    let iss = issuers[0].issuer.clone();
+    let jwks = Jwks::new(issuers, Duration::from_secs(300), Duration::ZERO);
+
+    // The discovery informs another issuer, its keys are not trusted.
+    *realm.issuer.lock().unwrap() = "http://oauth-2:8080/realms/tenant-01".to_owned();
+    assert!(jwks.key(&iss, "key-01").await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn issuers() -> Result<()> {
+    let (_, mut issuers) = serve("key-01", None).await?;
+    let (_, other) = serve("key-01", None).await?;
+    let iss = issuers[0].issuer.clone();
+    let other_iss = other[0].issuer.clone();
+    issuers.push(Issuer {
+        issuer: other_iss.clone(),
+        tenant: Uuid::from_u128(2),
+    });
+    let jwks = Jwks::new(issuers, Duration::from_secs(300), Duration::ZERO);
+
+    // The same kid published by two issuers selects the key of the issuer of the token.
+    let (_, issuer) = jwks.key(&iss, "key-01").await.unwrap();
+    assert_eq!(issuer.tenant, Uuid::from_u128(1));
+    let (_, issuer) = jwks.key(&other_iss, "key-01").await.unwrap();
+    assert_eq!(issuer.tenant, Uuid::from_u128(2));
+
+    // Issuers not configured are not trusted.
+    assert!(jwks
+        .key("http://oauth-2:8080/realms/tenant-01", "key-01")
+        .await
+        .is_err());
+
+    Ok(())
+}
@@ -0,0 +1,142 @@
+mod shared;
+
+use anyhow::Result;
+use reqwest::StatusCode;
+use serde_json::json;
+use shared::{
+    tokens::{self, Tenant},
+    ADMIN, PASSWORD, URL, USERNAME, VIEWER,
+};
+
+const KEYS: &str = "http://localhost:5000/api/keys";
+
+async fn create(token: &String, scope: &str) -> Result<(StatusCode, serde_json::Value)> {
+    let response = reqwest::Client::new()
+        .post(KEYS)
+        .bearer_auth(token)
+        .json(&json!({ "name": "Pipeline", "scope": scope }))
+        .send()
+        .await?;
+    let status = response.status();
+    let json = response.json::<serde_json::Value>().await?;
+    Ok((status, json))
+}
+
+async fn projects(secret: &str) -> Result<StatusCode> {
+    let response = reqwest::Client::new()
+        .get(URL)
+        .bearer_auth(secret)
+        .send()
+        .await?;
+    Ok(response.status())
+}
+
+async fn scopes(token: &String, viewer: &String) -> Result<()> {
+    // Write access only for the analysts.
+    let (status, _) = create(viewer, "Write").await?;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, json) = create(token, "Read").await?;
+    assert_eq!(status, StatusCode::CREATED);
+    assert!(json["key"]["hash"].is_null());
+    assert!(json["key"]["expires"].is_string());
+    let secret = json["secret"].as_str().unwrap().to_owned();
+    assert!(secret.starts_with("fpa_"));
+
+    // API keys don't create other API keys.
+    let (status, _) = create(&secret, "Read").await?;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    assert_eq!(projects(&secret).await?, StatusCode::OK);
+    let response = reqwest::Client::new()
+        .post(URL)
+        .bearer_auth(&secret)
+        .json(&json!({ "name": "API Key Test" }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    Ok(())
+}
+
+async fn revoke(token: &String) -> Result<()> {
+    let (status, json) = create(token, "Write").await?;
+    assert_eq!(status, StatusCode::CREATED);
+    let key = json["key"]["key"].as_str().unwrap().to_owned();
+    let secret = json["secret"].as_str().unwrap().to_owned();
+    assert_eq!(projects(&secret).await?, StatusCode::OK);
+
+    let response = reqwest::Client::new()
+        .get(KEYS)
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = response.json::<serde_json::Value>().await?;
+    let items = json["items"].as_array().unwrap();
+    assert!(items.iter().any(|i| i["key"] == json!(key)));
+
+    let response = reqwest::Client::new()
+        .delete(format!("{}/{}", KEYS, key))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert_eq!(projects(&secret).await?, StatusCode::UNAUTHORIZED);
+
+    Ok(())
+}
+
+/// The write scope is limited to the analyst access, even for the administrators.
+async fn limited(admin: &String) -> Result<()> {
+    let (status, json) = create(admin, "Write").await?;
+    assert_eq!(status, StatusCode::CREATED);
+    let key = json["key"]["key"].as_str().unwrap().to_owned();
+    let secret = json["secret"].as_str().unwrap().to_owned();
+
+    let response = reqwest::Client::new()
+        .put("http://localhost:5000/api/impacts")
+        .bearer_auth(&secret)
+        .json(&json!([]))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = reqwest::Client::new()
+        .delete(format!("{}/{}", KEYS, key))
+        .bearer_auth(admin)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    Ok(())
+}
+
+async fn expired(token: &String) -> Result<()> {
+    let response = reqwest::Client::new()
+        .post(KEYS)
+        .bearer_auth(token)
+        .json(&json!({ "name": "Expired", "expires": "2020-01-01T00:00:00Z" }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    assert_eq!(projects("fpa_unknown").await?, StatusCode::UNAUTHORIZED);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn execute() -> Result<()> {
+    let token = tokens::request_token(USERNAME, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    let viewer = tokens::request_token(VIEWER, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    let admin = tokens::request_token(ADMIN, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    assert!(!token.is_empty());
+
+    scopes(&token, &viewer).await?;
+    revoke(&token).await?;
+    limited(&admin).await?;
+    expired(&token).await?;
+
+    Ok(())
+}
@@ -0,0 +1,170 @@
+mod shared;
+
+use anyhow::Result;
+use reqwest::StatusCode;
+use serde_json::json;
+use shared::{
+    tokens::{self, Tenant},
+    ADMIN, PASSWORD, REVIEWER, URL, USERNAME, VIEWER,
+};
+use uuid::Uuid;
+
+async fn create(token: &String) -> Result<Uuid> {
+    let body = json!({
+        "name": "Members Test",
+        "description": "Project shared with the members.",
+    });
+    let response = reqwest::Client::new()
+        .post(URL)
+        .bearer_auth(token)
+        .json(&body)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let json = response.json::<serde_json::Value>().await?;
+    Ok(Uuid::parse_str(json["project"].as_str().unwrap()).unwrap())
+}
+
+async fn frontier(token: &String, project: &Uuid) -> Result<Uuid> {
+    let response = reqwest::Client::new()
+        .post(format!("{}/{}/frontiers", URL, project))
+        .bearer_auth(token)
+        .json(&json!({ "name": "Members Test" }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let json = response.json::<serde_json::Value>().await?;
+    Ok(Uuid::parse_str(json["frontier"].as_str().unwrap()).unwrap())
+}
+
+async fn find(token: &String, project: &Uuid) -> Result<StatusCode> {
+    let response = reqwest::Client::new()
+        .get(format!("{}/{}", URL, project))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    Ok(response.status())
+}
+
+async fn invite(token: &String, project: &Uuid, user: &Uuid, role: &str) -> Result<StatusCode> {
+    let response = reqwest::Client::new()
+        .post(format!("{}/{}/members", URL, project))
+        .bearer_auth(token)
+        .json(&json!({ "user": user, "role": role }))
+        .send()
+        .await?;
+    Ok(response.status())
+}
+
+async fn members(token: &String, project: &Uuid) -> Result<Vec<serde_json::Value>> {
+    let response = reqwest::Client::new()
+        .get(format!("{}/{}/members", URL, project))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json = response.json::<serde_json::Value>().await?;
+    Ok(json["items"].as_array().unwrap().to_vec())
+}
+
+async fn sharing(owner: &String, viewer: &String, reviewer: &String) -> Result<()> {
+    let project = create(owner).await?;
+    let viewer_id = tokens::subject(viewer)?;
+    let reviewer_id = tokens::subject(reviewer)?;
+
+    let items = members(owner, &project).await?;
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["role"], json!("Owner"));
+
+    // Projects are visible only to the members.
+    assert_eq!(find(viewer, &project).await?, StatusCode::NOT_FOUND);
+    let frontier = frontier(owner, &project).await?;
+    for path in ["summary", "estimate"] {
+        let response = reqwest::Client::new()
+            .get(format!(
+                "{}/{}/frontiers/{}/{}",
+                URL, project, frontier, path
+            ))
+            .bearer_auth(viewer)
+            .send()
+            .await?;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    let status = invite(owner, &project, &viewer_id, "Viewer").await?;
+    assert_eq!(status, StatusCode::CREATED);
+    let status = invite(owner, &project, &viewer_id, "Viewer").await?;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let status = invite(owner, &project, &reviewer_id, "Owner").await?;
+    assert_eq!(status, StatusCode::NOT_ACCEPTABLE);
+    let status = invite(owner, &project, &Uuid::now_v7(), "Viewer").await?;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(find(viewer, &project).await?, StatusCode::OK);
+
+    // Viewers of the Project do not change it.
+    let status = invite(owner, &project, &reviewer_id, "Viewer").await?;
+    assert_eq!(status, StatusCode::CREATED);
+    let response = reqwest::Client::new()
+        .post(format!("{}/{}/frontiers", URL, project))
+        .bearer_auth(reviewer)
+        .json(&json!({ "name": "Members Test" }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = reqwest::Client::new()
+        .put(format!("{}/{}/owner", URL, project))
+        .bearer_auth(owner)
+        .json(&json!({ "user": reviewer_id }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = response.json::<serde_json::Value>().await?;
+    assert_eq!(json["user"], json!(reviewer_id));
+
+    // The previous owner remains as an editor.
+    let items = members(owner, &project).await?;
+    assert_eq!(items.len(), 3);
+    let status = invite(owner, &project, &viewer_id, "Editor").await?;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let response = reqwest::Client::new()
+        .delete(format!("{}/{}/members/{}", URL, project, viewer_id))
+        .bearer_auth(reviewer)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert_eq!(find(viewer, &project).await?, StatusCode::NOT_FOUND);
+
+    let response = reqwest::Client::new()
+        .delete(format!("{}/{}/frontiers/{}", URL, project, frontier))
+        .bearer_auth(reviewer)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let admin = tokens::request_token(ADMIN, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    let response = reqwest::Client::new()
+        .delete(format!("{}/{}", URL, project))
+        .bearer_auth(admin)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn execute() -> Result<()> {
+    let owner = tokens::request_token(USERNAME, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    let viewer = tokens::request_token(VIEWER, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    let reviewer = tokens::request_token(REVIEWER, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    assert!(!owner.is_empty());
+
+    sharing(&owner, &viewer, &reviewer).await?;
+
+    Ok(())
+}
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::json;
 use shared::{
     tokens::{self, Tenant},
-    PASSWORD, URL, USERNAME,
+    ADMIN, PASSWORD, URL, USERNAME,
 };
 use uuid::Uuid;
 
@@ -204,7 +204,8 @@ async fn execute() -> Result<()> {
     let data = update(&token, &data).await?;
     update_duplicated(&token, &data).await?;
 
-    remove(&token, &data).await?;
+    let admin = tokens::request_token(ADMIN, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    remove(&admin, &data).await?;
 
     not_found(&token, &data).await?;
 
@@ -0,0 +1,139 @@
+mod shared;
+
+use anyhow::Result;
+use reqwest::{header, StatusCode};
+use serde_json::json;
+use shared::{
+    tokens::{self, Tenant},
+    ADMIN, PASSWORD, URL, USERNAME,
+};
+use uuid::Uuid;
+
+async fn counting(token: &String) -> Result<(String, String, String)> {
+    let response = reqwest::Client::new()
+        .post(URL)
+        .bearer_auth(token)
+        .json(&json!({ "name": format!("Report Test {}", Uuid::now_v7()) }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let json = response.json::<serde_json::Value>().await?;
+    let project = json["project"].as_str().unwrap().to_owned();
+
+    let response = reqwest::Client::new()
+        .post(format!("{}/{}/frontiers", URL, project))
+        .bearer_auth(token)
+        .json(&json!({ "name": "Reported Frontier" }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let json = response.json::<serde_json::Value>().await?;
+    let frontier = json["frontier"].as_str().unwrap().to_owned();
+
+    let response = reqwest::Client::new()
+        .post(format!(
+            "{}/{}/frontiers/{}/functions",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .json(&json!({
+            "ALI": {
+                "name": "Customers",
+                "rlrs": [{ "name": "Customer", "ders": [{ "name": "id" }, { "name": "name" }] }]
+            }
+        }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let json = response.json::<serde_json::Value>().await?;
+    let function = json["ALI"]["id"].as_str().unwrap().to_owned();
+
+    Ok((project, frontier, function))
+}
+
+async fn xlsx(token: &String, project: &str) -> Result<()> {
+    let response = reqwest::Client::new()
+        .get(format!("{}/{}/export.xlsx", URL, project))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+    );
+    let disposition = response.headers()[header::CONTENT_DISPOSITION].to_str()?;
+    assert!(disposition.starts_with("attachment; filename=\"Report Test "));
+    assert!(disposition.ends_with(".xlsx\""));
+
+    // XLSX is a zip package.
+    let content = response.bytes().await?;
+    assert!(content.starts_with(b"PK"));
+
+    Ok(())
+}
+
+async fn pdf(token: &String, project: &str) -> Result<()> {
+    let response = reqwest::Client::new()
+        .get(format!("{}/{}/report.pdf", URL, project))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/pdf");
+    let disposition = response.headers()[header::CONTENT_DISPOSITION].to_str()?;
+    assert!(disposition.ends_with(".pdf\""));
+
+    let content = response.bytes().await?;
+    assert!(content.starts_with(b"%PDF-"));
+
+    Ok(())
+}
+
+async fn remove(
+    token: &String,
+    admin: &String,
+    project: &str,
+    frontier: &str,
+    function: &str,
+) -> Result<()> {
+    let response = reqwest::Client::new()
+        .delete(format!(
+            "{}/{}/frontiers/{}/functions/{}",
+            URL, project, frontier, function
+        ))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = reqwest::Client::new()
+        .delete(format!("{}/{}/frontiers/{}", URL, project, frontier))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = reqwest::Client::new()
+        .delete(format!("{}/{}", URL, project))
+        .bearer_auth(admin)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn execute() -> Result<()> {
+    let token = tokens::request_token(USERNAME, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    let admin = tokens::request_token(ADMIN, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    assert!(!token.is_empty());
+
+    let (project, frontier, function) = counting(&token).await?;
+    xlsx(&token, &project).await?;
+    pdf(&token, &project).await?;
+    remove(&token, &admin, &project, &frontier, &function).await?;
+
+    Ok(())
+}
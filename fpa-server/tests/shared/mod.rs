@@ -5,4 +5,8 @@ pub mod tokens;
 
 pub const URL: &str = "http://localhost:5000/api/projects";
 pub const USERNAME: &str = "user";
+pub const VIEWER: &str = "viewer";
+pub const REVIEWER: &str = "reviewer";
+pub const ADMIN: &str = "admin";
+pub const SYSTEM: &str = "system";
 pub const PASSWORD: &str = "fpa-pass";
@@ -9,6 +9,7 @@ use anyhow::Result;
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use sha2::{Digest, Sha256};
 
 #[derive(Debug, Deserialize)]
 struct AccessTokenResponse {
@@ -24,13 +25,21 @@ struct AccessTokenResponse {
 }
 
 #[allow(non_camel_case_types)]
+#[derive(Clone, Copy)]
 pub enum Tenant {
     TENANT_DEFAULT,
     TENANT_01,
     TENANT_02,
 }
 
+/// Enables the built-in issuer of the fpa-server (`dev-auth` feature), instead of the Keycloak.
+const DEV_AUTH: &str = "FPA_DEV_AUTH";
+
 pub async fn request_token(user: &str, password: &str, tenant: Tenant) -> Result<String> {
+    if dev_auth() {
+        return request_dev_token(user, tenant).await;
+    }
+
     let (realm, secret) = match tenant {
         Tenant::TENANT_DEFAULT => ("default", "ogIzFgW9nY8kbptdREn5cw2rrn0Cihpv"),
         Tenant::TENANT_01 => ("tenant-01", "jKQO0Pxb1gFrSz64iUgqlgsoANs86d31"),
@@ -58,3 +67,106 @@ pub async fn request_token(user: &str, password: &str, tenant: Tenant) -> Result
 
     Ok(response.access_token)
 }
+
+/// Token of the built-in issuer, for the users of the Keycloak realms (same roles).
+async fn request_dev_token(user: &str, tenant: Tenant) -> Result<String> {
+    let roles = match user {
+        "admin" => vec!["administrator"],
+        "viewer" => vec!["user", "viewer"],
+        "reviewer" => vec!["user", "reviewer"],
+        "system" => vec!["user", "system"],
+        _ => vec!["user", "analyst"],
+    };
+    // Users of the realms are the ones of the database seed, members of its projects.
+    let (id, name) = match dev_user(user, tenant) {
+        Some((id, name)) => (uuid::Uuid::parse_str(id)?, name),
+        None => {
+            // Same user on each request, unique on each tenant.
+            let digest = Sha256::digest(format!("{}@{}", user, dev_tenant(tenant)));
+            let id = uuid::Builder::from_random_bytes(digest[..16].try_into()?).into_uuid();
+            (id, user)
+        }
+    };
+
+    request_dev_claims(tenant, id, name, &format!("{}@nao.com.br", user), &roles).await
+}
+
+/// Identifier and name of the users of the Keycloak realms, as seeded by `999-desenv.sql`.
+fn dev_user(user: &str, tenant: Tenant) -> Option<(&'static str, &'static str)> {
+    let id = match (tenant, user) {
+        (Tenant::TENANT_DEFAULT, "admin") => "7dd55185-97c0-448d-ad4e-610e7447d3fe",
+        (Tenant::TENANT_DEFAULT, "user") => "788bde27-f334-422b-adec-9de5e8c539a2",
+        (Tenant::TENANT_DEFAULT, "viewer") => "ce8ccb5b-4f6b-4aea-a366-bebe6be9d4cd",
+        (Tenant::TENANT_DEFAULT, "reviewer") => "ee310f2c-ec38-46f6-b0dc-d15ab936c15a",
+        (Tenant::TENANT_01, "admin") => "acc5de66-9a8a-48c0-93b6-70afb57e9157",
+        (Tenant::TENANT_01, "user") => "7c3974d3-ea3f-4e4a-88ee-18e30b9b34bc",
+        (Tenant::TENANT_01, "viewer") => "43f60c02-9886-48db-8ce0-884a623b01f1",
+        (Tenant::TENANT_01, "reviewer") => "c84cb08e-947b-40c3-b32c-04928292c474",
+        (Tenant::TENANT_02, "admin") => "0f055794-f3c6-4c9a-9b36-2665c2da79bb",
+        (Tenant::TENANT_02, "user") => "404b162b-0e6f-401f-b2bc-efa6110d4045",
+        (Tenant::TENANT_02, "viewer") => "2e9bf0db-5858-4e7b-9dea-f07c847ce0e8",
+        (Tenant::TENANT_02, "reviewer") => "db8a4fcb-920e-4485-bc60-d327eab101d6",
+        _ => return None,
+    };
+    let name = match user {
+        "admin" => "Administrator System",
+        "user" => "User System",
+        "viewer" => "Viewer System",
+        _ => "Reviewer System",
+    };
+    Some((id, name))
+}
+
+/// The built-in issuer is used instead of the Keycloak.
+pub fn dev_auth() -> bool {
+    std::env::var_os(DEV_AUTH).is_some()
+}
+
+fn dev_tenant(tenant: Tenant) -> uuid::Uuid {
+    match tenant {
+        Tenant::TENANT_DEFAULT => uuid::Uuid::from_u128(0),
+        Tenant::TENANT_01 => uuid::Uuid::from_u128(1),
+        Tenant::TENANT_02 => uuid::Uuid::from_u128(2),
+    }
+}
+
+/// Token of the built-in issuer with the given claims.
+pub async fn request_dev_claims(
+    tenant: Tenant,
+    user: uuid::Uuid,
+    name: &str,
+    email: &str,
+    roles: &[&str],
+) -> Result<String> {
+    let response: Value = reqwest::Client::new()
+        .post(format!(
+            "http://localhost:5000/dev/realms/{}/token",
+            dev_tenant(tenant)
+        ))
+        .json(&serde_json::json!({
+            "user": user,
+            "name": name,
+            "email": email,
+            "roles": roles,
+        }))
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    Ok(response["access_token"].as_str().unwrap().to_owned())
+}
+
+/// User (subject) of the token, without validating it.
+pub fn subject(token: &str) -> Result<uuid::Uuid> {
+    let mut validation = jsonwebtoken::Validation::default();
+    validation.insecure_disable_signature_validation();
+    validation.validate_aud = false;
+    let data = jsonwebtoken::decode::<Value>(
+        token,
+        &jsonwebtoken::DecodingKey::from_secret(&[]),
+        &validation,
+    )?;
+    Ok(uuid::Uuid::parse_str(data.claims["sub"].as_str().unwrap())?)
+}
@@ -0,0 +1,153 @@
+mod shared;
+
+use anyhow::Result;
+use reqwest::StatusCode;
+use serde_json::json;
+use shared::{
+    selects,
+    tokens::{self, Tenant},
+    PASSWORD, URL, USERNAME,
+};
+use uuid::Uuid;
+
+async fn create(token: &String, project: &Uuid, label: &str) -> Result<Uuid> {
+    let response = reqwest::Client::new()
+        .post(format!("{}/{}/snapshots", URL, project))
+        .bearer_auth(token)
+        .json(&json!({ "label": label }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let json = response.json::<serde_json::Value>().await?;
+    assert_eq!(json["label"], json!(label));
+    Ok(Uuid::parse_str(json["snapshot"].as_str().unwrap()).unwrap())
+}
+
+async fn diff(
+    token: &String,
+    project: &Uuid,
+    snapshot: &Uuid,
+    target: &Uuid,
+) -> Result<serde_json::Value> {
+    let response = reqwest::Client::new()
+        .get(format!(
+            "{}/{}/snapshots/{}/diff/{}",
+            URL, project, snapshot, target
+        ))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    Ok(response.json::<serde_json::Value>().await?)
+}
+
+async fn versions(token: &String, project: &Uuid) -> Result<()> {
+    let before = create(token, project, "Before Snapshot Test").await?;
+
+    let response = reqwest::Client::new()
+        .post(format!("{}/{}/frontiers", URL, project))
+        .bearer_auth(token)
+        .json(&json!({ "name": "Snapshot Test" }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let json = response.json::<serde_json::Value>().await?;
+    let frontier = json["frontier"].as_str().unwrap().to_owned();
+
+    let response = reqwest::Client::new()
+        .post(format!(
+            "{}/{}/frontiers/{}/functions",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .json(&json!({
+            "ALI": {
+                "name": "Snapshot ALI",
+                "rlrs": [{ "name": "Records", "ders": [{ "name": "id" }, { "name": "name" }] }],
+            }
+        }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let json = response.json::<serde_json::Value>().await?;
+    let function = json["ALI"]["id"].clone();
+
+    let after = create(token, project, "After Snapshot Test").await?;
+
+    let response = reqwest::Client::new()
+        .get(format!("{}/{}/snapshots/{}", URL, project, after))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = response.json::<serde_json::Value>().await?;
+    let content = json["frontiers"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["frontier"] == json!(frontier))
+        .unwrap();
+    assert_eq!(content["factors"].as_array().unwrap().len(), 14);
+    assert_eq!(content["functions"][0]["ALI"]["id"], function);
+
+    let json = diff(token, project, &before, &after).await?;
+    let item = json["functions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["function"] == function)
+        .unwrap();
+    assert_eq!(item["diff"], json!("Added"));
+    assert_eq!(item["rlrs"], json!(1));
+    assert_eq!(item["ders"], json!(2));
+    assert_eq!(item["points"], json!(7));
+
+    let json = diff(token, project, &after, &before).await?;
+    let item = json["functions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["function"] == function)
+        .unwrap();
+    assert_eq!(item["diff"], json!("Removed"));
+    assert_eq!(item["points"], json!(-7));
+
+    let response = reqwest::Client::new()
+        .get(format!("{}/{}/snapshots/{}", URL, project, Uuid::now_v7()))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    for path in [
+        format!(
+            "frontiers/{}/functions/{}",
+            frontier,
+            function.as_str().unwrap()
+        ),
+        format!("frontiers/{}", frontier),
+    ] {
+        let response = reqwest::Client::new()
+            .delete(format!("{}/{}/{}", URL, project, path))
+            .bearer_auth(token)
+            .send()
+            .await?;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn execute() -> Result<()> {
+    let token = tokens::request_token(USERNAME, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    assert!(!token.is_empty());
+
+    let project = selects::project(&token).await?;
+
+    versions(&token, &project).await?;
+
+    Ok(())
+}
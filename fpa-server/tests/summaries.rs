@@ -0,0 +1,264 @@
+mod shared;
+
+use anyhow::Result;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use serde_json::json;
+use shared::{
+    selects,
+    tokens::{self, Tenant},
+    PASSWORD, URL, USERNAME,
+};
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+struct Item {
+    count: i32,
+    low: i32,
+    average: i32,
+    high: i32,
+    points: i32,
+}
+
+#[derive(Debug, Deserialize)]
+struct Data {
+    frontier: Uuid,
+    functions: Vec<Item>,
+    ufp: i32,
+    tdi: i32,
+    vaf: f64,
+    afp: f64,
+}
+
+async fn by_frontier(token: &String, project: &Uuid, frontier: &Uuid) -> Result<()> {
+    let response = reqwest::Client::new()
+        .get(format!(
+            "{}/{}/frontiers/{}/summary",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json = response.json::<Data>().await?;
+    assert_eq!(json.frontier, *frontier);
+    assert_eq!(json.functions.len(), 5);
+    for item in json.functions.iter() {
+        assert_eq!(item.count, item.low + item.average + item.high);
+    }
+    assert_eq!(
+        json.ufp,
+        json.functions.iter().map(|i| i.points).sum::<i32>()
+    );
+    assert!((0..=70).contains(&json.tdi));
+    assert!((json.vaf - (0.65 + f64::from(json.tdi) / 100.0)).abs() < 0.001);
+    assert!((json.afp - f64::from(json.ufp) * json.vaf).abs() < 0.01);
+
+    Ok(())
+}
+
+async fn not_found(token: &String, project: &Uuid) -> Result<()> {
+    let response = reqwest::Client::new()
+        .get(format!(
+            "{}/{}/frontiers/{}/summary",
+            URL,
+            project,
+            Uuid::now_v7()
+        ))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    Ok(())
+}
+
+async fn create_frontier(token: &String, project: &Uuid, body: serde_json::Value) -> Result<Uuid> {
+    let response = reqwest::Client::new()
+        .post(format!("{}/{}/frontiers", URL, project))
+        .bearer_auth(token)
+        .json(&body)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let json = response.json::<serde_json::Value>().await?;
+    Ok(Uuid::parse_str(json["frontier"].as_str().unwrap()).unwrap())
+}
+
+async fn create_function(
+    token: &String,
+    project: &Uuid,
+    frontier: &Uuid,
+    r#type: &str,
+    change: &str,
+    impact: &str,
+) -> Result<Uuid> {
+    let body = json!({
+        r#type: {
+            "name": format!("{} {}", r#type, change),
+            "change": change,
+            "impact": impact,
+            "rlrs": [],
+        }
+    });
+    let response = reqwest::Client::new()
+        .post(format!(
+            "{}/{}/frontiers/{}/functions",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .json(&body)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let json = response.json::<serde_json::Value>().await?;
+    Ok(Uuid::parse_str(json[r#type]["id"].as_str().unwrap()).unwrap())
+}
+
+async fn summary(token: &String, project: &Uuid, frontier: &Uuid) -> Result<serde_json::Value> {
+    let response = reqwest::Client::new()
+        .get(format!(
+            "{}/{}/frontiers/{}/summary",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    Ok(response.json::<serde_json::Value>().await?)
+}
+
+async fn methods(token: &String, project: &Uuid) -> Result<()> {
+    let body = json!({
+        "name": "Summary Methods Test",
+        "method": "Indicative",
+    });
+    let frontier = create_frontier(token, project, body).await?;
+
+    let path = format!("frontiers/{}/functions", frontier);
+    let mut functions = Vec::<Uuid>::new();
+    for (r#type, body) in [
+        ("ALI", json!({ "ALI": { "name": "ALI Without RLRs" } })),
+        ("AIE", json!({ "AIE": { "name": "AIE Without RLRs" } })),
+        (
+            "EE",
+            json!({ "EE": { "name": "EE Without ALRs", "alrs": [] } }),
+        ),
+    ] {
+        let response = reqwest::Client::new()
+            .post(format!("{}/{}/{}", URL, project, path))
+            .bearer_auth(token)
+            .json(&body)
+            .send()
+            .await?;
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let json = response.json::<serde_json::Value>().await?;
+        functions.push(Uuid::parse_str(json[r#type]["id"].as_str().unwrap()).unwrap());
+    }
+
+    let json = summary(token, project, &frontier).await?;
+    assert_eq!(json["method"], json!("Indicative"));
+    assert_eq!(json["ufp"], json!(50));
+
+    let response = reqwest::Client::new()
+        .put(format!("{}/{}/frontiers/{}", URL, project, frontier))
+        .bearer_auth(token)
+        .json(&json!({
+            "name": "Summary Methods Test",
+            "method": "Estimated",
+        }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json = summary(token, project, &frontier).await?;
+    assert_eq!(json["method"], json!("Estimated"));
+    assert_eq!(json["ufp"], json!(16));
+
+    for function in functions {
+        remove(token, project, &format!("{}/{}", path, function)).await?;
+    }
+    remove(token, project, &format!("frontiers/{}", frontier)).await?;
+
+    Ok(())
+}
+
+async fn remove(token: &String, project: &Uuid, path: &str) -> Result<()> {
+    let response = reqwest::Client::new()
+        .delete(format!("{}/{}/{}", URL, project, path))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    Ok(())
+}
+
+async fn enhancement(token: &String, project: &Uuid) -> Result<()> {
+    let body = json!({
+        "name": "Summary Enhancement Test",
+        "counting": "Enhancement",
+    });
+    let frontier = create_frontier(token, project, body).await?;
+    let ali = create_function(token, project, &frontier, "ALI", "Added", "Inclusion").await?;
+    let aie = create_function(token, project, &frontier, "AIE", "Deleted", "Exclusion").await?;
+
+    let response = reqwest::Client::new()
+        .get(format!(
+            "{}/{}/frontiers/{}/summary?vafb=1.0",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json = response.json::<serde_json::Value>().await?;
+    assert_eq!(json["counting"], json!("Enhancement"));
+    assert_eq!(json["ufp"], json!(12));
+    assert_eq!(json["enhancement"]["added"], json!(7));
+    assert_eq!(json["enhancement"]["changed"], json!(0));
+    assert_eq!(json["enhancement"]["deleted"], json!(5));
+    assert_eq!(json["enhancement"]["conversion"], json!(0));
+    assert_eq!(json["enhancement"]["vafa"], json!(0.65));
+    assert_eq!(json["enhancement"]["vafb"], json!(1.0));
+    assert_eq!(json["enhancement"]["efp"], json!(9.55));
+    assert_eq!(json["sisp"], json!(9.0));
+
+    let response = reqwest::Client::new()
+        .get(format!(
+            "{}/{}/frontiers/{}/summary?vafb=2.0",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let path = format!("frontiers/{}/functions", frontier);
+    remove(token, project, &format!("{}/{}", path, ali)).await?;
+    remove(token, project, &format!("{}/{}", path, aie)).await?;
+    remove(token, project, &format!("frontiers/{}", frontier)).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn execute() -> Result<()> {
+    let token = tokens::request_token(USERNAME, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    assert!(!token.is_empty());
+
+    let project = selects::project(&token).await?;
+    let frontier = selects::frontier(&token, &project).await?;
+
+    by_frontier(&token, &project, &frontier).await?;
+    not_found(&token, &project).await?;
+    enhancement(&token, &project).await?;
+    methods(&token, &project).await?;
+
+    Ok(())
+}
@@ -0,0 +1,209 @@
+mod shared;
+
+use anyhow::Result;
+use reqwest::StatusCode;
+use serde_json::json;
+use shared::{
+    selects,
+    tokens::{self, Tenant},
+    PASSWORD, SYSTEM, URL, USERNAME,
+};
+use uuid::Uuid;
+
+const TENANTS: &str = "http://localhost:5000/api/admin/tenants";
+
+/// Tenant of the `tenant-02` realm, changed by the tests and restored at the end.
+const TENANT: Uuid = Uuid::from_u128(2);
+
+async fn change(system: &String, status: &str, tier: &str) -> Result<()> {
+    let response = reqwest::Client::new()
+        .get(format!("{}/{}", TENANTS, TENANT))
+        .bearer_auth(system)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = response.json::<serde_json::Value>().await?;
+
+    let response = reqwest::Client::new()
+        .put(format!("{}/{}", TENANTS, TENANT))
+        .bearer_auth(system)
+        .json(&json!({ "name": json["name"], "status": status, "tier": tier }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    Ok(())
+}
+
+async fn rejected(response: reqwest::Response, error: &str) -> Result<()> {
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let json = response.json::<serde_json::Value>().await?;
+    assert_eq!(json["error"], json!(error));
+
+    Ok(())
+}
+
+async fn disabled(system: &String, token: &String) -> Result<()> {
+    change(system, "Disabled", "Silver").await?;
+
+    let response = reqwest::Client::new()
+        .get(URL)
+        .bearer_auth(token)
+        .send()
+        .await?;
+    rejected(response, "TENANT_DISABLED").await?;
+
+    Ok(())
+}
+
+async fn suspended(system: &String, token: &String, project: &Uuid) -> Result<()> {
+    change(system, "Suspended", "Silver").await?;
+
+    // Only reading is allowed.
+    let response = reqwest::Client::new()
+        .get(format!("{}/{}", URL, project))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = reqwest::Client::new()
+        .post(URL)
+        .bearer_auth(token)
+        .json(&json!({ "name": "Suspended Tenant Test" }))
+        .send()
+        .await?;
+    rejected(response, "TENANT_SUSPENDED").await?;
+
+    let response = reqwest::Client::new()
+        .put(format!("{}/{}", URL, project))
+        .bearer_auth(token)
+        .json(&json!({ "name": "Suspended Tenant Test" }))
+        .send()
+        .await?;
+    rejected(response, "TENANT_SUSPENDED").await?;
+
+    let response = reqwest::Client::new()
+        .delete(format!("{}/{}", URL, project))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    rejected(response, "TENANT_SUSPENDED").await?;
+
+    Ok(())
+}
+
+/// The seed of the Tenant has more Projects than the bronze tier allows.
+async fn quota_projects(system: &String, token: &String) -> Result<()> {
+    change(system, "Active", "Bronze").await?;
+
+    let response = reqwest::Client::new()
+        .post(URL)
+        .bearer_auth(token)
+        .json(&json!({ "name": "Quota Projects Test" }))
+        .send()
+        .await?;
+    rejected(response, "QUOTA_PROJECTS").await?;
+
+    Ok(())
+}
+
+/// Frontiers are registered until the quota of the bronze tier, and removed afterwards.
+async fn quota_frontiers(system: &String, token: &String, project: &Uuid) -> Result<()> {
+    change(system, "Active", "Bronze").await?;
+
+    let mut frontiers = Vec::new();
+    let mut response = None;
+    for i in 0..1000 {
+        let created = reqwest::Client::new()
+            .post(format!("{}/{}/frontiers", URL, project))
+            .bearer_auth(token)
+            .json(&json!({ "name": format!("Quota Frontiers Test {:03}", i) }))
+            .send()
+            .await?;
+        if created.status() != StatusCode::CREATED {
+            response = Some(created);
+            break;
+        }
+        let json = created.json::<serde_json::Value>().await?;
+        frontiers.push(json["frontier"].as_str().unwrap().to_owned());
+    }
+
+    for frontier in frontiers {
+        let removed = reqwest::Client::new()
+            .delete(format!("{}/{}/frontiers/{}", URL, project, frontier))
+            .bearer_auth(token)
+            .send()
+            .await?;
+        assert_eq!(removed.status(), StatusCode::NO_CONTENT);
+    }
+    rejected(response.unwrap(), "QUOTA_FRONTIERS").await?;
+
+    Ok(())
+}
+
+/// One import with more Functions than the bronze tier allows, nothing is registered.
+async fn quota_functions(
+    system: &String,
+    token: &String,
+    project: &Uuid,
+    frontier: &Uuid,
+) -> Result<()> {
+    change(system, "Active", "Bronze").await?;
+
+    let mut csv = String::from("type,name\n");
+    for i in 0..5001 {
+        csv.push_str(&format!("ALI,Quota Functions Test {:04}\n", i));
+    }
+    let response = reqwest::Client::new()
+        .post(format!(
+            "{}/{}/frontiers/{}/functions/import/csv",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .header("Content-Type", "text/csv")
+        .body(csv)
+        .send()
+        .await?;
+    rejected(response, "QUOTA_FUNCTIONS").await?;
+
+    let response = reqwest::Client::new()
+        .get(format!(
+            "{}/{}/frontiers/{}/functions?name=Quota",
+            URL, project, frontier
+        ))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = response.json::<serde_json::Value>().await?;
+    assert_eq!(json["records"], json!(0));
+
+    Ok(())
+}
+
+async fn scenarios(system: &String, token: &String) -> Result<()> {
+    let project = selects::project(token).await?;
+    let frontier = selects::frontier(token, &project).await?;
+
+    disabled(system, token).await?;
+    suspended(system, token, &project).await?;
+    quota_projects(system, token).await?;
+    quota_frontiers(system, token, &project).await?;
+    quota_functions(system, token, &project, &frontier).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn execute() -> Result<()> {
+    let system = tokens::request_token(SYSTEM, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    let token = tokens::request_token(USERNAME, PASSWORD, Tenant::TENANT_02).await?;
+    assert!(!token.is_empty());
+
+    // The Tenant is restored even when a scenario fails.
+    let result = scenarios(&system, &token).await;
+    change(&system, "Active", "Silver").await?;
+
+    result
+}
@@ -0,0 +1,94 @@
+mod shared;
+
+use anyhow::Result;
+use reqwest::StatusCode;
+use shared::{
+    tokens::{self, Tenant},
+    PASSWORD, USERNAME,
+};
+use uuid::Uuid;
+
+const URL: &str = "http://localhost:5000/api/users";
+
+async fn me(token: &String) -> Result<serde_json::Value> {
+    let response = reqwest::Client::new()
+        .get(format!("{}/me", URL))
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    Ok(response.json::<serde_json::Value>().await?)
+}
+
+async fn list(token: &String) -> Result<()> {
+    let user = me(token).await?;
+    assert_eq!(
+        user["user"].as_str().unwrap(),
+        tokens::subject(token)?.to_string()
+    );
+    assert!(!user["email"].as_str().unwrap().is_empty());
+
+    let response = reqwest::Client::new()
+        .get(URL)
+        .query(&[("size", "50")])
+        .bearer_auth(token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json = response.json::<serde_json::Value>().await?;
+    assert!(json["records"].as_u64().unwrap() >= 1);
+    let items = json["items"].as_array().unwrap();
+    assert!(items.iter().any(|i| i["user"] == user["user"]));
+
+    Ok(())
+}
+
+/// Name and e-mail changed on the issuer are updated on the next request of the User.
+async fn claims() -> Result<()> {
+    let user = Uuid::now_v7();
+    let roles = ["user", "analyst"];
+
+    let token = tokens::request_dev_claims(
+        Tenant::TENANT_DEFAULT,
+        user,
+        "Claims Test",
+        "claims@nao.com.br",
+        &roles,
+    )
+    .await?;
+    let json = me(&token).await?;
+    assert_eq!(json["user"].as_str().unwrap(), user.to_string());
+    assert_eq!(json["name"].as_str().unwrap(), "Claims Test");
+    assert_eq!(json["email"].as_str().unwrap(), "claims@nao.com.br");
+
+    let token = tokens::request_dev_claims(
+        Tenant::TENANT_DEFAULT,
+        user,
+        "Claims Test Changed",
+        "claims.changed@nao.com.br",
+        &roles,
+    )
+    .await?;
+    let json = me(&token).await?;
+    assert_eq!(json["name"].as_str().unwrap(), "Claims Test Changed");
+    assert_eq!(json["email"].as_str().unwrap(), "claims.changed@nao.com.br");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn execute() -> Result<()> {
+    let token = tokens::request_token(USERNAME, PASSWORD, Tenant::TENANT_DEFAULT).await?;
+    assert!(!token.is_empty());
+
+    list(&token).await?;
+
+    // The claims are chosen only on the built-in issuer.
+    if tokens::dev_auth() {
+        claims().await?;
+    }
+
+    Ok(())
+}